@@ -32,11 +32,11 @@ pub(crate) fn parse_object_acl_from_xml(xml: &str) -> Result<ObjectAcl> {
 
 pub(crate) fn build_get_object_acl_request(bucket_name: &str, object_key: &str, options: &Option<GetObjectAclOptions>) -> Result<OssRequest> {
     if !validate_bucket_name(bucket_name) {
-        return Err(Error::Other(format!("invalid bucket name: {}", bucket_name)));
+        return Err(Error::InvalidBucketName(bucket_name.to_string()));
     }
 
     if !validate_object_key(object_key) {
-        return Err(Error::Other(format!("invalid object key: {}", object_key)));
+        return Err(Error::InvalidObjectKey(object_key.to_string()));
     }
 
     let mut request = OssRequest::new()
@@ -56,11 +56,11 @@ pub(crate) fn build_get_object_acl_request(bucket_name: &str, object_key: &str,
 
 pub(crate) fn build_put_object_acl_request(bucket_name: &str, object_key: &str, acl: ObjectAcl, options: &Option<GetObjectAclOptions>) -> Result<OssRequest> {
     if !validate_bucket_name(bucket_name) {
-        return Err(Error::Other(format!("invalid bucket name: {}", bucket_name)));
+        return Err(Error::InvalidBucketName(bucket_name.to_string()));
     }
 
     if !validate_object_key(object_key) {
-        return Err(Error::Other(format!("invalid object key: {}", object_key)));
+        return Err(Error::InvalidObjectKey(object_key.to_string()));
     }
 
     let mut request = OssRequest::new()
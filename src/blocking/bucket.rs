@@ -1,8 +1,11 @@
 use crate::{
     bucket_common::{
-        build_list_buckets_request, build_list_objects_request, build_put_bucket_request, extract_bucket_location, BucketDetail, BucketStat,
-        ListBucketsOptions, ListBucketsResult, ListObjectsOptions, ListObjectsResult, PutBucketConfiguration, PutBucketOptions,
+        build_list_buckets_request, build_list_objects_request, build_put_bucket_access_monitor_request, build_put_bucket_request,
+        build_put_bucket_request_payment_request, build_put_bucket_transfer_acceleration_request, extract_access_monitor_status, extract_bucket_location,
+        extract_request_payer, extract_transfer_acceleration_enabled, BucketDetail, BucketStat, ListBucketsOptions, ListBucketsResult, ListObjectsOptions,
+        ListObjectsResult, PutBucketConfiguration, PutBucketOptions,
     },
+    common::{AccessMonitor, RequestPayer},
     error::Error,
     request::{OssRequest, RequestMethod},
     util::validate_bucket_name,
@@ -19,15 +22,18 @@ pub trait BucketOperations {
     fn get_bucket_stat<S: AsRef<str>>(&self, bucket_name: S) -> Result<BucketStat>;
     fn list_objects<S: AsRef<str>>(&self, bucket_name: S, options: Option<ListObjectsOptions>) -> Result<ListObjectsResult>;
     fn delete_bucket<S: AsRef<str>>(&self, bucket_name: S) -> Result<()>;
+    fn put_bucket_transfer_acceleration<S: AsRef<str>>(&self, bucket_name: S, enabled: bool) -> Result<()>;
+    fn get_bucket_transfer_acceleration<S: AsRef<str>>(&self, bucket_name: S) -> Result<bool>;
+    fn put_bucket_request_payment<S: AsRef<str>>(&self, bucket_name: S, payer: RequestPayer) -> Result<()>;
+    fn get_bucket_request_payment<S: AsRef<str>>(&self, bucket_name: S) -> Result<RequestPayer>;
+    fn put_bucket_access_monitor<S: AsRef<str>>(&self, bucket_name: S, status: AccessMonitor) -> Result<()>;
+    fn get_bucket_access_monitor<S: AsRef<str>>(&self, bucket_name: S) -> Result<AccessMonitor>;
 }
 
 impl BucketOperations for Client {
     fn put_bucket<S: AsRef<str>>(&self, bucket_name: S, config: PutBucketConfiguration, options: Option<PutBucketOptions>) -> Result<()> {
         if !validate_bucket_name(bucket_name.as_ref()) {
-            return Err(Error::Other(format!(
-                "invalid bucket name: {}. please see the official document for more details",
-                bucket_name.as_ref()
-            )));
+            return Err(Error::InvalidBucketName(bucket_name.as_ref().to_string()));
         }
 
         let request_builder = build_put_bucket_request(bucket_name.as_ref(), &config, &options)?;
@@ -49,7 +55,7 @@ impl BucketOperations for Client {
         let bucket_name = bucket_name.as_ref();
 
         if !validate_bucket_name(bucket_name) {
-            return Err(Error::Other(format!("invalid bucket name: {}", bucket_name)));
+            return Err(Error::InvalidBucketName(bucket_name.to_string()));
         }
 
         let request_builder = OssRequest::new().method(RequestMethod::Get).bucket(bucket_name).add_query("bucketInfo", "");
@@ -63,7 +69,7 @@ impl BucketOperations for Client {
         let bucket_name = bucket_name.as_ref();
 
         if !validate_bucket_name(bucket_name) {
-            return Err(Error::Other(format!("invalid bucket name: {}", bucket_name)));
+            return Err(Error::InvalidBucketName(bucket_name.to_string()));
         }
 
         let request_builder = OssRequest::new().method(RequestMethod::Get).bucket(bucket_name).add_query("location", "");
@@ -77,7 +83,7 @@ impl BucketOperations for Client {
         let bucket_name = bucket_name.as_ref();
 
         if !validate_bucket_name(bucket_name) {
-            return Err(Error::Other(format!("invalid bucket name: {}", bucket_name)));
+            return Err(Error::InvalidBucketName(bucket_name.to_string()));
         }
 
         let request_builder = OssRequest::new().method(RequestMethod::Get).bucket(bucket_name).add_query("stat", "");
@@ -91,7 +97,7 @@ impl BucketOperations for Client {
         let bucket_name = bucket_name.as_ref();
 
         if !validate_bucket_name(bucket_name) {
-            return Err(Error::Other(format!("invalid bucket name: {}", bucket_name)));
+            return Err(Error::InvalidBucketName(bucket_name.to_string()));
         }
 
         let request = build_list_objects_request(bucket_name, &options)?;
@@ -105,7 +111,7 @@ impl BucketOperations for Client {
         let bucket_name = bucket_name.as_ref();
 
         if !validate_bucket_name(bucket_name) {
-            return Err(Error::Other(format!("invalid bucket name: {}", bucket_name)));
+            return Err(Error::InvalidBucketName(bucket_name.to_string()));
         }
 
         let request_builder = OssRequest::new().method(RequestMethod::Delete).bucket(bucket_name);
@@ -114,6 +120,93 @@ impl BucketOperations for Client {
 
         Ok(())
     }
+
+    fn put_bucket_transfer_acceleration<S: AsRef<str>>(&self, bucket_name: S, enabled: bool) -> Result<()> {
+        let bucket_name = bucket_name.as_ref();
+
+        if !validate_bucket_name(bucket_name) {
+            return Err(Error::InvalidBucketName(bucket_name.to_string()));
+        }
+
+        let request = build_put_bucket_transfer_acceleration_request(bucket_name, enabled)?;
+
+        self.do_request::<()>(request)?;
+
+        Ok(())
+    }
+
+    fn get_bucket_transfer_acceleration<S: AsRef<str>>(&self, bucket_name: S) -> Result<bool> {
+        let bucket_name = bucket_name.as_ref();
+
+        if !validate_bucket_name(bucket_name) {
+            return Err(Error::InvalidBucketName(bucket_name.to_string()));
+        }
+
+        let request = OssRequest::new()
+            .method(RequestMethod::Get)
+            .bucket(bucket_name)
+            .add_query("transferAcceleration", "");
+
+        let (_, content) = self.do_request::<String>(request)?;
+
+        extract_transfer_acceleration_enabled(&content)
+    }
+
+    fn put_bucket_request_payment<S: AsRef<str>>(&self, bucket_name: S, payer: RequestPayer) -> Result<()> {
+        let bucket_name = bucket_name.as_ref();
+
+        if !validate_bucket_name(bucket_name) {
+            return Err(Error::InvalidBucketName(bucket_name.to_string()));
+        }
+
+        let request = build_put_bucket_request_payment_request(bucket_name, payer)?;
+
+        self.do_request::<()>(request)?;
+
+        Ok(())
+    }
+
+    fn get_bucket_request_payment<S: AsRef<str>>(&self, bucket_name: S) -> Result<RequestPayer> {
+        let bucket_name = bucket_name.as_ref();
+
+        if !validate_bucket_name(bucket_name) {
+            return Err(Error::InvalidBucketName(bucket_name.to_string()));
+        }
+
+        let request = OssRequest::new().method(RequestMethod::Get).bucket(bucket_name).add_query("requestPayment", "");
+
+        let (_, content) = self.do_request::<String>(request)?;
+
+        extract_request_payer(&content)
+    }
+
+    fn put_bucket_access_monitor<S: AsRef<str>>(&self, bucket_name: S, status: AccessMonitor) -> Result<()> {
+        let bucket_name = bucket_name.as_ref();
+
+        if !validate_bucket_name(bucket_name) {
+            return Err(Error::InvalidBucketName(bucket_name.to_string()));
+        }
+
+        let request = build_put_bucket_access_monitor_request(bucket_name, status)?;
+
+        self.do_request::<()>(request)?;
+
+        Ok(())
+    }
+
+    fn get_bucket_access_monitor<S: AsRef<str>>(&self, bucket_name: S) -> Result<AccessMonitor> {
+        let bucket_name = bucket_name.as_ref();
+
+        if !validate_bucket_name(bucket_name) {
+            return Err(Error::InvalidBucketName(bucket_name.to_string()));
+        }
+
+        let request = OssRequest::new().method(RequestMethod::Get).bucket(bucket_name).add_query("accessmonitor", "");
+
+        let (_, content) = self.do_request::<String>(request)?;
+
+        extract_access_monitor_status(&content)
+    }
 }
 
 #[cfg(all(test, feature = "blocking"))]
@@ -0,0 +1,169 @@
+//! Verify the callback requests which are sent from OSS to your callback server
+
+use std::{collections::HashMap, sync::Mutex, time::Duration};
+
+use rsa::RsaPublicKey;
+
+use crate::{
+    callback_common::{parse_public_key, resolve_public_key_url, verify_callback_signature, MAX_CACHED_PUBLIC_KEYS},
+    error::{CallbackSignatureError, Error},
+    Result,
+};
+
+/// Fetch the PEM encoded public key from the url.
+/// Implement this trait to fetch the public key in your own way, e.g. from a local file in tests.
+pub trait PublicKeyFetcher {
+    fn fetch_public_key(&self, url: &str) -> Result<String>;
+}
+
+/// Fetch public key with HTTP request.
+/// The default http client times out after 10 seconds connecting and 30 seconds in total.
+#[derive(Debug, Clone)]
+pub struct HttpPublicKeyFetcher {
+    http_client: reqwest::blocking::Client,
+}
+
+impl Default for HttpPublicKeyFetcher {
+    fn default() -> Self {
+        let http_client = reqwest::blocking::Client::builder()
+            .connect_timeout(Duration::from_secs(10))
+            .timeout(Duration::from_secs(30))
+            .build()
+            .expect("failed to build http client");
+
+        Self { http_client }
+    }
+}
+
+impl HttpPublicKeyFetcher {
+    pub fn new(http_client: reqwest::blocking::Client) -> Self {
+        Self { http_client }
+    }
+}
+
+impl PublicKeyFetcher for HttpPublicKeyFetcher {
+    fn fetch_public_key(&self, url: &str) -> Result<String> {
+        let response = self.http_client.get(url).send()?;
+
+        if !response.status().is_success() {
+            return Err(Error::CallbackSignature(CallbackSignatureError::InvalidPublicKey(format!(
+                "fetch public key from {} failed with status: {}",
+                url,
+                response.status()
+            ))));
+        }
+
+        Ok(response.text()?)
+    }
+}
+
+/// Verify the signature of callback requests sent from OSS.
+///
+/// The public key url is read from the base64 encoded `x-oss-pub-key-url` header,
+/// and only the keys hosted on `gosspublic.alicdn.com` are trusted.
+/// Fetched public keys are cached by url.
+pub struct CallbackVerifier<F: PublicKeyFetcher = HttpPublicKeyFetcher> {
+    fetcher: F,
+    cache: Mutex<HashMap<String, RsaPublicKey>>,
+}
+
+impl CallbackVerifier<HttpPublicKeyFetcher> {
+    pub fn new() -> Self {
+        Self::with_fetcher(HttpPublicKeyFetcher::default())
+    }
+}
+
+impl Default for CallbackVerifier<HttpPublicKeyFetcher> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<F: PublicKeyFetcher> CallbackVerifier<F> {
+    pub fn with_fetcher(fetcher: F) -> Self {
+        Self {
+            fetcher,
+            cache: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Verify the callback request.
+    ///
+    /// - `path_and_query` The request uri, e.g. `/callback?id=1`. The path could be url encoded.
+    /// - `headers` The request headers. Header names are case-insensitive.
+    /// - `body` The raw request body.
+    pub fn verify(&self, path_and_query: &str, headers: &HashMap<String, String>, body: &[u8]) -> Result<()> {
+        let url = resolve_public_key_url(headers)?;
+        let public_key = self.get_public_key(&url)?;
+        verify_callback_signature(&public_key, headers, path_and_query, body)
+    }
+
+    fn get_public_key(&self, url: &str) -> Result<RsaPublicKey> {
+        if let Some(key) = self.cache.lock().unwrap().get(url) {
+            return Ok(key.clone());
+        }
+
+        let pem = self.fetcher.fetch_public_key(url)?;
+        let key = parse_public_key(&pem)?;
+
+        let mut cache = self.cache.lock().unwrap();
+        if cache.len() >= MAX_CACHED_PUBLIC_KEYS {
+            cache.clear();
+        }
+        cache.insert(url.to_string(), key.clone());
+
+        Ok(key)
+    }
+}
+
+#[cfg(test)]
+mod test_callback_blocking {
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+
+    use base64::{prelude::BASE64_STANDARD, Engine};
+
+    use crate::{
+        callback_common::test_callback_common::{signed_headers, BODY, PATH_AND_QUERY, PUBLIC_KEY},
+        error::{CallbackSignatureError, Error},
+        Result,
+    };
+
+    use super::{CallbackVerifier, PublicKeyFetcher};
+
+    struct StaticPublicKeyFetcher {
+        count: Arc<AtomicUsize>,
+    }
+
+    impl PublicKeyFetcher for StaticPublicKeyFetcher {
+        fn fetch_public_key(&self, url: &str) -> Result<String> {
+            assert_eq!("https://gosspublic.alicdn.com/callback_pub_key_v1.pem", url);
+            self.count.fetch_add(1, Ordering::SeqCst);
+            Ok(PUBLIC_KEY.to_string())
+        }
+    }
+
+    #[test]
+    fn test_verify_callback() {
+        let count = Arc::new(AtomicUsize::new(0));
+        let verifier = CallbackVerifier::with_fetcher(StaticPublicKeyFetcher { count: count.clone() });
+
+        verifier.verify(PATH_AND_QUERY, &signed_headers(), BODY.as_bytes()).unwrap();
+        verifier.verify(PATH_AND_QUERY, &signed_headers(), BODY.as_bytes()).unwrap();
+        assert_eq!(1, count.load(Ordering::SeqCst));
+
+        // the query is not part of the cache key, so it can not be used to force fetching
+        let mut headers = signed_headers();
+        headers.insert(
+            "x-oss-pub-key-url".to_string(),
+            BASE64_STANDARD.encode("https://gosspublic.alicdn.com/callback_pub_key_v1.pem?r=1"),
+        );
+        verifier.verify(PATH_AND_QUERY, &headers, BODY.as_bytes()).unwrap();
+        assert_eq!(1, count.load(Ordering::SeqCst));
+
+        let err = verifier.verify("/callback", &signed_headers(), BODY.as_bytes()).unwrap_err();
+        assert!(matches!(err, Error::CallbackSignature(CallbackSignatureError::SignatureMismatch)));
+    }
+}
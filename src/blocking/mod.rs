@@ -4,22 +4,38 @@ use std::{
     io::{Read, Seek},
     path::Path,
     str::FromStr,
+    sync::{
+        atomic::{AtomicI64, Ordering},
+        Arc,
+    },
 };
 
+use base64::{prelude::BASE64_STANDARD, Engine};
 use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
 use url::Url;
 
 use crate::{
+    common::{AccelerateEndpoint, SignatureVersion},
     error::{Error, ErrorResponse},
-    get_region_from_endpoint, hmac_sha256, util, RequestBody, Result,
+    get_region_from_endpoint, hmac_sha256,
+    request::{OssRequest, StreamBody},
+    util::{self, hmac_sha1},
+    RequestBody, Result,
 };
 
 pub mod acl;
 pub mod bucket;
+pub mod callback;
 pub mod cname;
 pub mod multipart;
 pub mod object;
+pub mod object_reader;
+pub mod object_writer;
+pub mod post_object;
 pub mod presign;
+pub mod process;
+pub mod public_access_block;
+pub mod select_object;
 pub mod symlink;
 pub mod tagging;
 
@@ -32,6 +48,10 @@ pub struct ClientBuilder {
     region: Option<String>,
     scheme: Option<String>,
     sts_token: Option<String>,
+    accelerate_endpoint: Option<AccelerateEndpoint>,
+    cname: bool,
+    path_style: bool,
+    signature_version: SignatureVersion,
     client: Option<reqwest::blocking::Client>,
 }
 
@@ -73,6 +93,37 @@ impl ClientBuilder {
         self
     }
 
+    /// Send object related requests (put, get, multipart uploads and presigned urls, etc.) to the transfer acceleration endpoint.
+    /// Requests on bucket configurations are still sent to the `endpoint`.
+    /// The signature is still calculated with the region of the bucket.
+    pub fn accelerate_endpoint(mut self, accelerate_endpoint: AccelerateEndpoint) -> Self {
+        self.accelerate_endpoint = Some(accelerate_endpoint);
+        self
+    }
+
+    /// Set to `true` if the `endpoint` is a custom domain (CNAME) which is bound to a bucket.
+    /// Requests and presigned urls are sent to the custom domain directly without the bucket name prefix.
+    /// The `region` must be set explicitly because it can not be guessed from a custom domain.
+    pub fn cname(mut self, cname: bool) -> Self {
+        self.cname = cname;
+        self
+    }
+
+    /// Set to `true` to use path-style addressing: `scheme://endpoint/bucket/key`.
+    /// This is useful for OSS-compatible servers and private deployments, e.g. `http://127.0.0.1:9000`.
+    /// In such case, the `region` should be set explicitly because it can not be guessed from the endpoint.
+    pub fn path_style(mut self, path_style: bool) -> Self {
+        self.path_style = path_style;
+        self
+    }
+
+    /// Set the signature version. `SignatureVersion::V4` is used by default.
+    /// `SignatureVersion::V1` is only for legacy deployments and OSS-compatible servers which do not support V4.
+    pub fn signature_version(mut self, signature_version: SignatureVersion) -> Self {
+        self.signature_version = signature_version;
+        self
+    }
+
     /// You can build your own `reqwest::Client` and set to the OSS client.
     /// I do not expose each option of `reqwest::Client` because there are many options to build a `reqwest::Client`.
     pub fn client(mut self, client: reqwest::blocking::Client) -> Self {
@@ -93,6 +144,10 @@ impl ClientBuilder {
             region,
             scheme,
             sts_token,
+            accelerate_endpoint,
+            cname,
+            path_style,
+            signature_version,
             client,
         } = self;
 
@@ -127,7 +182,12 @@ impl ClientBuilder {
             region,
             scheme,
             sts_token,
+            accelerate_endpoint,
+            cname,
+            path_style,
+            signature_version,
             blocking_http_client: if let Some(c) = client { c } else { reqwest::blocking::Client::new() },
+            clock_offset: Arc::new(AtomicI64::new(0)),
         })
     }
 }
@@ -140,7 +200,15 @@ pub struct Client {
     endpoint: String,
     scheme: String,
     sts_token: Option<String>,
+    accelerate_endpoint: Option<AccelerateEndpoint>,
+    cname: bool,
+    path_style: bool,
+    signature_version: SignatureVersion,
     blocking_http_client: reqwest::blocking::Client,
+
+    /// Offset in seconds of the server clock to the local clock, learned from `RequestTimeTooSkewed` errors.
+    /// It is shared by the clients cloned by `clone_to`.
+    clock_offset: Arc<AtomicI64>,
 }
 
 impl Client {
@@ -206,7 +274,12 @@ impl Client {
             endpoint: lc_endpoint,
             scheme,
             sts_token: None,
+            accelerate_endpoint: None,
+            cname: false,
+            path_style: false,
+            signature_version: SignatureVersion::V4,
             blocking_http_client: reqwest::blocking::Client::new(),
+            clock_offset: Arc::new(AtomicI64::new(0)),
         }
     }
 
@@ -221,27 +294,24 @@ impl Client {
         hex::encode(hmac_sha256(&signing_key, string_to_sign.as_bytes()))
     }
 
-    /// Some of the strings are used multiple times,
-    /// So I put them in this method to prevent re-generating
-    /// and better debuging output.
-    /// And add some default headers to the request builder.
-    fn do_request<T>(&self, mut oss_request: crate::request::OssRequest) -> Result<(HashMap<String, String>, T)>
-    where
-        T: FromResponse,
-    {
-        // check if sign `host` header
-        if oss_request.additional_headers.contains("host") {
-            let host = if oss_request.bucket_name.is_empty() {
-                self.endpoint.clone()
-            } else {
-                format!("{}.{}", oss_request.bucket_name, self.endpoint)
-            };
+    /// Calculate the signature of signature version 1: base64 encoded HMAC-SHA1 of the string to sign
+    fn calculate_signature_v1(&self, string_to_sign: &str) -> String {
+        BASE64_STANDARD.encode(hmac_sha1(self.access_key_secret.as_bytes(), string_to_sign.as_bytes()))
+    }
 
-            oss_request.headers_mut().insert("host".to_string(), host);
-        }
+    /// Build the `authorization` header value with the signature version of the client.
+    /// `http_date` is the `Date` header which is used in the string to sign of signature version 1.
+    fn build_authorization(&self, oss_request: &mut OssRequest, http_date: &str) -> String {
+        if self.signature_version == SignatureVersion::V1 {
+            // these headers are for signature version 4 only
+            oss_request.headers_mut().remove("x-oss-date");
+            oss_request.headers_mut().remove("x-oss-content-sha256");
 
-        if let Some(s) = &self.sts_token {
-            oss_request.headers_mut().insert("x-oss-security-token".to_string(), s.to_string());
+            let string_to_sign = oss_request.build_string_to_sign_v1(http_date);
+
+            log::debug!("string to sign: \n--------\n{}\n--------", string_to_sign);
+
+            return format!("OSS {}:{}", self.access_key_id, self.calculate_signature_v1(&string_to_sign));
         }
 
         let date_time_string = oss_request.headers.get("x-oss-date").unwrap();
@@ -257,7 +327,7 @@ impl Client {
 
         log::debug!("signature: {}", sig);
 
-        let auth_string = format!(
+        format!(
             "OSS4-HMAC-SHA256 Credential={}/{}/{}/oss/aliyun_v4_request,{}Signature={}",
             self.access_key_id,
             date_string,
@@ -268,33 +338,110 @@ impl Client {
                 format!("{},", additional_headers)
             },
             sig
-        );
-
-        let mut header_map = HeaderMap::new();
+        )
+    }
 
-        for (k, v) in oss_request.headers.iter() {
-            header_map.insert(HeaderName::from_str(k)?, HeaderValue::from_str(v)?);
+    /// The host (without scheme) which the request is sent to.
+    /// Requests on objects are sent to the transfer acceleration endpoint if it is set.
+    /// In cname mode, all requests are sent to the custom domain without bucket name prefix.
+    /// In path-style mode, the bucket name is put in the path instead of the host.
+    fn request_host(&self, oss_request: &OssRequest) -> String {
+        if self.cname {
+            return self.endpoint.clone();
         }
 
-        let http_date = util::get_http_date();
+        let endpoint = match &self.accelerate_endpoint {
+            Some(e) if !oss_request.object_key.is_empty() => e.as_str(),
+            _ => self.endpoint.as_str(),
+        };
 
-        header_map.insert(HeaderName::from_static("authorization"), HeaderValue::from_str(&auth_string)?);
-        header_map.insert(HeaderName::from_static("date"), HeaderValue::from_str(&http_date)?);
+        if oss_request.bucket_name.is_empty() || self.path_style {
+            endpoint.to_string()
+        } else {
+            format!("{}.{}", oss_request.bucket_name, endpoint)
+        }
+    }
 
+    /// Build the full url of the request, including the query string.
+    fn request_url(&self, oss_request: &OssRequest) -> String {
         let uri = oss_request.build_request_uri();
         let query_string = oss_request.build_canonical_query_string();
 
-        let domain_name = if oss_request.bucket_name.is_empty() {
-            format!("{}://{}{}", self.scheme, self.endpoint, uri)
+        let uri = if self.path_style && !self.cname && !oss_request.bucket_name.is_empty() {
+            format!("/{}{}", oss_request.bucket_name, uri)
         } else {
-            format!("{}://{}.{}{}", self.scheme, oss_request.bucket_name, self.endpoint, uri)
+            uri
         };
 
-        let full_url = if query_string.is_empty() {
-            domain_name
+        let url = format!("{}://{}{}", self.scheme, self.request_host(oss_request), uri);
+
+        if query_string.is_empty() {
+            url
         } else {
-            format!("{}?{}", domain_name, query_string)
-        };
+            format!("{}?{}", url, query_string)
+        }
+    }
+
+    /// Send the request. If it fails with `RequestTimeTooSkewed` because of the local clock,
+    /// the clock offset is learned from the error and the request is sent once again if its body could be sent again.
+    fn do_request<T>(&self, oss_request: crate::request::OssRequest) -> Result<(HashMap<String, String>, T)>
+    where
+        T: FromResponse,
+    {
+        let retry_request = oss_request.try_clone();
+
+        match self.send_request(oss_request) {
+            Err(e) => match e.server_clock_offset() {
+                Some(offset) => {
+                    log::warn!("request time too skewed, clock offset of server to local is {} seconds", offset);
+                    self.clock_offset.store(offset, Ordering::Relaxed);
+
+                    match retry_request {
+                        Some(r) => self.send_request(r),
+                        None => Err(e),
+                    }
+                }
+                None => Err(e),
+            },
+            ret => ret,
+        }
+    }
+
+    /// Some of the strings are used multiple times,
+    /// So I put them in this method to prevent re-generating
+    /// and better debuging output.
+    /// And add some default headers to the request builder.
+    fn send_request<T>(&self, mut oss_request: crate::request::OssRequest) -> Result<(HashMap<String, String>, T)>
+    where
+        T: FromResponse,
+    {
+        oss_request.shift_date(self.clock_offset());
+
+        // check if sign `host` header
+        if oss_request.additional_headers.contains("host") {
+            let host = self.request_host(&oss_request);
+
+            oss_request.headers_mut().insert("host".to_string(), host);
+        }
+
+        if let Some(s) = &self.sts_token {
+            oss_request.headers_mut().insert("x-oss-security-token".to_string(), s.to_string());
+        }
+
+        let http_date = util::get_http_date_with_offset(self.clock_offset());
+
+        let auth_string = self.build_authorization(&mut oss_request, &http_date);
+
+        let mut header_map = HeaderMap::new();
+
+        for (k, v) in oss_request.headers.iter() {
+            header_map.insert(HeaderName::from_str(k)?, HeaderValue::from_str(v)?);
+        }
+
+        header_map.insert(HeaderName::from_static("authorization"), HeaderValue::from_str(&auth_string)?);
+        header_map.insert(HeaderName::from_static("date"), HeaderValue::from_str(&http_date)?);
+
+        let full_url = self.request_url(&oss_request);
 
         log::debug!("full url: {}", full_url);
 
@@ -303,8 +450,18 @@ impl Client {
             .request(oss_request.method.into(), Url::parse(&full_url)?)
             .headers(header_map);
 
+        // streaming body is sent instead of `body`
+        let body = match oss_request.stream_body {
+            Some(StreamBody::Reader(body)) => {
+                req_builder = req_builder.body(body);
+                RequestBody::Empty
+            }
+            Some(StreamBody::Stream(_)) => return Err(Error::InvalidArgument("async stream body is not supported by blocking client".to_string())),
+            None => oss_request.body,
+        };
+
         // 根据 body 类型设置请求体
-        req_builder = match oss_request.body {
+        req_builder = match body {
             RequestBody::Empty => req_builder,
             RequestBody::Text(text) => req_builder.body(text),
             RequestBody::Bytes(bytes) => req_builder.body(bytes),
@@ -323,6 +480,15 @@ impl Client {
 
         let req = req_builder.build()?;
 
+        self.execute_request(req, &full_url)
+    }
+
+    /// Send the built request and parse the response.
+    /// Non-2xx responses are converted to errors with the error response body if there is one.
+    fn execute_request<T>(&self, req: reqwest::blocking::Request, full_url: &str) -> Result<(HashMap<String, String>, T)>
+    where
+        T: FromResponse,
+    {
         let response = self.blocking_http_client.execute(req)?;
 
         let mut response_headers = HashMap::new();
@@ -341,15 +507,15 @@ impl Client {
                     log::error!("{}", s);
                     if s.is_empty() {
                         log::error!("call api failed with status: \"{}\". full url: {}", status, full_url);
-                        Err(Error::StatusError(status))
+                        Err(Error::from_api_response(status, &response_headers, None))
                     } else {
                         let error_response = ErrorResponse::from_xml(&s)?;
-                        Err(Error::ApiError(Box::new(error_response)))
+                        Err(Error::from_api_response(status, &response_headers, Some(error_response)))
                     }
                 }
                 Err(_) => {
                     log::error!("call api failed with status: \"{}\". full url: {}", status, full_url);
-                    Err(Error::StatusError(status))
+                    Err(Error::from_api_response(status, &response_headers, None))
                 }
             }
         } else {
@@ -377,9 +543,20 @@ impl Client {
             endpoint: endpoint.to_string(),
             scheme: self.scheme.clone(),
             sts_token: self.sts_token.clone(),
+            accelerate_endpoint: self.accelerate_endpoint,
+            cname: self.cname,
+            path_style: self.path_style,
+            signature_version: self.signature_version,
             blocking_http_client: self.blocking_http_client.clone(),
+            clock_offset: self.clock_offset.clone(),
         }
     }
+
+    /// The offset in seconds of the server clock to the local clock.
+    /// It is learned from `RequestTimeTooSkewed` errors and applied to the signing time of later requests and presigned urls.
+    pub fn clock_offset(&self) -> i64 {
+        self.clock_offset.load(Ordering::Relaxed)
+    }
 }
 
 pub(crate) trait FromResponse: Sized {
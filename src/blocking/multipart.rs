@@ -1,3 +1,4 @@
+use std::io::Read;
 use std::ops::Range;
 use std::path::Path;
 
@@ -6,15 +7,15 @@ use base64::Engine;
 
 use crate::error::Error;
 use crate::multipart_common::{
-    build_complete_multipart_uploads_request, build_initiate_multipart_uploads_request, build_list_multipart_uploads_request, build_list_parts_request,
-    build_upload_part_copy_request, build_upload_part_request, CompleteMultipartUploadApiResponse, CompleteMultipartUploadOptions,
+    build_abort_multipart_uploads_request, build_complete_multipart_uploads_request, build_initiate_multipart_uploads_request,
+    build_list_multipart_uploads_request, build_list_parts_request, build_upload_part_copy_request, build_upload_part_request,
+    build_upload_part_stream_request, AbortMultipartUploadOptions, CompleteMultipartUploadApiResponse, CompleteMultipartUploadOptions,
     CompleteMultipartUploadRequest, CompleteMultipartUploadResult, InitiateMultipartUploadOptions, InitiateMultipartUploadResult, ListMultipartUploadsOptions,
     ListMultipartUploadsResult, ListPartsOptions, ListPartsResult, UploadPartCopyOptions, UploadPartCopyRequest, UploadPartCopyResult, UploadPartRequest,
     UploadPartResult,
 };
-use crate::request::{OssRequest, RequestMethod};
-use crate::util::{validate_bucket_name, validate_object_key};
-use crate::{RequestBody, Result};
+use crate::util::validate_bucket_name;
+use crate::{RequestBody, Result, StreamBody};
 
 use super::Client;
 
@@ -82,6 +83,23 @@ pub trait MultipartUploadsOperations {
         S2: AsRef<str>,
         S3: AsRef<str>;
 
+    /// Upload part from a reader.
+    /// If `content_length` is `None`, the data is uploaded with chunked transfer encoding.
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/uploadpart>
+    fn upload_part_from_reader<S1, S2, R>(
+        &self,
+        bucket_name: S1,
+        object_key: S2,
+        reader: R,
+        content_length: Option<u64>,
+        params: UploadPartRequest,
+    ) -> Result<UploadPartResult>
+    where
+        S1: AsRef<str>,
+        S2: AsRef<str>,
+        R: Read + Send + 'static;
+
     /// When you want to copy a file larger than 1GB, you must use `upload_part_copy`.
     /// First, initiate a multipart upload and get `uploadId`, then call this method to upload parts of the source object.
     /// Finally complete the multipart upload by invoking `complete_multipart_uploads`
@@ -115,7 +133,7 @@ pub trait MultipartUploadsOperations {
     /// About multipart uploads
     ///
     /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/abortmultipartupload>
-    fn abort_multipart_uploads<S1, S2, S3>(&self, bucket_name: S1, object_key: S2, upload_id: S3) -> Result<()>
+    fn abort_multipart_uploads<S1, S2, S3>(&self, bucket_name: S1, object_key: S2, upload_id: S3, options: Option<AbortMultipartUploadOptions>) -> Result<()>
     where
         S1: AsRef<str>,
         S2: AsRef<str>,
@@ -131,7 +149,7 @@ impl MultipartUploadsOperations for Client {
         S: AsRef<str>,
     {
         if !validate_bucket_name(bucket_name.as_ref()) {
-            return Err(Error::Other(format!("invalid bucket name: {}", bucket_name.as_ref())));
+            return Err(Error::InvalidBucketName(bucket_name.as_ref().to_string()));
         }
         let request = build_list_multipart_uploads_request(bucket_name.as_ref(), &options)?;
         let (_, xml) = self.do_request::<String>(request)?;
@@ -228,6 +246,35 @@ impl MultipartUploadsOperations for Client {
         self.upload_part_from_buffer(bucket_name, object_key, data, params)
     }
 
+    /// Upload part from a reader.
+    /// If `content_length` is `None`, the data is uploaded with chunked transfer encoding.
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/uploadpart>
+    fn upload_part_from_reader<S1, S2, R>(
+        &self,
+        bucket_name: S1,
+        object_key: S2,
+        reader: R,
+        content_length: Option<u64>,
+        params: UploadPartRequest,
+    ) -> Result<UploadPartResult>
+    where
+        S1: AsRef<str>,
+        S2: AsRef<str>,
+        R: Read + Send + 'static,
+    {
+        let body = match content_length {
+            Some(len) => reqwest::blocking::Body::sized(reader, len),
+            None => reqwest::blocking::Body::new(reader),
+        };
+
+        let request = build_upload_part_stream_request(bucket_name.as_ref(), object_key.as_ref(), StreamBody::Reader(body), content_length, params)?;
+
+        let (headers, _) = self.do_request::<()>(request)?;
+
+        Ok(headers.into())
+    }
+
     /// When you want to copy a file larger than 1GB, you must use `upload_part_copy`.
     /// First, initiate a multipart upload and get `uploadId`, then call this method to upload parts of the source object.
     /// Finally complete the multipart upload by invoking `complete_multipart_uploads`
@@ -282,32 +329,13 @@ impl MultipartUploadsOperations for Client {
     /// About multipart uploads
     ///
     /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/abortmultipartupload>
-    fn abort_multipart_uploads<S1, S2, S3>(&self, bucket_name: S1, object_key: S2, upload_id: S3) -> Result<()>
+    fn abort_multipart_uploads<S1, S2, S3>(&self, bucket_name: S1, object_key: S2, upload_id: S3, options: Option<AbortMultipartUploadOptions>) -> Result<()>
     where
         S1: AsRef<str>,
         S2: AsRef<str>,
         S3: AsRef<str>,
     {
-        let bucket_name = bucket_name.as_ref();
-        let object_key = object_key.as_ref();
-
-        if !validate_bucket_name(bucket_name) {
-            return Err(Error::Other(format!("invalid bucket name: {}", bucket_name)));
-        }
-
-        if !validate_object_key(object_key) {
-            return Err(Error::Other(format!("invalid object key: {}", object_key)));
-        }
-
-        if upload_id.as_ref().is_empty() {
-            return Err(Error::Other("invalid upload id: [empty]".to_string()));
-        }
-
-        let request = OssRequest::new()
-            .method(RequestMethod::Delete)
-            .bucket(bucket_name)
-            .object(object_key)
-            .add_query("uploadId", upload_id);
+        let request = build_abort_multipart_uploads_request(bucket_name.as_ref(), object_key.as_ref(), upload_id.as_ref(), &options)?;
 
         self.do_request::<()>(request)?;
 
@@ -431,6 +459,7 @@ mod test_multipart_blocking {
             let upload_data = UploadPartRequest {
                 part_number: (i + 1) as u32,
                 upload_id: upload_id.clone(),
+                ..Default::default()
             };
 
             log::debug!("begin to upload part {}", i);
@@ -527,6 +556,7 @@ mod test_multipart_blocking {
             let upload_data = UploadPartRequest {
                 part_number: part_no,
                 upload_id: upload_id.clone(),
+                ..Default::default()
             };
 
             let upload_response = client.upload_part_from_buffer(bucket, &object, buf, upload_data);
@@ -546,7 +576,7 @@ mod test_multipart_blocking {
 
         assert_eq!(ranges.len(), list_parts_result.parts.len());
 
-        let abort_response = client.abort_multipart_uploads(bucket, &object, upload_id.clone());
+        let abort_response = client.abort_multipart_uploads(bucket, &object, upload_id.clone(), None);
         log::debug!("{:#?}", abort_response);
         assert!(abort_response.is_ok());
 
@@ -576,7 +606,7 @@ mod test_multipart_blocking {
             bucket,
             &dest_object_key,
             UploadPartCopyRequest::new(1, &upload_id, source_object_key),
-            Some(UploadPartCopyOptionsBuilder::new().copy_source_range("bytes=0-185000").build()),
+            Some(UploadPartCopyOptionsBuilder::new().copy_source_range(0..=185000).build()),
         );
         assert!(upload_response.is_ok());
         log::debug!("upload response 1: {:#?}", upload_response);
@@ -587,7 +617,7 @@ mod test_multipart_blocking {
             bucket,
             &dest_object_key,
             UploadPartCopyRequest::new(2, &upload_id, source_object_key),
-            Some(UploadPartCopyOptionsBuilder::new().copy_source_range("bytes=185001-").build()),
+            Some(UploadPartCopyOptionsBuilder::new().copy_source_range(185001..).build()),
         );
         assert!(upload_response.is_ok());
         log::debug!("upload response 2: {:#?}", upload_response);
@@ -654,6 +684,7 @@ mod test_multipart_blocking {
             let upload_data = UploadPartRequest {
                 part_number: (i + 1) as u32,
                 upload_id: upload_id.clone(),
+                ..Default::default()
             };
 
             log::debug!("begin to upload part {}", i);
@@ -695,7 +726,10 @@ mod test_multipart_blocking {
             .custom_variable("v1", "this is value of v1")
             .build();
 
-        let options = CompleteMultipartUploadOptions { callback: Some(cb) };
+        let options = CompleteMultipartUploadOptions {
+            callback: Some(cb),
+            ..Default::default()
+        };
 
         let comp_response = client.complete_multipart_uploads(
             bucket,
@@ -1,19 +1,19 @@
-use std::path::Path;
+use std::{io::Read, path::Path};
 
 use base64::{prelude::BASE64_STANDARD, Engine};
 use reqwest::StatusCode;
 
 use crate::{
-    error::Error,
+    error::{ApiErrorCode, Error},
     object_common::{
         build_copy_object_request, build_delete_multiple_objects_request, build_get_object_request, build_head_object_request, build_put_object_request,
-        build_restore_object_request, AppendObjectOptions, AppendObjectResult, CopyObjectOptions, CopyObjectResult, DeleteMultipleObjectsConfig,
-        DeleteMultipleObjectsResult, DeleteObjectOptions, DeleteObjectResult, GetObjectMetadataOptions, GetObjectOptions, GetObjectResult, HeadObjectOptions,
-        ObjectMetadata, PutObjectOptions, PutObjectResult, RestoreObjectRequest, RestoreObjectResult,
+        build_put_object_stream_request, build_restore_object_request, AppendObjectOptions, AppendObjectResult, CopyObjectOptions, CopyObjectResult,
+        DeleteMultipleObjectsConfig, DeleteMultipleObjectsResult, DeleteObjectOptions, DeleteObjectResult, GetObjectMetadataOptions, GetObjectOptions,
+        GetObjectResult, HeadObjectOptions, ObjectMetadata, PutObjectOptions, PutObjectResult, RestoreObjectRequest, RestoreObjectResult,
     },
     request::{OssRequest, RequestMethod},
     util::{validate_bucket_name, validate_object_key, validate_path},
-    RequestBody, Result,
+    RequestBody, Result, StreamBody,
 };
 
 use super::{BytesBody, Client};
@@ -54,6 +54,24 @@ pub trait ObjectOperations {
         S2: AsRef<str>,
         S3: AsRef<str>;
 
+    /// Create an object from a reader, e.g. an archive being built on the fly.
+    /// If `content_length` is `None`, the data is uploaded with chunked transfer encoding.
+    /// And, it is recommended to set `mime_type` in `options`
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/putobject>
+    fn put_object_from_reader<S1, S2, R>(
+        &self,
+        bucket_name: S1,
+        object_key: S2,
+        reader: R,
+        content_length: Option<u64>,
+        options: Option<PutObjectOptions>,
+    ) -> Result<PutObjectResult>
+    where
+        S1: AsRef<str>,
+        S2: AsRef<str>,
+        R: Read + Send + 'static;
+
     /// Append object.
     ///
     /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/appendobject>
@@ -113,10 +131,10 @@ pub trait ObjectOperations {
         S2: AsRef<str>,
         P: AsRef<Path>;
 
-    /// Get object content into memory (bytes array).
+    /// Get object content into memory (bytes array), along with metadata parsed from response headers.
     ///
     /// Large files can consume significant memory, exercise caution when using this function.
-    fn get_object_to_buffer<S1, S2>(&self, bucket_name: S1, object_key: S2, options: Option<GetObjectOptions>) -> Result<Vec<u8>>
+    fn get_object_to_buffer<S1, S2>(&self, bucket_name: S1, object_key: S2, options: Option<GetObjectOptions>) -> Result<(GetObjectResult, Vec<u8>)>
     where
         S1: AsRef<str> + Send,
         S2: AsRef<str> + Send;
@@ -290,12 +308,54 @@ impl ObjectOperations for Client {
         let data = if let Ok(d) = BASE64_STANDARD.decode(base64_string.as_ref()) {
             d
         } else {
-            return Err(Error::Other("Decoding base64 string failed".to_string()));
+            return Err(Error::InvalidArgument("Decoding base64 string failed".to_string()));
         };
 
         self.put_object_from_buffer(bucket_name, object_key, data, options)
     }
 
+    /// Create an object from a reader, e.g. an archive being built on the fly.
+    /// If `content_length` is `None`, the data is uploaded with chunked transfer encoding.
+    /// And, it is recommended to set `mime_type` in `options`
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/putobject>
+    fn put_object_from_reader<S1, S2, R>(
+        &self,
+        bucket_name: S1,
+        object_key: S2,
+        reader: R,
+        content_length: Option<u64>,
+        options: Option<PutObjectOptions>,
+    ) -> Result<PutObjectResult>
+    where
+        S1: AsRef<str>,
+        S2: AsRef<str>,
+        R: Read + Send + 'static,
+    {
+        let bucket_name = bucket_name.as_ref();
+        let object_key = object_key.as_ref();
+
+        let object_key = object_key.strip_prefix("/").unwrap_or(object_key);
+        let object_key = object_key.strip_suffix("/").unwrap_or(object_key);
+
+        let with_callback = if let Some(opt) = &options { opt.callback.is_some() } else { false };
+
+        let body = match content_length {
+            Some(len) => reqwest::blocking::Body::sized(reader, len),
+            None => reqwest::blocking::Body::new(reader),
+        };
+
+        let request = build_put_object_stream_request(bucket_name, object_key, StreamBody::Reader(body), content_length, &options)?;
+
+        let (headers, content) = self.do_request::<String>(request)?;
+
+        if with_callback {
+            Ok(PutObjectResult::CallbackResponse(content))
+        } else {
+            Ok(PutObjectResult::ApiResponse(headers.into()))
+        }
+    }
+
     /// Append object.
     ///
     /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/appendobject>
@@ -389,7 +449,7 @@ impl ObjectOperations for Client {
         let data = if let Ok(d) = BASE64_STANDARD.decode(base64_string.as_ref()) {
             d
         } else {
-            return Err(Error::Other("Decoding base64 string failed".to_string()));
+            return Err(Error::InvalidArgument("Decoding base64 string failed".to_string()));
         };
 
         self.append_object_from_buffer(bucket_name, object_key, data, position, options)
@@ -415,7 +475,7 @@ impl ObjectOperations for Client {
         };
 
         if !validate_path(&file_path) {
-            return Err(Error::Other(format!("invalid file path: {:?}", file_path.as_os_str().to_str())));
+            return Err(Error::InvalidArgument(format!("invalid file path: {:?}", file_path.as_os_str().to_str())));
         }
 
         // check parent path
@@ -427,17 +487,17 @@ impl ObjectOperations for Client {
 
         let request = build_get_object_request(bucket_name, object_key, &options)?;
 
-        let (_, mut stream) = self.do_request::<BytesBody>(request)?;
+        let (headers, mut stream) = self.do_request::<BytesBody>(request)?;
 
         stream.save_to_file(file_path)?;
 
-        Ok(GetObjectResult)
+        Ok(headers.into())
     }
 
-    /// Get object content into memory (bytes array).
+    /// Get object content into memory (bytes array), along with metadata parsed from response headers.
     ///
     /// Large files can consume significant memory, exercise caution when using this function.
-    fn get_object_to_buffer<S1, S2>(&self, bucket_name: S1, object_key: S2, options: Option<GetObjectOptions>) -> Result<Vec<u8>>
+    fn get_object_to_buffer<S1, S2>(&self, bucket_name: S1, object_key: S2, options: Option<GetObjectOptions>) -> Result<(GetObjectResult, Vec<u8>)>
     where
         S1: AsRef<str> + Send,
         S2: AsRef<str> + Send
@@ -447,9 +507,9 @@ impl ObjectOperations for Client {
 
         let request = build_get_object_request(bucket_name, object_key, &options)?;
 
-        let (_, stream) = self.do_request::<BytesBody>(request)?;
+        let (headers, stream) = self.do_request::<BytesBody>(request)?;
 
-        stream.save_to_buffer()
+        Ok((headers.into(), stream.save_to_buffer()?))
     }
 
     /// Create a "folder"
@@ -470,7 +530,7 @@ impl ObjectOperations for Client {
         };
 
         if !validate_bucket_name(bucket_name) {
-            return Err(Error::Other(format!("invalid bucket name: {}", bucket_name)));
+            return Err(Error::InvalidBucketName(bucket_name.to_string()));
         }
 
         let request = OssRequest::new()
@@ -503,7 +563,7 @@ impl ObjectOperations for Client {
         };
 
         if !validate_bucket_name(bucket_name) {
-            return Err(Error::Other(format!("invalid bucket name: {}", bucket_name)));
+            return Err(Error::InvalidBucketName(bucket_name.to_string()));
         }
 
         let request = OssRequest::new().method(RequestMethod::Delete).bucket(bucket_name).object(object_key);
@@ -525,11 +585,11 @@ impl ObjectOperations for Client {
         let object_key = object_key.as_ref();
 
         if !validate_bucket_name(bucket_name) {
-            return Err(Error::Other(format!("invalid bucket name: {}", bucket_name)));
+            return Err(Error::InvalidBucketName(bucket_name.to_string()));
         }
 
         if !validate_object_key(object_key) {
-            return Err(Error::Other(format!("invalid object key: {}", object_key)));
+            return Err(Error::InvalidObjectKey(object_key.to_string()));
         }
 
         let mut request = OssRequest::new()
@@ -559,7 +619,8 @@ impl ObjectOperations for Client {
         match self.get_object_metadata(bucket_name, object_key, options) {
             Ok(_) => Ok(true),
             Err(e) => match e {
-                Error::StatusError(status) if status == StatusCode::NOT_FOUND => Ok(false),
+                Error::StatusError { status, .. } if status == StatusCode::NOT_FOUND => Ok(false),
+                Error::ApiError(ref r) if r.error_code() == ApiErrorCode::NoSuchKey => Ok(false),
                 _ => Err(e),
             },
         }
@@ -624,11 +685,11 @@ impl ObjectOperations for Client {
         let object_key = object_key.as_ref();
 
         if !validate_bucket_name(bucket_name) {
-            return Err(Error::Other(format!("invalid bucket name: {}", bucket_name)));
+            return Err(Error::InvalidBucketName(bucket_name.to_string()));
         }
 
         if !validate_object_key(object_key) {
-            return Err(Error::Other(format!("invalid object key: {}", object_key)));
+            return Err(Error::InvalidObjectKey(object_key.to_string()));
         }
 
         let mut request = OssRequest::new().method(RequestMethod::Delete).bucket(bucket_name).object(object_key);
@@ -684,11 +745,11 @@ impl ObjectOperations for Client {
         let object_key = object_key.as_ref();
 
         if !validate_bucket_name(bucket_name) {
-            return Err(Error::Other(format!("invalid bucket name: {}", bucket_name)));
+            return Err(Error::InvalidBucketName(bucket_name.to_string()));
         }
 
         if !validate_object_key(object_key) {
-            return Err(Error::Other(format!("invalid object key: {}", object_key)));
+            return Err(Error::InvalidObjectKey(object_key.to_string()));
         }
         let request = OssRequest::new()
             .method(RequestMethod::Post)
@@ -910,7 +971,7 @@ mod test_object_blocking {
 
         let output_file = format!("/home/yuanyq/Downloads/ali-oss-rs-test/{}.zip.1", Uuid::new_v4());
 
-        let options = GetObjectOptionsBuilder::new().range("bytes=0-499").build();
+        let options = GetObjectOptionsBuilder::new().range(0..=499).build();
 
         let result = client.get_object_to_file("yuanyq", "rust-sdk-test/katex.zip", &output_file, Some(options));
 
@@ -935,7 +996,7 @@ mod test_object_blocking {
         ];
 
         for output_file in invalid_files {
-            let options = GetObjectOptionsBuilder::new().range("bytes=0-499").build();
+            let options = GetObjectOptionsBuilder::new().range(0..=499).build();
 
             let result = client.get_object_to_file("yuanyq", "rust-sdk-test/katex.zip", output_file, Some(options));
 
@@ -952,9 +1013,11 @@ mod test_object_blocking {
         let bucket_name = "mi-dev-public";
         let object_key = "quiz_template.xls";
         // 9b98f68671ec239958e58a6813960ab5
-        let buf = client.get_object_to_buffer(bucket_name, object_key, None).unwrap();
+        let (result, buf) = client.get_object_to_buffer(bucket_name, object_key, None).unwrap();
         let d = md5::compute(&buf).to_vec();
         assert_eq!(hex::encode(&d), "9b98f68671ec239958e58a6813960ab5");
+        assert_eq!(buf.len() as u64, result.metadata.content_length);
+        assert!(!result.metadata.etag.is_empty());
     }
 
     #[test]
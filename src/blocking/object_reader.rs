@@ -0,0 +1,242 @@
+//! An object reader implementing `std::io::Read` and `std::io::Seek`.
+//!
+//! The length and ETag of the object are retrieved by `head_object` when the reader is created.
+//! Data is read with ranged get object requests, at least `read_ahead_size` bytes each time,
+//! and `If-Match` is sent with the ETag so that an overwrite during reading is detected.
+
+use std::io::{Read, Seek, SeekFrom};
+
+use crate::{
+    object_reader_common::{
+        build_head_options, build_range_options, calculate_fetch_end, calculate_seek_position, validate_object_reader_params, ObjectReaderOptions,
+    },
+    Result,
+};
+
+use super::{object::ObjectOperations, Client};
+
+/// Read an object with random access. See module level document for details.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::io::{Read, Seek};
+/// use ali_oss_rs::blocking::object_reader::ObjectReader;
+///
+/// fn read_footer(client: &ali_oss_rs::blocking::Client) -> ali_oss_rs::Result<Vec<u8>> {
+///     let mut reader = ObjectReader::new(client, "example-bucket", "path/to/data.parquet", None)?;
+///     reader.seek(std::io::SeekFrom::End(-8))?;
+///     let mut footer = vec![0u8; 8];
+///     reader.read_exact(&mut footer)?;
+///     Ok(footer)
+/// }
+/// ```
+pub struct ObjectReader<'a> {
+    client: &'a Client,
+    bucket_name: String,
+    object_key: String,
+    options: ObjectReaderOptions,
+    len: u64,
+    etag: String,
+    pos: u64,
+
+    /// Buffered data and the position of its first byte in the object
+    buffer: Vec<u8>,
+    buffer_start: u64,
+}
+
+impl<'a> ObjectReader<'a> {
+    /// Create a new object reader. The length and ETag of the object are retrieved by `head_object`.
+    pub fn new<S1, S2>(client: &'a Client, bucket_name: S1, object_key: S2, options: Option<ObjectReaderOptions>) -> Result<Self>
+    where
+        S1: AsRef<str>,
+        S2: AsRef<str>,
+    {
+        let bucket_name = bucket_name.as_ref();
+        let object_key = object_key.as_ref();
+        let options = options.unwrap_or_default();
+
+        validate_object_reader_params(bucket_name, object_key, &options)?;
+
+        let metadata = client.head_object(bucket_name, object_key, Some(build_head_options(&options)))?;
+
+        Ok(Self {
+            client,
+            bucket_name: bucket_name.to_string(),
+            object_key: object_key.to_string(),
+            options,
+            len: metadata.content_length,
+            etag: metadata.etag,
+            pos: 0,
+            buffer: vec![],
+            buffer_start: 0,
+        })
+    }
+
+    /// The length of the object
+    pub fn len(&self) -> u64 {
+        self.len
+    }
+
+    /// Returns `true` if the object is empty
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The ETag of the object when the reader was created
+    pub fn etag(&self) -> &str {
+        &self.etag
+    }
+}
+
+impl Read for ObjectReader<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        if self.pos >= self.len || buf.is_empty() {
+            return Ok(0);
+        }
+
+        let buffer_end = self.buffer_start + self.buffer.len() as u64;
+
+        if self.pos < self.buffer_start || self.pos >= buffer_end {
+            let end = calculate_fetch_end(self.pos, self.len, self.options.read_ahead_size, buf.len());
+            let options = build_range_options(&self.options, &self.etag, self.pos, end);
+
+            let (_, data) = self
+                .client
+                .get_object_to_buffer(&self.bucket_name, &self.object_key, Some(options))
+                .map_err(std::io::Error::other)?;
+
+            if data.is_empty() {
+                return Err(std::io::Error::new(std::io::ErrorKind::UnexpectedEof, "ranged get object returns no data"));
+            }
+
+            self.buffer = data;
+            self.buffer_start = self.pos;
+        }
+
+        let offset = (self.pos - self.buffer_start) as usize;
+        let n = buf.len().min(self.buffer.len() - offset);
+        buf[..n].copy_from_slice(&self.buffer[offset..offset + n]);
+        self.pos += n as u64;
+
+        Ok(n)
+    }
+}
+
+impl Seek for ObjectReader<'_> {
+    fn seek(&mut self, pos: SeekFrom) -> std::io::Result<u64> {
+        self.pos = calculate_seek_position(self.pos, self.len, pos)?;
+        Ok(self.pos)
+    }
+}
+
+#[cfg(test)]
+mod test_object_reader_blocking {
+    use std::io::{Read, Seek, SeekFrom};
+
+    use crate::{
+        blocking::{Client, ClientBuilder},
+        error::Error,
+        mock_server::{self, MockRequest, MockResponse},
+        object_reader_common::ObjectReaderOptionsBuilder,
+    };
+
+    use super::ObjectReader;
+
+    fn mock_client(port: u16) -> Client {
+        ClientBuilder::new("ak", "sk", format!("http://127.0.0.1:{}", port))
+            .region("cn-hangzhou")
+            .path_style(true)
+            .build()
+            .unwrap()
+    }
+
+    fn test_data() -> Vec<u8> {
+        (0..1000).map(|i| (i % 251) as u8).collect()
+    }
+
+    /// Respond like OSS does for head object and ranged get object, `etag` is the ETag of the current object
+    fn respond(request: &MockRequest, etag: &str) -> MockResponse {
+        let data = test_data();
+        let etag = format!("\"{}\"", etag);
+
+        if request.method == "HEAD" {
+            return MockResponse::new(200).header("Content-Length", data.len().to_string()).header("ETag", etag);
+        }
+
+        if request.headers.get("if-match") != Some(&etag) {
+            return MockResponse::new(412).body(
+                "<?xml version=\"1.0\" encoding=\"UTF-8\"?><Error><Code>PreconditionFailed</Code><Message>At least one of the pre-conditions you specified did not hold.</Message><RequestId>1234</RequestId></Error>",
+            );
+        }
+
+        let (start, end) = request.headers["range"].strip_prefix("bytes=").unwrap().split_once('-').unwrap();
+        let (start, end) = (start.parse::<usize>().unwrap(), end.parse::<usize>().unwrap());
+
+        MockResponse::new(206)
+            .header("Content-Range", format!("bytes {}-{}/{}", start, end, data.len()))
+            .header("ETag", etag)
+            .body(&data[start..=end])
+    }
+
+    #[test]
+    fn test_object_reader_read_and_seek() {
+        let (port, server) = mock_server::start(4, |request: &MockRequest| respond(request, "ETAG1"));
+        let client = mock_client(port);
+        let data = test_data();
+        let options = ObjectReaderOptionsBuilder::new().read_ahead_size(100).build();
+
+        let mut reader = ObjectReader::new(&client, "example-bucket", "a.bin", Some(options)).unwrap();
+        assert_eq!(1000, reader.len());
+        assert_eq!("ETAG1", reader.etag());
+
+        let mut buf = vec![0u8; 10];
+        reader.read_exact(&mut buf).unwrap();
+        assert_eq!(&data[..10], &buf[..]);
+
+        // served from the buffered data
+        reader.read_exact(&mut buf).unwrap();
+        assert_eq!(&data[10..20], &buf[..]);
+
+        assert_eq!(500, reader.seek(SeekFrom::Start(500)).unwrap());
+        reader.read_exact(&mut buf).unwrap();
+        assert_eq!(&data[500..510], &buf[..]);
+
+        reader.seek(SeekFrom::End(-5)).unwrap();
+        let mut rest = vec![];
+        reader.read_to_end(&mut rest).unwrap();
+        assert_eq!(&data[995..], &rest[..]);
+
+        let requests = server.join().unwrap();
+        assert_eq!("HEAD", requests[0].method);
+        assert_eq!(
+            vec!["bytes=0-99", "bytes=500-599", "bytes=995-999"],
+            requests[1..].iter().map(|r| r.headers["range"].as_str()).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_object_reader_precondition_failed() {
+        // the object is overwritten after the reader was created
+        let (port, server) = mock_server::start(2, |request: &MockRequest| {
+            if request.method == "HEAD" {
+                respond(request, "ETAG1")
+            } else {
+                respond(request, "ETAG2")
+            }
+        });
+        let client = mock_client(port);
+
+        let mut reader = ObjectReader::new(&client, "example-bucket", "a.bin", None).unwrap();
+
+        let mut buf = vec![0u8; 10];
+        let err = reader.read_exact(&mut buf).unwrap_err();
+        assert!(matches!(
+            err.get_ref().and_then(|e| e.downcast_ref::<Error>()),
+            Some(Error::PreconditionFailed(_))
+        ));
+
+        let requests = server.join().unwrap();
+        assert_eq!(Some(&"\"ETAG1\"".to_string()), requests[1].headers.get("if-match"));
+    }
+}
@@ -0,0 +1,363 @@
+//! An object writer implementing `std::io::Write`.
+//!
+//! Data written to the writer is buffered into parts. If the total size is not greater than one part,
+//! the object is created by a single put object request when [`ObjectWriter::finish`] is called.
+//! Otherwise a multipart upload is initiated and parts are uploaded when they are full,
+//! and the multipart upload is completed when [`ObjectWriter::finish`] is called.
+//!
+//! If any request fails, the writer turns into failed state: later calls to `write` and [`ObjectWriter::finish`] return error,
+//! and the multipart upload (if initiated) is aborted when the writer is dropped.
+//! If the writer is dropped before being finished, the multipart upload (if initiated) is aborted.
+
+use std::io::Write;
+
+use crate::{
+    multipart_common::CompleteMultipartUploadRequest,
+    object_writer_common::{
+        build_abort_options, build_complete_options, build_initiate_options, build_upload_part_params, validate_object_writer_params, ObjectWriterOptions,
+    },
+    Result,
+};
+
+use super::{multipart::MultipartUploadsOperations, object::ObjectOperations, Client};
+
+/// Write data to an object. See module level document for details.
+///
+/// # Examples
+///
+/// ```no_run
+/// use ali_oss_rs::blocking::object_writer::ObjectWriter;
+///
+/// fn copy_to_oss(client: &ali_oss_rs::blocking::Client) -> ali_oss_rs::Result<()> {
+///     let mut writer = ObjectWriter::new(client, "example-bucket", "path/to/object.txt", None)?;
+///     let mut file = std::fs::File::open("/path/to/local/file.txt")?;
+///     std::io::copy(&mut file, &mut writer)?;
+///     writer.finish()?;
+///     Ok(())
+/// }
+/// ```
+pub struct ObjectWriter<'a> {
+    client: &'a Client,
+    bucket_name: String,
+    object_key: String,
+    options: ObjectWriterOptions,
+    buffer: Vec<u8>,
+    upload_id: Option<String>,
+    parts: Vec<(u32, String)>,
+    finished: bool,
+    failed: bool,
+}
+
+impl<'a> ObjectWriter<'a> {
+    pub fn new<S1, S2>(client: &'a Client, bucket_name: S1, object_key: S2, options: Option<ObjectWriterOptions>) -> Result<Self>
+    where
+        S1: AsRef<str>,
+        S2: AsRef<str>,
+    {
+        let bucket_name = bucket_name.as_ref();
+        let object_key = object_key.as_ref();
+        let options = options.unwrap_or_default();
+
+        validate_object_writer_params(bucket_name, object_key, &options)?;
+
+        Ok(Self {
+            client,
+            bucket_name: bucket_name.to_string(),
+            object_key: object_key.to_string(),
+            buffer: vec![],
+            options,
+            upload_id: None,
+            parts: vec![],
+            finished: false,
+            failed: false,
+        })
+    }
+
+    /// The upload id if the data is being uploaded with multipart uploads.
+    pub fn upload_id(&self) -> Option<&str> {
+        self.upload_id.as_deref()
+    }
+
+    fn failed_error() -> std::io::Error {
+        std::io::Error::other("object writer is in failed state")
+    }
+
+    /// Turn the writer into failed state if the request fails,
+    /// so the data which has been taken out of the buffer would not be lost silently on retry.
+    fn check<T>(&mut self, ret: Result<T>) -> Result<T> {
+        if ret.is_err() {
+            self.failed = true;
+        }
+        ret
+    }
+
+    fn upload_part(&mut self, data: Vec<u8>) -> Result<()> {
+        let upload_id = match &self.upload_id {
+            Some(s) => s.clone(),
+            None => {
+                let options = build_initiate_options(&self.options);
+                let ret = self.client.initiate_multipart_uploads(&self.bucket_name, &self.object_key, options)?;
+                self.upload_id = Some(ret.upload_id.clone());
+                ret.upload_id
+            }
+        };
+
+        let part_number = self.parts.len() as u32 + 1;
+        let params = build_upload_part_params(&self.options, &upload_id, part_number);
+        let ret = self.client.upload_part_from_buffer(&self.bucket_name, &self.object_key, data, params)?;
+        self.parts.push((part_number, ret.etag));
+
+        Ok(())
+    }
+
+    /// Upload the buffered data and complete the upload.
+    /// If the total size is not greater than one part, the object is created by a single put object request.
+    pub fn finish(&mut self) -> Result<()> {
+        if self.finished {
+            return Ok(());
+        }
+
+        if self.failed {
+            return Err(Self::failed_error().into());
+        }
+
+        let data = std::mem::take(&mut self.buffer);
+
+        let ret = match &self.upload_id {
+            None => self
+                .client
+                .put_object_from_buffer(&self.bucket_name, &self.object_key, data, self.options.put_options.clone())
+                .map(|_| ()),
+            Some(upload_id) => {
+                let upload_id = upload_id.clone();
+
+                if !data.is_empty() {
+                    let ret = self.upload_part(data);
+                    self.check(ret)?;
+                }
+
+                let request = CompleteMultipartUploadRequest {
+                    upload_id,
+                    parts: std::mem::take(&mut self.parts),
+                };
+
+                let options = build_complete_options(&self.options);
+                self.client
+                    .complete_multipart_uploads(&self.bucket_name, &self.object_key, request, options)
+                    .map(|_| ())
+            }
+        };
+
+        self.check(ret)?;
+        self.finished = true;
+
+        Ok(())
+    }
+}
+
+impl Write for ObjectWriter<'_> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        if self.finished {
+            return Err(std::io::Error::other("object writer has been finished"));
+        }
+
+        if self.failed {
+            return Err(Self::failed_error());
+        }
+
+        if buf.is_empty() {
+            return Ok(0);
+        }
+
+        // the buffer is full and there are more data, so it must be uploaded with multipart uploads
+        if self.buffer.len() >= self.options.part_size {
+            let rest = self.buffer.split_off(self.options.part_size);
+            let data = std::mem::replace(&mut self.buffer, rest);
+            let ret = self.upload_part(data);
+            self.check(ret).map_err(std::io::Error::other)?;
+        }
+
+        let n = buf.len().min(self.options.part_size - self.buffer.len());
+        self.buffer.extend_from_slice(&buf[..n]);
+
+        Ok(n)
+    }
+
+    /// Buffered data which is not enough for a part is kept until [`ObjectWriter::finish`] is called.
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+impl Drop for ObjectWriter<'_> {
+    fn drop(&mut self) {
+        if self.finished {
+            return;
+        }
+
+        if let Some(upload_id) = &self.upload_id {
+            let options = build_abort_options(&self.options);
+            if let Err(e) = self.client.abort_multipart_uploads(&self.bucket_name, &self.object_key, upload_id, options) {
+                log::error!("abort multipart uploads {} failed: {}", upload_id, e);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_object_writer_blocking {
+    use std::io::Write;
+
+    use crate::{
+        blocking::{Client, ClientBuilder},
+        common::RequestPayer,
+        mock_server::{self, MockRequest, MockResponse},
+        object_common::PutObjectOptionsBuilder,
+        object_writer_common::{ObjectWriterOptions, ObjectWriterOptionsBuilder},
+    };
+
+    use super::ObjectWriter;
+
+    const PART_SIZE: usize = 102_400;
+
+    fn mock_client(port: u16) -> Client {
+        ClientBuilder::new("ak", "sk", format!("http://127.0.0.1:{}", port))
+            .region("cn-hangzhou")
+            .path_style(true)
+            .build()
+            .unwrap()
+    }
+
+    fn writer_options() -> ObjectWriterOptions {
+        ObjectWriterOptionsBuilder::new()
+            .part_size(PART_SIZE)
+            .put_options(PutObjectOptionsBuilder::new().request_payer(RequestPayer::Requester).build())
+            .build()
+    }
+
+    fn test_data(len: usize) -> Vec<u8> {
+        (0..len).map(|i| (i % 251) as u8).collect()
+    }
+
+    /// Respond like OSS does for put object and multipart uploads
+    fn handle(request: &MockRequest) -> MockResponse {
+        match request.method.as_str() {
+            "POST" if request.has_query("uploads") => MockResponse::new(200).body(
+                "<?xml version=\"1.0\" encoding=\"UTF-8\"?><InitiateMultipartUploadResult><Bucket>example-bucket</Bucket><Key>a.txt</Key><UploadId>upload-1</UploadId></InitiateMultipartUploadResult>",
+            ),
+            "POST" => MockResponse::new(200).body(
+                "<?xml version=\"1.0\" encoding=\"UTF-8\"?><CompleteMultipartUploadResult><Bucket>example-bucket</Bucket><Key>a.txt</Key><ETag>\"ABC-3\"</ETag></CompleteMultipartUploadResult>",
+            ),
+            "DELETE" => MockResponse::new(204),
+            _ => MockResponse::new(200).header("ETag", format!("\"ETAG-{}\"", request.body.len())),
+        }
+    }
+
+    fn handle_put_failure(request: &MockRequest) -> MockResponse {
+        if request.method == "PUT" {
+            MockResponse::new(500).body(
+                "<?xml version=\"1.0\" encoding=\"UTF-8\"?><Error><Code>InternalError</Code><Message>We encountered an internal error.</Message><RequestId>1234</RequestId></Error>",
+            )
+        } else {
+            handle(request)
+        }
+    }
+
+    #[test]
+    fn test_object_writer_put_object() {
+        let (port, server) = mock_server::start(1, handle);
+        let client = mock_client(port);
+        let data = test_data(PART_SIZE);
+
+        let mut writer = ObjectWriter::new(&client, "example-bucket", "a.txt", Some(writer_options())).unwrap();
+        writer.write_all(&data).unwrap();
+        writer.finish().unwrap();
+        assert!(writer.upload_id().is_none());
+
+        let requests = server.join().unwrap();
+        assert_eq!(1, requests.len());
+        assert_eq!("PUT", requests[0].method);
+        assert_eq!("/example-bucket/a.txt", requests[0].path);
+        assert_eq!(data, requests[0].body);
+    }
+
+    #[test]
+    fn test_object_writer_multipart_uploads() {
+        let (port, server) = mock_server::start(5, handle);
+        let client = mock_client(port);
+        let data = test_data(PART_SIZE * 2 + 51_200);
+
+        let mut writer = ObjectWriter::new(&client, "example-bucket", "a.txt", Some(writer_options())).unwrap();
+        writer.write_all(&data).unwrap();
+        writer.finish().unwrap();
+        assert_eq!(Some("upload-1"), writer.upload_id());
+
+        let requests = server.join().unwrap();
+        assert_eq!(5, requests.len());
+
+        assert_eq!("POST", requests[0].method);
+        assert!(requests[0].has_query("uploads"));
+
+        for (i, request) in requests[1..4].iter().enumerate() {
+            assert_eq!("PUT", request.method);
+            assert!(request.path.contains(&format!("partNumber={}", i + 1)));
+            assert!(request.path.contains("uploadId=upload-1"));
+            assert_eq!(Some(&"requester".to_string()), request.headers.get("x-oss-request-payer"));
+        }
+
+        assert_eq!(&data[..PART_SIZE], &requests[1].body[..]);
+        assert_eq!(&data[PART_SIZE..PART_SIZE * 2], &requests[2].body[..]);
+        assert_eq!(&data[PART_SIZE * 2..], &requests[3].body[..]);
+
+        assert_eq!("POST", requests[4].method);
+        assert!(requests[4].path.contains("uploadId=upload-1"));
+        let body = String::from_utf8_lossy(&requests[4].body);
+        assert!(body.contains("<PartNumber>1</PartNumber><ETag>&quot;ETAG-102400&quot;</ETag>"));
+        assert!(body.contains("<PartNumber>3</PartNumber><ETag>&quot;ETAG-51200&quot;</ETag>"));
+    }
+
+    #[test]
+    fn test_object_writer_abort_on_drop() {
+        let (port, server) = mock_server::start(3, handle);
+        let client = mock_client(port);
+
+        let mut writer = ObjectWriter::new(&client, "example-bucket", "a.txt", Some(writer_options())).unwrap();
+        writer.write_all(&test_data(PART_SIZE + 1)).unwrap();
+        drop(writer);
+
+        let requests = server.join().unwrap();
+        assert_eq!(vec!["POST", "PUT", "DELETE"], requests.iter().map(|r| r.method.as_str()).collect::<Vec<_>>());
+        assert!(requests[2].path.contains("uploadId=upload-1"));
+        assert_eq!(Some(&"requester".to_string()), requests[2].headers.get("x-oss-request-payer"));
+    }
+
+    #[test]
+    fn test_object_writer_retry_after_failure() {
+        // single put object fails
+        let (port, server) = mock_server::start(1, handle_put_failure);
+        let client = mock_client(port);
+
+        let mut writer = ObjectWriter::new(&client, "example-bucket", "a.txt", Some(writer_options())).unwrap();
+        writer.write_all(b"hello").unwrap();
+        assert!(writer.finish().is_err());
+
+        // the buffer has been taken out, so retrying must not put an empty object
+        assert!(writer.finish().is_err());
+        assert!(writer.write_all(b"more").is_err());
+        drop(writer);
+
+        assert_eq!(1, server.join().unwrap().len());
+
+        // upload part fails in the middle of writing
+        let (port, server) = mock_server::start(3, handle_put_failure);
+        let client = mock_client(port);
+
+        let mut writer = ObjectWriter::new(&client, "example-bucket", "a.txt", Some(writer_options())).unwrap();
+        assert!(writer.write_all(&test_data(PART_SIZE + 1)).is_err());
+        assert!(writer.finish().is_err());
+        drop(writer);
+
+        let requests = server.join().unwrap();
+        assert_eq!(vec!["POST", "PUT", "DELETE"], requests.iter().map(|r| r.method.as_str()).collect::<Vec<_>>());
+    }
+}
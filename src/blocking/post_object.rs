@@ -0,0 +1,88 @@
+//! PostObject module, which uploads an object with an HTML form signed by a policy.
+
+use url::Url;
+
+use super::Client;
+use crate::object_common::PutObjectResult;
+use crate::post_object_common::{build_post_object_form, sign_post_policy, PostObjectOptions, PostPolicy, SignedPostPolicy};
+use crate::request::OssRequest;
+use crate::util::{get_iso8601_date_time_string, shift_iso8601_date_time_string};
+use crate::Result;
+
+impl Client {
+    /// Sign the policy for uploading objects to the bucket with HTML forms, e.g. direct uploads from browsers.
+    /// The returned value contains the url and form fields to post.
+    pub fn sign_post_policy<S: AsRef<str>>(&self, bucket_name: S, policy: &PostPolicy) -> Result<SignedPostPolicy> {
+        let bucket_name = bucket_name.as_ref();
+
+        let signed = sign_post_policy(
+            policy,
+            bucket_name,
+            &self.access_key_id,
+            &self.region,
+            self.sts_token.as_deref(),
+            &shift_iso8601_date_time_string(&get_iso8601_date_time_string(), self.clock_offset()),
+            |s, d| self.calculate_signature(s, d),
+        )?;
+
+        let url = self.request_url(&OssRequest::new().bucket(bucket_name));
+
+        Ok(SignedPostPolicy { url, ..signed })
+    }
+}
+
+pub trait PostObjectOperations {
+    /// Upload an object with a form signed by the policy.
+    /// This is what browsers do with the result of [`Client::sign_post_policy`], mostly used for testing the policy.
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/postobject>
+    fn post_object<S1, S2>(
+        &self,
+        bucket_name: S1,
+        object_key: S2,
+        content: Vec<u8>,
+        policy: PostPolicy,
+        options: Option<PostObjectOptions>,
+    ) -> Result<PutObjectResult>
+    where
+        S1: AsRef<str>,
+        S2: AsRef<str>;
+}
+
+impl PostObjectOperations for Client {
+    /// Upload an object with a form signed by the policy.
+    /// This is what browsers do with the result of [`Client::sign_post_policy`], mostly used for testing the policy.
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/postobject>
+    fn post_object<S1, S2>(
+        &self,
+        bucket_name: S1,
+        object_key: S2,
+        content: Vec<u8>,
+        policy: PostPolicy,
+        options: Option<PostObjectOptions>,
+    ) -> Result<PutObjectResult>
+    where
+        S1: AsRef<str>,
+        S2: AsRef<str>,
+    {
+        let signed = self.sign_post_policy(bucket_name, &policy)?;
+        let (content_type, body) = build_post_object_form(object_key.as_ref(), &signed, &content, &options)?;
+
+        let req = self
+            .blocking_http_client
+            .post(Url::parse(&signed.url)?)
+            .header("content-type", content_type)
+            .header("content-length", body.len())
+            .body(body)
+            .build()?;
+
+        let (headers, content) = self.execute_request::<String>(req, &signed.url)?;
+
+        if policy.callback.is_some() {
+            Ok(PutObjectResult::CallbackResponse(content))
+        } else {
+            Ok(PutObjectResult::ApiResponse(headers.into()))
+        }
+    }
+}
@@ -1,8 +1,17 @@
+use std::collections::HashMap;
+
+use chrono::{Duration, NaiveDateTime, Utc};
+
 use crate::{
+    common::SignatureVersion,
+    error::{Error, PresignedUrlError},
     presign::SignedOssRequest,
-    presign_common::{build_presign_get_request, PresignGetOptions},
-    request::OssRequest,
+    presign_common::{
+        build_presign_get_request, build_presign_request, parse_presigned_url, verify_presigned_request, PresignGetOptions, PresignOptions, PresignedUrlInfo,
+    },
+    request::{OssRequest, RequestMethod},
     util::{self, get_iso8601_date_time_string},
+    Result,
 };
 
 use super::Client;
@@ -14,7 +23,107 @@ impl Client {
         S1: AsRef<str>,
         S2: AsRef<str>,
     {
-        let mut request = build_presign_get_request(bucket_name.as_ref(), object_key.as_ref(), &options);
+        let request = build_presign_get_request(bucket_name.as_ref(), object_key.as_ref(), &options);
+        let request = self.sign_presign_request(request);
+        self.request_url(&request)
+    }
+
+    /// Presign URL for request of any method with the signature in the query string.
+    /// The returned headers are the signed headers given in options,
+    /// which must be sent with exactly the same values along with the url.
+    pub fn presign_request<S1, S2>(&self, method: RequestMethod, bucket_name: S1, object_key: S2, options: PresignOptions) -> SignedOssRequest
+    where
+        S1: AsRef<str>,
+        S2: AsRef<str>,
+    {
+        let request = build_presign_request(method, bucket_name.as_ref(), object_key.as_ref(), &options);
+        let request = self.sign_presign_request(request);
+        let url = self.request_url(&request);
+
+        SignedOssRequest { url, headers: request.headers }
+    }
+
+    /// Presign URL for uploading an object with `PUT` request.
+    /// Set content type, content md5 and metadata in options if they should be signed.
+    pub fn presign_put_object<S1, S2>(&self, bucket_name: S1, object_key: S2, options: PresignOptions) -> SignedOssRequest
+    where
+        S1: AsRef<str>,
+        S2: AsRef<str>,
+    {
+        self.presign_request(RequestMethod::Put, bucket_name, object_key, options)
+    }
+
+    /// Presign URL for uploading a part of a multipart upload with `PUT` request.
+    pub fn presign_upload_part<S1, S2, S3>(&self, bucket_name: S1, object_key: S2, upload_id: S3, part_number: u32, options: PresignOptions) -> SignedOssRequest
+    where
+        S1: AsRef<str>,
+        S2: AsRef<str>,
+        S3: AsRef<str>,
+    {
+        let mut options = options;
+        options.query_parameters.insert("partNumber".to_string(), part_number.to_string());
+        options.query_parameters.insert("uploadId".to_string(), upload_id.as_ref().to_string());
+
+        self.presign_request(RequestMethod::Put, bucket_name, object_key, options)
+    }
+
+    /// Presign URL for getting object metadata with `HEAD` request.
+    pub fn presign_head_object<S1, S2>(&self, bucket_name: S1, object_key: S2, options: PresignOptions) -> SignedOssRequest
+    where
+        S1: AsRef<str>,
+        S2: AsRef<str>,
+    {
+        self.presign_request(RequestMethod::Head, bucket_name, object_key, options)
+    }
+
+    /// Presign URL for deleting an object with `DELETE` request.
+    pub fn presign_delete_object<S1, S2>(&self, bucket_name: S1, object_key: S2, options: PresignOptions) -> SignedOssRequest
+    where
+        S1: AsRef<str>,
+        S2: AsRef<str>,
+    {
+        self.presign_request(RequestMethod::Delete, bucket_name, object_key, options)
+    }
+
+    /// Verify a presigned url generated with the access key id of this client,
+    /// including the credential scope, the signature over the canonical request and the expiration.
+    /// `headers` are the request headers sent along with the url, only the signed ones are used in verification.
+    ///
+    /// The bucket name can not be resolved from the url in cname mode, so the verification is unsupported in that case.
+    /// Only signature version 4 is supported.
+    pub fn verify_presigned_url<S: AsRef<str>>(&self, url: S, method: RequestMethod, headers: &HashMap<String, String>) -> Result<PresignedUrlInfo> {
+        if self.signature_version == SignatureVersion::V1 {
+            return Err(Error::PresignedUrl(PresignedUrlError::Unsupported(
+                "verification of signature version 1 is not supported".to_string(),
+            )));
+        }
+
+        if self.cname {
+            return Err(Error::PresignedUrl(PresignedUrlError::Unsupported(
+                "can not resolve bucket name from url in cname mode".to_string(),
+            )));
+        }
+
+        let mut endpoints = vec![self.endpoint.as_str()];
+        if let Some(e) = &self.accelerate_endpoint {
+            endpoints.push(e.as_str());
+        }
+
+        let request = parse_presigned_url(url.as_ref(), method, headers, self.path_style, &endpoints)?;
+
+        // the expiration is checked against the server time
+        let now = Utc::now() + Duration::seconds(self.clock_offset());
+
+        verify_presigned_request(request, &self.access_key_id, &self.region, now, |s, d| self.calculate_signature(s, d))
+    }
+
+    /// Add credential, security token and the calculated signature to query of the request
+    fn sign_presign_request(&self, mut request: OssRequest) -> OssRequest {
+        request.shift_date(self.clock_offset());
+
+        if self.signature_version == SignatureVersion::V1 {
+            return self.sign_presign_request_v1(request);
+        }
 
         let date_time_string = request.query.get("x-oss-date").unwrap().clone();
         let date_string = &date_time_string[..8];
@@ -41,22 +150,39 @@ impl Client {
 
         let sig = self.calculate_signature(&string_to_sign, date_string);
 
-        request = request.add_query("x-oss-signature", &sig);
-
-        let uri = request.build_request_uri();
-        let query_string = request.build_canonical_query_string();
+        request.add_query("x-oss-signature", &sig)
+    }
 
-        let domain_name = if request.bucket_name.is_empty() {
-            format!("{}://{}{}", self.scheme, self.endpoint, uri)
-        } else {
-            format!("{}://{}.{}{}", self.scheme, request.bucket_name, self.endpoint, uri)
+    /// Replace the query parameters of signature version 4 with `OSSAccessKeyId`, `Expires` and `Signature` of signature version 1.
+    /// Only `content-type`, `content-md5` and `x-oss-*` headers are signed, additional headers are not supported by signature version 1.
+    fn sign_presign_request_v1(&self, mut request: OssRequest) -> OssRequest {
+        let query = request.query_mut();
+        let date_time_string = query.remove("x-oss-date").unwrap_or_default();
+        let expire_seconds = query.remove("x-oss-expires").and_then(|s| s.parse::<i64>().ok()).unwrap_or_default();
+        query.remove("x-oss-signature-version");
+        query.remove("x-oss-additional-headers");
+
+        let signed_at = match NaiveDateTime::parse_from_str(&date_time_string, "%Y%m%dT%H%M%SZ") {
+            Ok(d) => d.and_utc().timestamp(),
+            Err(_) => Utc::now().timestamp(),
         };
 
-        if query_string.is_empty() {
-            domain_name
-        } else {
-            format!("{}?{}", domain_name, query_string)
+        let expires = (signed_at + expire_seconds).to_string();
+
+        if let Some(s) = &self.sts_token {
+            request = request.add_query("security-token", s);
         }
+
+        let string_to_sign = request.build_string_to_sign_v1(&expires);
+
+        log::debug!("string to sign: \n--------\n{}\n--------", string_to_sign);
+
+        let sig = self.calculate_signature_v1(&string_to_sign);
+
+        request
+            .add_query("OSSAccessKeyId", &self.access_key_id)
+            .add_query("Expires", expires)
+            .add_query("Signature", sig)
     }
 
     pub fn presign_raw_request(&self, mut oss_request: OssRequest) -> SignedOssRequest {
@@ -65,48 +191,24 @@ impl Client {
             oss_request.headers_mut().entry(date_header.clone()).or_insert(get_iso8601_date_time_string());
         }
 
-        let date_time_string = oss_request.headers.get(&date_header).unwrap().to_string();
-        let date_string = &date_time_string[..8];
+        oss_request.shift_date(self.clock_offset());
 
         if let Some(s) = &self.sts_token {
             if !oss_request.headers.contains_key("x-oss-security-token") {
                 oss_request = oss_request.add_header("x-oss-security-token", s);
             }
         }
-        let additional_headers = oss_request.build_additional_headers();
-        let string_to_sign = oss_request.build_string_to_sign(&self.region);
-
-        log::debug!("string to sign: \n--------\n{}\n--------", string_to_sign);
 
-        let sig = self.calculate_signature(&string_to_sign, date_string);
+        let http_date = util::get_http_date_with_offset(self.clock_offset());
+        let auth_string = self.build_authorization(&mut oss_request, &http_date);
 
-        log::debug!("signature: {}", sig);
-
-        let auth_string = format!(
-            "OSS4-HMAC-SHA256 Credential={}/{}/{}/oss/aliyun_v4_request,{}Signature={}",
-            self.access_key_id,
-            date_string,
-            self.region,
-            if additional_headers.is_empty() {
-                "".to_string()
-            } else {
-                format!("{},", additional_headers)
-            },
-            sig
-        );
+        if self.signature_version == SignatureVersion::V1 {
+            oss_request = oss_request.add_header("date", &http_date);
+        }
 
         oss_request = oss_request.add_header("authorization", &auth_string);
 
-        let uri = oss_request.build_request_uri();
-        let query_string = oss_request.build_canonical_query_string();
-
-        let url = if oss_request.bucket_name.is_empty() {
-            format!("{}://{}{}", self.scheme, self.endpoint, uri)
-        } else {
-            format!("{}://{}.{}{}", self.scheme, oss_request.bucket_name, self.endpoint, uri)
-        };
-
-        let url = if query_string.is_empty() { url } else { format!("{}?{}", url, query_string) };
+        let url = self.request_url(&oss_request);
 
         SignedOssRequest {
             url,
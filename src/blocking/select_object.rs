@@ -0,0 +1,238 @@
+//! Select object module
+
+use std::io::Read;
+
+use bytes::Bytes;
+
+use super::{BytesBody, Client};
+use crate::error::Error;
+use crate::select_object_common::{
+    build_create_select_object_meta_request, build_select_object_request, check_select_status, is_output_raw, parse_select_object_meta,
+    CreateSelectObjectMetaRequest, SelectFrame, SelectFrameDecoder, SelectObjectMeta, SelectObjectOptions, SelectObjectRequest, SelectObjectSummary,
+};
+use crate::Result;
+
+const READ_BUFFER_SIZE: usize = 8 * 1024;
+
+/// Selected data of select object.
+///
+/// Frames in the response body are decoded and the data of data frames is yielded.
+/// If the select operation fails in the middle, [`Error::SelectObjectFailed`] is yielded.
+pub struct SelectObjectIter {
+    response: reqwest::blocking::Response,
+    decoder: SelectFrameDecoder,
+
+    /// The response body is the selected data without frames
+    raw: bool,
+    summary: Option<SelectObjectSummary>,
+    done: bool,
+}
+
+impl SelectObjectIter {
+    fn new(response: reqwest::blocking::Response, raw: bool, verify_crc: bool) -> Self {
+        Self {
+            response,
+            decoder: SelectFrameDecoder::new(verify_crc),
+            raw,
+            summary: None,
+            done: false,
+        }
+    }
+
+    /// The summary in the end frame. It is available after the iterator is exhausted,
+    /// and is always `None` if the raw data is output.
+    pub fn summary(&self) -> Option<&SelectObjectSummary> {
+        self.summary.as_ref()
+    }
+
+    fn read_chunk(&mut self) -> Result<Option<Bytes>> {
+        let mut buf = vec![0u8; READ_BUFFER_SIZE];
+        let n = self.response.read(&mut buf)?;
+        if n == 0 {
+            return Ok(None);
+        }
+        buf.truncate(n);
+        Ok(Some(Bytes::from(buf)))
+    }
+
+    fn next_data(&mut self) -> Result<Option<Bytes>> {
+        if self.raw {
+            return self.read_chunk();
+        }
+
+        loop {
+            match self.decoder.next_frame()? {
+                Some(SelectFrame::Data(_, data)) if !data.is_empty() => return Ok(Some(data)),
+                Some(SelectFrame::End(summary)) => {
+                    let ret = check_select_status(summary.status, &summary.error_message);
+                    self.summary = Some(summary);
+                    return ret.map(|_| None);
+                }
+                Some(_) => continue,
+                None => {}
+            }
+
+            match self.read_chunk()? {
+                Some(bytes) => self.decoder.feed(&bytes),
+                None => return Err(Error::InvalidResponse("select object response ends without end frame".to_string())),
+            }
+        }
+    }
+}
+
+impl Iterator for SelectObjectIter {
+    type Item = Result<Bytes>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let ret = self.next_data();
+        if !matches!(ret, Ok(Some(_))) {
+            self.done = true;
+        }
+
+        ret.transpose()
+    }
+}
+
+pub trait SelectObjectOperations {
+    /// Select data from a CSV or JSON object with SQL statement.
+    /// The returned iterator yields the selected data.
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/selectobject>
+    fn select_object<S1, S2>(
+        &self,
+        bucket_name: S1,
+        object_key: S2,
+        request: SelectObjectRequest,
+        options: Option<SelectObjectOptions>,
+    ) -> Result<SelectObjectIter>
+    where
+        S1: AsRef<str>,
+        S2: AsRef<str>;
+
+    /// Create select meta of a CSV or JSON object, which is required for selecting by ranges.
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/createselectobjectmeta>
+    fn create_select_object_meta<S1, S2>(&self, bucket_name: S1, object_key: S2, request: CreateSelectObjectMetaRequest) -> Result<SelectObjectMeta>
+    where
+        S1: AsRef<str>,
+        S2: AsRef<str>;
+}
+
+impl SelectObjectOperations for Client {
+    /// Select data from a CSV or JSON object with SQL statement.
+    /// The returned iterator yields the selected data.
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/selectobject>
+    fn select_object<S1, S2>(
+        &self,
+        bucket_name: S1,
+        object_key: S2,
+        request: SelectObjectRequest,
+        options: Option<SelectObjectOptions>,
+    ) -> Result<SelectObjectIter>
+    where
+        S1: AsRef<str>,
+        S2: AsRef<str>,
+    {
+        let options = options.unwrap_or_default();
+        let request = build_select_object_request(bucket_name.as_ref(), object_key.as_ref(), &request, &options)?;
+        let (headers, body) = self.do_request::<BytesBody>(request)?;
+        Ok(SelectObjectIter::new(body.0, is_output_raw(&headers), options.enable_payload_crc))
+    }
+
+    /// Create select meta of a CSV or JSON object, which is required for selecting by ranges.
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/createselectobjectmeta>
+    fn create_select_object_meta<S1, S2>(&self, bucket_name: S1, object_key: S2, request: CreateSelectObjectMetaRequest) -> Result<SelectObjectMeta>
+    where
+        S1: AsRef<str>,
+        S2: AsRef<str>,
+    {
+        let request = build_create_select_object_meta_request(bucket_name.as_ref(), object_key.as_ref(), &request)?;
+        let (_, body) = self.do_request::<Vec<u8>>(request)?;
+        parse_select_object_meta(&body)
+    }
+}
+
+#[cfg(test)]
+mod test_select_object_blocking {
+    use crate::{
+        blocking::ClientBuilder,
+        error::Error,
+        mock_server::{self, MockResponse},
+        select_object_common::{
+            test_select_object_common::{data_frame, end_frame},
+            InputSerialization, SelectObjectRequest,
+        },
+    };
+
+    use super::{SelectObjectIter, SelectObjectOperations};
+
+    fn select_with_mock_server(response: MockResponse) -> SelectObjectIter {
+        let (port, _) = mock_server::start(1, move |_| response.clone());
+
+        let client = ClientBuilder::new("ak", "sk", format!("http://127.0.0.1:{}", port))
+            .region("cn-hangzhou")
+            .path_style(true)
+            .build()
+            .unwrap();
+
+        let request = SelectObjectRequest::new("select * from ossobject", InputSerialization::default());
+        client.select_object("example-bucket", "a.csv", request, None).unwrap()
+    }
+
+    #[test]
+    fn test_select_object_frames() {
+        let mut body = data_frame(4, b"a,b\n");
+        body.extend(end_frame(4, 4, 200, ""));
+
+        let mut iter = select_with_mock_server(MockResponse::new(206).body(body));
+        assert_eq!(b"a,b\n".as_slice(), iter.next().unwrap().unwrap().as_ref());
+        assert!(iter.next().is_none());
+        assert_eq!(200, iter.summary().unwrap().status);
+    }
+
+    #[test]
+    fn test_select_object_failed() {
+        let mut body = data_frame(4, b"a,b\n");
+        body.extend(end_frame(4, 4, 400, "InvalidCsvLine"));
+
+        let mut iter = select_with_mock_server(MockResponse::new(206).body(body));
+        assert!(iter.next().unwrap().is_ok());
+
+        match iter.next().unwrap() {
+            Err(Error::SelectObjectFailed { status, message }) => {
+                assert_eq!(400, status);
+                assert_eq!("InvalidCsvLine", message);
+            }
+            r => panic!("select object failed error expected, got: {:?}", r),
+        }
+
+        assert!(iter.next().is_none());
+    }
+
+    #[test]
+    fn test_select_object_without_end_frame() {
+        let mut iter = select_with_mock_server(MockResponse::new(206).body(data_frame(4, b"a,b\n")));
+        assert!(iter.next().unwrap().is_ok());
+        assert!(matches!(iter.next().unwrap(), Err(Error::InvalidResponse(_))));
+        assert!(iter.next().is_none());
+    }
+
+    #[test]
+    fn test_select_object_output_raw() {
+        let response = MockResponse::new(206).header("x-oss-select-output-raw", "true").body(b"a,b\nc,d\n".to_vec());
+        let iter = select_with_mock_server(response);
+
+        let data = iter.map(|r| r.unwrap()).fold(vec![], |mut data, bytes| {
+            data.extend_from_slice(&bytes);
+            data
+        });
+
+        assert_eq!(b"a,b\nc,d\n".to_vec(), data);
+    }
+}
@@ -3,9 +3,12 @@ use async_trait::async_trait;
 
 use crate::{
     bucket_common::{
-        build_list_buckets_request, build_list_objects_request, build_put_bucket_request, extract_bucket_location, BucketDetail, BucketStat,
-        ListBucketsOptions, ListBucketsResult, ListObjectsOptions, ListObjectsResult, PutBucketConfiguration, PutBucketOptions,
+        build_list_buckets_request, build_list_objects_request, build_put_bucket_access_monitor_request, build_put_bucket_request,
+        build_put_bucket_request_payment_request, build_put_bucket_transfer_acceleration_request, extract_access_monitor_status, extract_bucket_location,
+        extract_request_payer, extract_transfer_acceleration_enabled, BucketDetail, BucketStat, ListBucketsOptions, ListBucketsResult, ListObjectsOptions,
+        ListObjectsResult, PutBucketConfiguration, PutBucketOptions,
     },
+    common::{AccessMonitor, RequestPayer},
     error::Error,
     request::{OssRequest, RequestMethod},
     util::validate_bucket_name,
@@ -58,6 +61,49 @@ pub trait BucketOperations {
     async fn delete_bucket<S>(&self, bucket_name: S) -> Result<()>
     where
         S: AsRef<str> + Send;
+
+    /// Enable or disable transfer acceleration for a bucket
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/putbuckettransferacceleration>
+    async fn put_bucket_transfer_acceleration<S>(&self, bucket_name: S, enabled: bool) -> Result<()>
+    where
+        S: AsRef<str> + Send;
+
+    /// Get transfer acceleration status of a bucket. Returns `true` if the transfer acceleration is enabled.
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/getbuckettransferacceleration>
+    async fn get_bucket_transfer_acceleration<S>(&self, bucket_name: S) -> Result<bool>
+    where
+        S: AsRef<str> + Send;
+
+    /// Set who pays for the requests and traffic of a bucket
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/putbucketrequestpayment>
+    async fn put_bucket_request_payment<S>(&self, bucket_name: S, payer: RequestPayer) -> Result<()>
+    where
+        S: AsRef<str> + Send;
+
+    /// Get who pays for the requests and traffic of a bucket
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/getbucketrequestpayment>
+    async fn get_bucket_request_payment<S>(&self, bucket_name: S) -> Result<RequestPayer>
+    where
+        S: AsRef<str> + Send;
+
+    /// Enable or disable access tracking of a bucket.
+    /// Access tracking must be enabled before configuring lifecycle rules based on last access time.
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/putbucketaccessmonitor>
+    async fn put_bucket_access_monitor<S>(&self, bucket_name: S, status: AccessMonitor) -> Result<()>
+    where
+        S: AsRef<str> + Send;
+
+    /// Get access tracking status of a bucket
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/getbucketaccessmonitor>
+    async fn get_bucket_access_monitor<S>(&self, bucket_name: S) -> Result<AccessMonitor>
+    where
+        S: AsRef<str> + Send;
 }
 
 #[async_trait]
@@ -73,10 +119,7 @@ impl BucketOperations for crate::Client {
     /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/putbucket>
     async fn put_bucket<S: AsRef<str> + Send>(&self, bucket_name: S, config: PutBucketConfiguration, options: Option<PutBucketOptions>) -> Result<()> {
         if !validate_bucket_name(bucket_name.as_ref()) {
-            return Err(Error::Other(format!(
-                "invalid bucket name: {}. please see the official document for more details",
-                bucket_name.as_ref()
-            )));
+            return Err(Error::InvalidBucketName(bucket_name.as_ref().to_string()));
         }
 
         let request_builder = build_put_bucket_request(bucket_name.as_ref(), &config, &options)?;
@@ -102,7 +145,7 @@ impl BucketOperations for crate::Client {
         let bucket_name = bucket_name.as_ref();
 
         if !validate_bucket_name(bucket_name) {
-            return Err(Error::Other(format!("invalid bucket name: {}", bucket_name)));
+            return Err(Error::InvalidBucketName(bucket_name.to_string()));
         }
 
         let request_builder = OssRequest::new().method(RequestMethod::Delete).bucket(bucket_name);
@@ -119,7 +162,7 @@ impl BucketOperations for crate::Client {
         let bucket_name = bucket_name.as_ref();
 
         if !validate_bucket_name(bucket_name) {
-            return Err(Error::Other(format!("invalid bucket name: {}", bucket_name)));
+            return Err(Error::InvalidBucketName(bucket_name.to_string()));
         }
         let request_builder = OssRequest::new().method(RequestMethod::Get).bucket(bucket_name).add_query("bucketInfo", "");
 
@@ -135,7 +178,7 @@ impl BucketOperations for crate::Client {
         let bucket_name = bucket_name.as_ref();
 
         if !validate_bucket_name(bucket_name) {
-            return Err(Error::Other(format!("invalid bucket name: {}", bucket_name)));
+            return Err(Error::InvalidBucketName(bucket_name.to_string()));
         }
 
         let request_builder = OssRequest::new().method(RequestMethod::Get).bucket(bucket_name).add_query("location", "");
@@ -152,7 +195,7 @@ impl BucketOperations for crate::Client {
         let bucket_name = bucket_name.as_ref();
 
         if !validate_bucket_name(bucket_name) {
-            return Err(Error::Other(format!("invalid bucket name: {}", bucket_name)));
+            return Err(Error::InvalidBucketName(bucket_name.to_string()));
         }
 
         let request_builder = OssRequest::new().method(RequestMethod::Get).bucket(bucket_name).add_query("stat", "");
@@ -169,7 +212,7 @@ impl BucketOperations for crate::Client {
         let bucket_name = bucket_name.as_ref();
 
         if !validate_bucket_name(bucket_name) {
-            return Err(Error::Other(format!("invalid bucket name: {}", bucket_name)));
+            return Err(Error::InvalidBucketName(bucket_name.to_string()));
         }
 
         let request = build_list_objects_request(bucket_name, &options)?;
@@ -178,6 +221,112 @@ impl BucketOperations for crate::Client {
 
         ListObjectsResult::from_xml(&content)
     }
+
+    /// Enable or disable transfer acceleration for a bucket.
+    /// It may take up to 30 minutes for the change to take effect.
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/putbuckettransferacceleration>
+    async fn put_bucket_transfer_acceleration<S: AsRef<str> + Send>(&self, bucket_name: S, enabled: bool) -> Result<()> {
+        let bucket_name = bucket_name.as_ref();
+
+        if !validate_bucket_name(bucket_name) {
+            return Err(Error::InvalidBucketName(bucket_name.to_string()));
+        }
+
+        let request = build_put_bucket_transfer_acceleration_request(bucket_name, enabled)?;
+
+        self.do_request::<()>(request).await?;
+
+        Ok(())
+    }
+
+    /// Get transfer acceleration status of a bucket.
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/getbuckettransferacceleration>
+    async fn get_bucket_transfer_acceleration<S: AsRef<str> + Send>(&self, bucket_name: S) -> Result<bool> {
+        let bucket_name = bucket_name.as_ref();
+
+        if !validate_bucket_name(bucket_name) {
+            return Err(Error::InvalidBucketName(bucket_name.to_string()));
+        }
+
+        let request = OssRequest::new()
+            .method(RequestMethod::Get)
+            .bucket(bucket_name)
+            .add_query("transferAcceleration", "");
+
+        let (_, content) = self.do_request::<String>(request).await?;
+
+        extract_transfer_acceleration_enabled(&content)
+    }
+
+    /// Set who pays for the requests and traffic of a bucket.
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/putbucketrequestpayment>
+    async fn put_bucket_request_payment<S: AsRef<str> + Send>(&self, bucket_name: S, payer: RequestPayer) -> Result<()> {
+        let bucket_name = bucket_name.as_ref();
+
+        if !validate_bucket_name(bucket_name) {
+            return Err(Error::InvalidBucketName(bucket_name.to_string()));
+        }
+
+        let request = build_put_bucket_request_payment_request(bucket_name, payer)?;
+
+        self.do_request::<()>(request).await?;
+
+        Ok(())
+    }
+
+    /// Get who pays for the requests and traffic of a bucket.
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/getbucketrequestpayment>
+    async fn get_bucket_request_payment<S: AsRef<str> + Send>(&self, bucket_name: S) -> Result<RequestPayer> {
+        let bucket_name = bucket_name.as_ref();
+
+        if !validate_bucket_name(bucket_name) {
+            return Err(Error::InvalidBucketName(bucket_name.to_string()));
+        }
+
+        let request = OssRequest::new().method(RequestMethod::Get).bucket(bucket_name).add_query("requestPayment", "");
+
+        let (_, content) = self.do_request::<String>(request).await?;
+
+        extract_request_payer(&content)
+    }
+
+    /// Enable or disable access tracking of a bucket.
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/putbucketaccessmonitor>
+    async fn put_bucket_access_monitor<S: AsRef<str> + Send>(&self, bucket_name: S, status: AccessMonitor) -> Result<()> {
+        let bucket_name = bucket_name.as_ref();
+
+        if !validate_bucket_name(bucket_name) {
+            return Err(Error::InvalidBucketName(bucket_name.to_string()));
+        }
+
+        let request = build_put_bucket_access_monitor_request(bucket_name, status)?;
+
+        self.do_request::<()>(request).await?;
+
+        Ok(())
+    }
+
+    /// Get access tracking status of a bucket.
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/getbucketaccessmonitor>
+    async fn get_bucket_access_monitor<S: AsRef<str> + Send>(&self, bucket_name: S) -> Result<AccessMonitor> {
+        let bucket_name = bucket_name.as_ref();
+
+        if !validate_bucket_name(bucket_name) {
+            return Err(Error::InvalidBucketName(bucket_name.to_string()));
+        }
+
+        let request = OssRequest::new().method(RequestMethod::Get).bucket(bucket_name).add_query("accessmonitor", "");
+
+        let (_, content) = self.do_request::<String>(request).await?;
+
+        extract_access_monitor_status(&content)
+    }
 }
 
 #[cfg(test)]
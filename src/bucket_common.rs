@@ -4,8 +4,8 @@ use quick_xml::events::{BytesDecl, BytesEnd, BytesStart, BytesText, Event};
 
 use crate::{
     common::{
-        self, AccessMonitor, CrossRegionReplication, DataRedundancyType, ObjectType, Owner, ServerSideEncryptionAlgorithm, ServerSideEncryptionRule,
-        StorageClass, TransferAcceleration, Versioning,
+        self, AccessMonitor, CrossRegionReplication, DataRedundancyType, ObjectType, Owner, RequestPayer, ServerSideEncryptionAlgorithm,
+        ServerSideEncryptionRule, StorageClass, TransferAcceleration, Versioning,
     },
     error::Error,
     request::{OssRequest, RequestMethod},
@@ -64,7 +64,10 @@ impl TryFrom<&str> for BucketAcl {
             "public-read-write" => Ok(BucketAcl::PublicReadWrite),
             "public-read" => Ok(BucketAcl::PublicRead),
             "private" => Ok(BucketAcl::Private),
-            _ => Err(Error::Other(format!("Invalid ACL value: {}", s))),
+            _ => Err(Error::InvalidEnumValue {
+                name: "ACL",
+                value: s.to_string(),
+            }),
         }
     }
 }
@@ -418,10 +421,8 @@ pub(crate) fn extract_bucket_location(xml: &str) -> Result<String> {
         match reader.read_event()? {
             Event::Eof => break,
             Event::Start(t) => tag = String::from_utf8_lossy(t.local_name().as_ref()).to_string(),
-            Event::Text(s) => {
-                if tag == "LocationConstraint" {
-                    location = s.unescape()?.trim().to_string();
-                }
+            Event::Text(s) if tag == "LocationConstraint" => {
+                location = s.unescape()?.trim().to_string();
             }
             Event::End(_) => tag.clear(),
             _ => {}
@@ -765,7 +766,7 @@ pub(crate) fn build_put_bucket_request(bucket_name: &str, config: &PutBucketConf
 
         if !options.tags.is_empty() {
             if !options.tags.iter().all(|(k, v)| validate_tag_key(k) && validate_tag_value(v)) {
-                return Err(Error::Other(format!("invalid tag name or tag key: {:#?}", options.tags)));
+                return Err(Error::InvalidArgument(format!("invalid tag name or tag key: {:#?}", options.tags)));
             }
 
             let tags_string = options
@@ -805,7 +806,7 @@ pub(crate) fn build_list_buckets_request(options: &Option<ListBucketsOptions>) -
 
 pub(crate) fn build_list_objects_request(bucket_name: &str, options: &Option<ListObjectsOptions>) -> Result<OssRequest> {
     if !validate_bucket_name(bucket_name) {
-        return Err(Error::Other(format!("invalid bucket name: {}", bucket_name)));
+        return Err(Error::InvalidBucketName(bucket_name.to_string()));
     }
 
     let mut request = OssRequest::new().method(RequestMethod::Get).bucket(bucket_name).add_query("list-type", "2");
@@ -821,7 +822,7 @@ pub(crate) fn build_list_objects_request(bucket_name: &str, options: &Option<Lis
 
         if let Some(u) = options.max_keys {
             if u == 0 || u > 1000 {
-                return Err(Error::Other(format!("invalid max-keys: {}. must between 1 and 1000", u)));
+                return Err(Error::InvalidArgument(format!("invalid max-keys: {}. must between 1 and 1000", u)));
             }
             request = request.add_query("max-keys", u.to_string());
         }
@@ -841,3 +842,193 @@ pub(crate) fn build_list_objects_request(bucket_name: &str, options: &Option<Lis
 
     Ok(request)
 }
+
+pub(crate) fn build_put_bucket_transfer_acceleration_request(bucket_name: &str, enabled: bool) -> Result<OssRequest> {
+    let mut writer = quick_xml::Writer::new(Vec::new());
+    writer.write_event(Event::Decl(BytesDecl::new("1.0", Some("UTF-8"), None)))?;
+    writer.write_event(Event::Start(BytesStart::new("TransferAccelerationConfiguration")))?;
+    writer.write_event(Event::Start(BytesStart::new("Enabled")))?;
+    writer.write_event(Event::Text(BytesText::new(if enabled { "true" } else { "false" })))?;
+    writer.write_event(Event::End(BytesEnd::new("Enabled")))?;
+    writer.write_event(Event::End(BytesEnd::new("TransferAccelerationConfiguration")))?;
+
+    let xml = String::from_utf8(writer.into_inner())?;
+
+    let request = OssRequest::new()
+        .method(RequestMethod::Put)
+        .bucket(bucket_name)
+        .add_query("transferAcceleration", "")
+        .content_type(common::MIME_TYPE_XML)
+        .text_body(xml);
+
+    Ok(request)
+}
+
+/// Extract the `Enabled` value from transfer acceleration configuration XML.
+pub(crate) fn extract_transfer_acceleration_enabled(xml: &str) -> Result<bool> {
+    let mut reader = quick_xml::Reader::from_str(xml);
+    let mut tag = "".to_string();
+    let mut enabled = false;
+    loop {
+        match reader.read_event()? {
+            Event::Eof => break,
+            Event::Start(t) => tag = String::from_utf8_lossy(t.local_name().as_ref()).to_string(),
+            Event::Text(s) if tag == "Enabled" => {
+                enabled = s.unescape()?.trim() == "true";
+            }
+            Event::End(_) => tag.clear(),
+            _ => {}
+        }
+    }
+
+    Ok(enabled)
+}
+
+pub(crate) fn build_put_bucket_request_payment_request(bucket_name: &str, payer: RequestPayer) -> Result<OssRequest> {
+    let mut writer = quick_xml::Writer::new(Vec::new());
+    writer.write_event(Event::Decl(BytesDecl::new("1.0", Some("UTF-8"), None)))?;
+    writer.write_event(Event::Start(BytesStart::new("RequestPaymentConfiguration")))?;
+    writer.write_event(Event::Start(BytesStart::new("Payer")))?;
+    writer.write_event(Event::Text(BytesText::new(payer.as_str())))?;
+    writer.write_event(Event::End(BytesEnd::new("Payer")))?;
+    writer.write_event(Event::End(BytesEnd::new("RequestPaymentConfiguration")))?;
+
+    let xml = String::from_utf8(writer.into_inner())?;
+
+    let request = OssRequest::new()
+        .method(RequestMethod::Put)
+        .bucket(bucket_name)
+        .add_query("requestPayment", "")
+        .content_type(common::MIME_TYPE_XML)
+        .text_body(xml);
+
+    Ok(request)
+}
+
+/// Extract the `Payer` value from request payment configuration XML.
+pub(crate) fn extract_request_payer(xml: &str) -> Result<RequestPayer> {
+    let mut reader = quick_xml::Reader::from_str(xml);
+    let mut tag = "".to_string();
+    let mut payer = RequestPayer::default();
+    loop {
+        match reader.read_event()? {
+            Event::Eof => break,
+            Event::Start(t) => tag = String::from_utf8_lossy(t.local_name().as_ref()).to_string(),
+            Event::Text(s) if tag == "Payer" => {
+                payer = RequestPayer::try_from(s.unescape()?.trim())?;
+            }
+            Event::End(_) => tag.clear(),
+            _ => {}
+        }
+    }
+
+    Ok(payer)
+}
+
+pub(crate) fn build_put_bucket_access_monitor_request(bucket_name: &str, status: AccessMonitor) -> Result<OssRequest> {
+    let mut writer = quick_xml::Writer::new(Vec::new());
+    writer.write_event(Event::Decl(BytesDecl::new("1.0", Some("UTF-8"), None)))?;
+    writer.write_event(Event::Start(BytesStart::new("AccessMonitorConfiguration")))?;
+    writer.write_event(Event::Start(BytesStart::new("Status")))?;
+    writer.write_event(Event::Text(BytesText::new(status.as_str())))?;
+    writer.write_event(Event::End(BytesEnd::new("Status")))?;
+    writer.write_event(Event::End(BytesEnd::new("AccessMonitorConfiguration")))?;
+
+    let xml = String::from_utf8(writer.into_inner())?;
+
+    let request = OssRequest::new()
+        .method(RequestMethod::Put)
+        .bucket(bucket_name)
+        .add_query("accessmonitor", "")
+        .content_type(common::MIME_TYPE_XML)
+        .text_body(xml);
+
+    Ok(request)
+}
+
+/// Extract the `Status` value from access monitor configuration XML.
+pub(crate) fn extract_access_monitor_status(xml: &str) -> Result<AccessMonitor> {
+    let mut reader = quick_xml::Reader::from_str(xml);
+    let mut tag = "".to_string();
+    let mut status = AccessMonitor::default();
+    loop {
+        match reader.read_event()? {
+            Event::Eof => break,
+            Event::Start(t) => tag = String::from_utf8_lossy(t.local_name().as_ref()).to_string(),
+            Event::Text(s) if tag == "Status" => {
+                status = AccessMonitor::try_from(s.unescape()?.trim())?;
+            }
+            Event::End(_) => tag.clear(),
+            _ => {}
+        }
+    }
+
+    Ok(status)
+}
+
+#[cfg(test)]
+mod test_bucket_common {
+    use crate::{
+        bucket_common::{
+            build_put_bucket_access_monitor_request, build_put_bucket_request_payment_request, build_put_bucket_transfer_acceleration_request,
+            extract_access_monitor_status, extract_request_payer, extract_transfer_acceleration_enabled,
+        },
+        common::{AccessMonitor, RequestPayer},
+    };
+
+    #[test]
+    fn test_transfer_acceleration_xml() {
+        let request = build_put_bucket_transfer_acceleration_request("test-bucket", true).unwrap();
+        assert!(request.query.contains_key("transferAcceleration"));
+
+        let xml = match &request.body {
+            crate::request::RequestBody::Text(s) => s.clone(),
+            _ => panic!("unexpected request body"),
+        };
+
+        assert!(xml.contains("<TransferAccelerationConfiguration><Enabled>true</Enabled></TransferAccelerationConfiguration>"));
+        assert!(extract_transfer_acceleration_enabled(&xml).unwrap());
+
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+        <TransferAccelerationConfiguration>
+          <Enabled>false</Enabled>
+        </TransferAccelerationConfiguration>"#;
+
+        assert!(!extract_transfer_acceleration_enabled(xml).unwrap());
+    }
+
+    #[test]
+    fn test_request_payment_xml() {
+        let request = build_put_bucket_request_payment_request("test-bucket", RequestPayer::Requester).unwrap();
+        assert!(request.query.contains_key("requestPayment"));
+
+        let xml = match &request.body {
+            crate::request::RequestBody::Text(s) => s.clone(),
+            _ => panic!("unexpected request body"),
+        };
+
+        assert!(xml.contains("<RequestPaymentConfiguration><Payer>Requester</Payer></RequestPaymentConfiguration>"));
+        assert_eq!(RequestPayer::Requester, extract_request_payer(&xml).unwrap());
+
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+        <RequestPaymentConfiguration>
+          <Payer>BucketOwner</Payer>
+        </RequestPaymentConfiguration>"#;
+
+        assert_eq!(RequestPayer::BucketOwner, extract_request_payer(xml).unwrap());
+    }
+
+    #[test]
+    fn test_access_monitor_xml() {
+        let request = build_put_bucket_access_monitor_request("test-bucket", AccessMonitor::Enabled).unwrap();
+        assert!(request.query.contains_key("accessmonitor"));
+
+        let xml = match &request.body {
+            crate::request::RequestBody::Text(s) => s.clone(),
+            _ => panic!("unexpected request body"),
+        };
+
+        assert!(xml.contains("<AccessMonitorConfiguration><Status>Enabled</Status></AccessMonitorConfiguration>"));
+        assert_eq!(AccessMonitor::Enabled, extract_access_monitor_status(&xml).unwrap());
+    }
+}
@@ -0,0 +1,196 @@
+//! Verify the callback requests which are sent from OSS to your callback server
+
+use std::{collections::HashMap, sync::Mutex, time::Duration};
+
+use async_trait::async_trait;
+use rsa::RsaPublicKey;
+
+use crate::{
+    callback_common::{parse_public_key, resolve_public_key_url, verify_callback_signature, MAX_CACHED_PUBLIC_KEYS},
+    error::{CallbackSignatureError, Error},
+    Result,
+};
+
+/// Fetch the PEM encoded public key from the url.
+/// Implement this trait to fetch the public key in your own way, e.g. from a local file in tests.
+#[async_trait]
+pub trait PublicKeyFetcher: Send + Sync {
+    async fn fetch_public_key(&self, url: &str) -> Result<String>;
+}
+
+/// Fetch public key with HTTP request.
+/// The default http client times out after 10 seconds connecting and 30 seconds in total.
+#[derive(Debug, Clone)]
+pub struct HttpPublicKeyFetcher {
+    http_client: reqwest::Client,
+}
+
+impl Default for HttpPublicKeyFetcher {
+    fn default() -> Self {
+        let http_client = reqwest::Client::builder()
+            .connect_timeout(Duration::from_secs(10))
+            .timeout(Duration::from_secs(30))
+            .build()
+            .expect("failed to build http client");
+
+        Self { http_client }
+    }
+}
+
+impl HttpPublicKeyFetcher {
+    pub fn new(http_client: reqwest::Client) -> Self {
+        Self { http_client }
+    }
+}
+
+#[async_trait]
+impl PublicKeyFetcher for HttpPublicKeyFetcher {
+    async fn fetch_public_key(&self, url: &str) -> Result<String> {
+        let response = self.http_client.get(url).send().await?;
+
+        if !response.status().is_success() {
+            return Err(Error::CallbackSignature(CallbackSignatureError::InvalidPublicKey(format!(
+                "fetch public key from {} failed with status: {}",
+                url,
+                response.status()
+            ))));
+        }
+
+        Ok(response.text().await?)
+    }
+}
+
+/// Verify the signature of callback requests sent from OSS.
+///
+/// The public key url is read from the base64 encoded `x-oss-pub-key-url` header,
+/// and only the keys hosted on `gosspublic.alicdn.com` are trusted.
+/// Fetched public keys are cached by url.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::collections::HashMap;
+///
+/// use ali_oss_rs::callback::CallbackVerifier;
+///
+/// // `path_and_query` is the request uri, e.g. `/callback?id=1`, `headers` and `body` are of the callback request.
+/// async fn handle_callback(verifier: &CallbackVerifier, path_and_query: &str, headers: &HashMap<String, String>, body: &[u8]) -> ali_oss_rs::Result<()> {
+///     verifier.verify(path_and_query, headers, body).await
+/// }
+///
+/// #[tokio::main]
+/// async fn main() {
+///     // create the verifier once, so the fetched public keys are cached
+///     let verifier = CallbackVerifier::new();
+///
+///     let headers = HashMap::new();
+///     handle_callback(&verifier, "/callback?id=1", &headers, b"{}").await.unwrap();
+/// }
+/// ```
+pub struct CallbackVerifier<F: PublicKeyFetcher = HttpPublicKeyFetcher> {
+    fetcher: F,
+    cache: Mutex<HashMap<String, RsaPublicKey>>,
+}
+
+impl CallbackVerifier<HttpPublicKeyFetcher> {
+    pub fn new() -> Self {
+        Self::with_fetcher(HttpPublicKeyFetcher::default())
+    }
+}
+
+impl Default for CallbackVerifier<HttpPublicKeyFetcher> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<F: PublicKeyFetcher> CallbackVerifier<F> {
+    pub fn with_fetcher(fetcher: F) -> Self {
+        Self {
+            fetcher,
+            cache: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Verify the callback request.
+    ///
+    /// - `path_and_query` The request uri, e.g. `/callback?id=1`. The path could be url encoded.
+    /// - `headers` The request headers. Header names are case-insensitive.
+    /// - `body` The raw request body.
+    pub async fn verify(&self, path_and_query: &str, headers: &HashMap<String, String>, body: &[u8]) -> Result<()> {
+        let url = resolve_public_key_url(headers)?;
+        let public_key = self.get_public_key(&url).await?;
+        verify_callback_signature(&public_key, headers, path_and_query, body)
+    }
+
+    async fn get_public_key(&self, url: &str) -> Result<RsaPublicKey> {
+        if let Some(key) = self.cache.lock().unwrap().get(url) {
+            return Ok(key.clone());
+        }
+
+        let pem = self.fetcher.fetch_public_key(url).await?;
+        let key = parse_public_key(&pem)?;
+
+        let mut cache = self.cache.lock().unwrap();
+        if cache.len() >= MAX_CACHED_PUBLIC_KEYS {
+            cache.clear();
+        }
+        cache.insert(url.to_string(), key.clone());
+
+        Ok(key)
+    }
+}
+
+#[cfg(test)]
+mod test_callback_async {
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+
+    use async_trait::async_trait;
+    use base64::{prelude::BASE64_STANDARD, Engine};
+
+    use crate::{
+        callback_common::test_callback_common::{signed_headers, BODY, PATH_AND_QUERY, PUBLIC_KEY},
+        error::{CallbackSignatureError, Error},
+        Result,
+    };
+
+    use super::{CallbackVerifier, PublicKeyFetcher};
+
+    struct StaticPublicKeyFetcher {
+        count: Arc<AtomicUsize>,
+    }
+
+    #[async_trait]
+    impl PublicKeyFetcher for StaticPublicKeyFetcher {
+        async fn fetch_public_key(&self, url: &str) -> Result<String> {
+            assert_eq!("https://gosspublic.alicdn.com/callback_pub_key_v1.pem", url);
+            self.count.fetch_add(1, Ordering::SeqCst);
+            Ok(PUBLIC_KEY.to_string())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_verify_callback() {
+        let count = Arc::new(AtomicUsize::new(0));
+        let verifier = CallbackVerifier::with_fetcher(StaticPublicKeyFetcher { count: count.clone() });
+
+        verifier.verify(PATH_AND_QUERY, &signed_headers(), BODY.as_bytes()).await.unwrap();
+        verifier.verify(PATH_AND_QUERY, &signed_headers(), BODY.as_bytes()).await.unwrap();
+        assert_eq!(1, count.load(Ordering::SeqCst));
+
+        // the query is not part of the cache key, so it can not be used to force fetching
+        let mut headers = signed_headers();
+        headers.insert(
+            "x-oss-pub-key-url".to_string(),
+            BASE64_STANDARD.encode("https://gosspublic.alicdn.com/callback_pub_key_v1.pem?r=1"),
+        );
+        verifier.verify(PATH_AND_QUERY, &headers, BODY.as_bytes()).await.unwrap();
+        assert_eq!(1, count.load(Ordering::SeqCst));
+
+        let err = verifier.verify("/callback", &signed_headers(), BODY.as_bytes()).await.unwrap_err();
+        assert!(matches!(err, Error::CallbackSignature(CallbackSignatureError::SignatureMismatch)));
+    }
+}
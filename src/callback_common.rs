@@ -0,0 +1,532 @@
+//! Common types and functions for callback requests sent from OSS
+
+use std::collections::HashMap;
+
+use base64::{prelude::BASE64_STANDARD, Engine};
+use rsa::{pkcs1::DecodeRsaPublicKey, pkcs8::DecodePublicKey, Pkcs1v15Sign, RsaPublicKey};
+
+use crate::{
+    error::{CallbackSignatureError, Error},
+    object_common::{Callback, CallbackBodyType},
+    util::sanitize_etag,
+    Result,
+};
+
+/// The only trusted host of the public keys used by OSS to sign callback requests
+pub const TRUSTED_PUBLIC_KEY_HOST: &str = "gosspublic.alicdn.com";
+
+/// The cache of public keys is cleared when it is full,
+/// so forged callback requests can not grow it without limit.
+pub(crate) const MAX_CACHED_PUBLIC_KEYS: usize = 16;
+
+/// DER encoded `DigestInfo` prefix of MD5 for PKCS#1 v1.5 signature
+const MD5_DIGEST_INFO_PREFIX: [u8; 18] = [
+    0x30, 0x20, 0x30, 0x0c, 0x06, 0x08, 0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x02, 0x05, 0x05, 0x00, 0x04, 0x10,
+];
+
+fn invalid_header(message: impl Into<String>) -> Error {
+    Error::CallbackSignature(CallbackSignatureError::InvalidHeader(message.into()))
+}
+
+/// Get header value with case-insensitive header name
+pub(crate) fn get_header<'a>(headers: &'a HashMap<String, String>, name: &str) -> Option<&'a str> {
+    headers.iter().find(|(k, _)| k.eq_ignore_ascii_case(name)).map(|(_, v)| v.as_str())
+}
+
+/// Decode the base64 encoded `x-oss-pub-key-url` header and make sure it is hosted on [`TRUSTED_PUBLIC_KEY_HOST`]
+/// with path `/callback_pub_key_v{n}.pem`. The query and fragment are removed,
+/// and the returned url is always `https` even if OSS sends `http` url.
+pub(crate) fn resolve_public_key_url(headers: &HashMap<String, String>) -> Result<String> {
+    let encoded = get_header(headers, "x-oss-pub-key-url").ok_or_else(|| invalid_header("x-oss-pub-key-url is missing"))?;
+
+    let decoded = BASE64_STANDARD
+        .decode(encoded.trim())
+        .map_err(|_| invalid_header("x-oss-pub-key-url is not base64 encoded"))?;
+    let decoded = String::from_utf8(decoded).map_err(|_| invalid_header("x-oss-pub-key-url is not a valid string"))?;
+
+    let mut url = url::Url::parse(&decoded).map_err(|_| Error::CallbackSignature(CallbackSignatureError::UntrustedPublicKeyUrl(decoded.clone())))?;
+
+    let trusted = matches!(url.scheme(), "http" | "https")
+        && url.host_str() == Some(TRUSTED_PUBLIC_KEY_HOST)
+        && url.port().is_none()
+        && url.username().is_empty()
+        && url.password().is_none()
+        && is_public_key_path(url.path());
+
+    if !trusted {
+        return Err(Error::CallbackSignature(CallbackSignatureError::UntrustedPublicKeyUrl(decoded)));
+    }
+
+    // the public key is fetched with https to prevent being tampered
+    if url.scheme() == "http" {
+        let _ = url.set_scheme("https");
+    }
+
+    url.set_query(None);
+    url.set_fragment(None);
+
+    Ok(url.to_string())
+}
+
+/// e.g. `/callback_pub_key_v1.pem`
+fn is_public_key_path(path: &str) -> bool {
+    match path.strip_prefix("/callback_pub_key_v").and_then(|s| s.strip_suffix(".pem")) {
+        Some(v) => !v.is_empty() && v.len() <= 3 && v.bytes().all(|b| b.is_ascii_digit()),
+        None => false,
+    }
+}
+
+/// Parse the PEM encoded public key, both `PUBLIC KEY` and `RSA PUBLIC KEY` are supported.
+pub(crate) fn parse_public_key(pem: &str) -> Result<RsaPublicKey> {
+    let pem = pem.trim();
+
+    RsaPublicKey::from_public_key_pem(pem)
+        .or_else(|_| RsaPublicKey::from_pkcs1_pem(pem))
+        .map_err(|e| Error::CallbackSignature(CallbackSignatureError::InvalidPublicKey(e.to_string())))
+}
+
+/// Build the string to sign of callback request:
+/// the url decoded path, the raw query string with `?` if there is one, a line break and the body.
+///
+/// `path_and_query` is the request uri, e.g. `/callback?id=1`.
+pub(crate) fn build_callback_string_to_sign(path_and_query: &str, body: &[u8]) -> Result<Vec<u8>> {
+    let (path, query) = match path_and_query.split_once('?') {
+        Some((p, q)) => (p, Some(q)),
+        None => (path_and_query, None),
+    };
+
+    let path = urlencoding::decode(path).map_err(|e| invalid_header(format!("invalid request path: {}", e)))?;
+
+    let mut data = path.as_bytes().to_vec();
+
+    if let Some(q) = query {
+        data.push(b'?');
+        data.extend_from_slice(q.as_bytes());
+    }
+
+    data.push(b'\n');
+    data.extend_from_slice(body);
+
+    Ok(data)
+}
+
+/// Verify the base64 encoded `authorization` header, which is the RSA signature of the MD5 digest of the string to sign.
+pub(crate) fn verify_callback_signature(public_key: &RsaPublicKey, headers: &HashMap<String, String>, path_and_query: &str, body: &[u8]) -> Result<()> {
+    let authorization = get_header(headers, "authorization").ok_or_else(|| invalid_header("authorization is missing"))?;
+    let signature = BASE64_STANDARD
+        .decode(authorization.trim())
+        .map_err(|_| invalid_header("authorization is not base64 encoded"))?;
+
+    let string_to_sign = build_callback_string_to_sign(path_and_query, body)?;
+    let digest = md5::compute(&string_to_sign);
+
+    let scheme = Pkcs1v15Sign {
+        hash_len: Some(16),
+        prefix: Box::new(MD5_DIGEST_INFO_PREFIX),
+    };
+
+    public_key
+        .verify(scheme, digest.as_ref(), &signature)
+        .map_err(|_| Error::CallbackSignature(CallbackSignatureError::SignatureMismatch))
+}
+
+/// Image information in callback body, only available for images
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde-support", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde-camelcase", serde(rename_all = "camelCase"))]
+pub struct CallbackImageInfo {
+    pub height: Option<u32>,
+    pub width: Option<u32>,
+    pub format: Option<String>,
+}
+
+/// Parsed body of the callback request sent from OSS.
+///
+/// The system variables are typed, and the custom variables are keyed with the `x:` prefix,
+/// which is the same as [`Callback::custom_variables`].
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde-support", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde-camelcase", serde(rename_all = "camelCase"))]
+pub struct CallbackPayload {
+    pub bucket: Option<String>,
+    pub object: Option<String>,
+    pub etag: Option<String>,
+    pub size: Option<u64>,
+    pub mime_type: Option<String>,
+    pub image_info: Option<CallbackImageInfo>,
+    pub crc64: Option<String>,
+    pub content_md5: Option<String>,
+    pub vpc_id: Option<String>,
+    pub client_ip: Option<String>,
+    pub request_id: Option<String>,
+    pub operation: Option<String>,
+    pub custom_variables: HashMap<String, String>,
+
+    /// All fields in the callback body, including the constants.
+    /// Fields of nested JSON objects are keyed with dot separated path, e.g. `imageInfo.height`.
+    pub fields: HashMap<String, String>,
+}
+
+impl CallbackPayload {
+    /// Parse the callback body with the callback which is sent with the request creating the object.
+    /// The variables are located by the field names in [`Callback::body`],
+    /// e.g. `key=${object}&my_var=${x:my_var}` puts the value of field `key` to `object`
+    /// and the value of field `my_var` to custom variable `x:my_var`.
+    pub fn parse(callback: &Callback, body: &[u8]) -> Result<Self> {
+        let body_type = callback.body_type.unwrap_or_default();
+        let fields = parse_callback_fields(body_type, body)?;
+        let variables = parse_callback_body_template(body_type, &callback.body);
+
+        Self::from_fields(fields, &variables)
+    }
+
+    /// Parse the callback body without the callback. The field names must be the same as the variable names,
+    /// e.g. `bucket=${bucket}&imageInfo.height=${imageInfo.height}&x:my_var=${x:my_var}`.
+    pub fn parse_with_body_type(body_type: CallbackBodyType, body: &[u8]) -> Result<Self> {
+        let fields = parse_callback_fields(body_type, body)?;
+        let variables = fields.keys().map(|k| (k.clone(), k.clone())).collect::<Vec<_>>();
+
+        Self::from_fields(fields, &variables)
+    }
+
+    /// `variables` are pairs of field name and variable name
+    fn from_fields(fields: HashMap<String, String>, variables: &[(String, String)]) -> Result<Self> {
+        let mut ret = Self::default();
+        let mut image_info = CallbackImageInfo::default();
+
+        for (field, variable) in variables {
+            let value = match fields.get(field) {
+                Some(v) => v.clone(),
+                None => continue,
+            };
+
+            if let Some(name) = variable.strip_prefix("x:") {
+                ret.custom_variables.insert(format!("x:{}", name), value);
+                continue;
+            }
+
+            let non_empty = if value.is_empty() { None } else { Some(value) };
+
+            match variable.as_str() {
+                "bucket" => ret.bucket = non_empty,
+                "object" => ret.object = non_empty,
+                "etag" => ret.etag = non_empty.map(sanitize_etag),
+                "size" => ret.size = non_empty.map(|s| s.parse::<u64>()).transpose()?,
+                "mimeType" => ret.mime_type = non_empty,
+                "imageInfo.height" => image_info.height = non_empty.map(|s| s.parse::<u32>()).transpose()?,
+                "imageInfo.width" => image_info.width = non_empty.map(|s| s.parse::<u32>()).transpose()?,
+                "imageInfo.format" => image_info.format = non_empty,
+                "crc64" => ret.crc64 = non_empty,
+                "contentMd5" => ret.content_md5 = non_empty,
+                "vpcId" => ret.vpc_id = non_empty,
+                "clientIp" => ret.client_ip = non_empty,
+                "reqId" => ret.request_id = non_empty,
+                "operation" => ret.operation = non_empty,
+                _ => {}
+            }
+        }
+
+        if image_info != CallbackImageInfo::default() {
+            ret.image_info = Some(image_info);
+        }
+
+        ret.fields = fields;
+
+        Ok(ret)
+    }
+}
+
+/// Find the pairs of field name and variable name in the callback body template,
+/// e.g. `key=${object}` for form and `"key":${object}` (or `"key":"${object}"`) for JSON.
+/// Fields of nested JSON objects are named with the dot separated path, the same as [`CallbackPayload::fields`].
+pub(crate) fn parse_callback_body_template(body_type: CallbackBodyType, template: &str) -> Vec<(String, String)> {
+    match body_type {
+        CallbackBodyType::FormUrlEncoded => {
+            let re = regex::Regex::new(r"(?:^|&)([^=&]+)=\$\{([^}]+)\}").unwrap();
+            re.captures_iter(template).map(|c| (c[1].trim().to_string(), c[2].trim().to_string())).collect()
+        }
+        CallbackBodyType::Json => parse_json_callback_body_template(template),
+    }
+}
+
+const TEMPLATE_VARIABLE_PLACEHOLDER: &str = "__ali_oss_rs_variable_";
+
+/// Replace the variables with placeholder strings, so the template can be parsed as JSON
+/// and the full path of the field holding each variable is found by flattening it.
+/// Variables which are a part of a string value, e.g. `"key":"prefix-${object}"`, are ignored.
+fn parse_json_callback_body_template(template: &str) -> Vec<(String, String)> {
+    let re = regex::Regex::new(r#"("?)\$\{([^}]+)\}("?)"#).unwrap();
+
+    let mut variables = vec![];
+    let json = re.replace_all(template, |c: &regex::Captures| {
+        if c[1].len() != c[3].len() {
+            return c[0].to_string();
+        }
+
+        variables.push(c[2].trim().to_string());
+        format!("\"{}{}\"", TEMPLATE_VARIABLE_PLACEHOLDER, variables.len() - 1)
+    });
+
+    let value = match serde_json::from_str::<serde_json::Value>(&json) {
+        Ok(v) => v,
+        Err(e) => {
+            log::warn!("callback body template is not valid JSON: {}", e);
+            return vec![];
+        }
+    };
+
+    let mut fields = HashMap::new();
+    flatten_json_value("", &value, &mut fields);
+
+    let mut pairs = fields
+        .into_iter()
+        .filter_map(|(k, v)| {
+            let index = v.strip_prefix(TEMPLATE_VARIABLE_PLACEHOLDER)?.parse::<usize>().ok()?;
+            Some((index, k))
+        })
+        .collect::<Vec<_>>();
+    pairs.sort();
+
+    pairs.into_iter().map(|(index, k)| (k, variables[index].clone())).collect()
+}
+
+/// Decode the callback body to flat fields
+pub(crate) fn parse_callback_fields(body_type: CallbackBodyType, body: &[u8]) -> Result<HashMap<String, String>> {
+    let mut fields = HashMap::new();
+
+    match body_type {
+        CallbackBodyType::FormUrlEncoded => {
+            for (k, v) in url::form_urlencoded::parse(body) {
+                fields.insert(k.to_string(), v.to_string());
+            }
+        }
+
+        CallbackBodyType::Json => {
+            let value = serde_json::from_slice::<serde_json::Value>(body)?;
+            flatten_json_value("", &value, &mut fields);
+        }
+    }
+
+    Ok(fields)
+}
+
+fn flatten_json_value(prefix: &str, value: &serde_json::Value, fields: &mut HashMap<String, String>) {
+    match value {
+        serde_json::Value::Object(map) => {
+            for (k, v) in map {
+                let key = if prefix.is_empty() { k.clone() } else { format!("{}.{}", prefix, k) };
+                flatten_json_value(&key, v, fields);
+            }
+        }
+        serde_json::Value::String(s) => {
+            fields.insert(prefix.to_string(), s.clone());
+        }
+        serde_json::Value::Null => {
+            fields.insert(prefix.to_string(), String::new());
+        }
+        v => {
+            fields.insert(prefix.to_string(), v.to_string());
+        }
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test_callback_common {
+    use std::collections::HashMap;
+
+    use base64::{prelude::BASE64_STANDARD, Engine};
+
+    use crate::{
+        error::{CallbackSignatureError, Error},
+        object_common::{Callback, CallbackBodyParameter, CallbackBodyType, CallbackBuilder},
+    };
+
+    use super::{
+        build_callback_string_to_sign, parse_callback_body_template, parse_public_key, resolve_public_key_url, verify_callback_signature, CallbackImageInfo,
+        CallbackPayload,
+    };
+
+    pub(crate) const PUBLIC_KEY: &str = "-----BEGIN PUBLIC KEY-----
+MIGfMA0GCSqGSIb3DQEBAQUAA4GNADCBiQKBgQDSYyX80sd6kKhFoeer5HriycHo
+wRv9yQWqtPjEgqTKhuheR87vKyiosvZsQ+Kfcd/S5w5Wa3DhkOTXooqSQ33u0acR
+VR8pLBget17xAuG2jeMuti7SfXjJe+ubg9/43OddoFqGCjQ/L2TwI9GsTMgF/XQ4
+2DkROD0ZL3MgsRxqVwIDAQAB
+-----END PUBLIC KEY-----
+";
+
+    pub(crate) const PATH_AND_QUERY: &str = "/call%20back?id=1&name=a%20b";
+
+    pub(crate) const BODY: &str = "bucket=examplebucket&object=exampleobject.txt&size=120";
+
+    /// Headers of a callback request signed by the private key of [`PUBLIC_KEY`]
+    pub(crate) fn signed_headers() -> HashMap<String, String> {
+        HashMap::from([
+            (
+                "Authorization".to_string(),
+                "QYOOQTHr5kM5CRvveVk4KydOlWAc9f6/A02ZtvFuBJGFM4Ai67uqKj6D9jdHM31oX5qLfZrTilVPuVPuUiMnj2OrsGn8ffozrQYH3dYLfxvZ5dMp2BBKsSfBPENhTTyznC7/C0U1fzyQuH4Y5ICGGSWtYsXzT+xAIca3O6oTrEM=".to_string(),
+            ),
+            (
+                "x-oss-pub-key-url".to_string(),
+                "aHR0cDovL2dvc3NwdWJsaWMuYWxpY2RuLmNvbS9jYWxsYmFja19wdWJfa2V5X3YxLnBlbQ==".to_string(),
+            ),
+        ])
+    }
+
+    #[test]
+    fn test_resolve_public_key_url() {
+        let url = resolve_public_key_url(&signed_headers()).unwrap();
+        assert_eq!("https://gosspublic.alicdn.com/callback_pub_key_v1.pem", url);
+
+        // https://evil.example.com/gosspublic.alicdn.com/key.pem
+        let headers = HashMap::from([(
+            "x-oss-pub-key-url".to_string(),
+            "aHR0cHM6Ly9ldmlsLmV4YW1wbGUuY29tL2dvc3NwdWJsaWMuYWxpY2RuLmNvbS9rZXkucGVt".to_string(),
+        )]);
+        let err = resolve_public_key_url(&headers).unwrap_err();
+        assert!(matches!(err, Error::CallbackSignature(CallbackSignatureError::UntrustedPublicKeyUrl(_))));
+
+        let headers = HashMap::from([(
+            "x-oss-pub-key-url".to_string(),
+            BASE64_STANDARD.encode("http://gosspublic.alicdn.com/callback_pub_key_v1.pem?r=1#a"),
+        )]);
+        assert_eq!(
+            "https://gosspublic.alicdn.com/callback_pub_key_v1.pem",
+            resolve_public_key_url(&headers).unwrap()
+        );
+
+        for url in [
+            "https://gosspublic.alicdn.com/other.pem",
+            "https://gosspublic.alicdn.com/callback_pub_key_v1.pem/a",
+        ] {
+            let headers = HashMap::from([("x-oss-pub-key-url".to_string(), BASE64_STANDARD.encode(url))]);
+            let err = resolve_public_key_url(&headers).unwrap_err();
+            assert!(matches!(err, Error::CallbackSignature(CallbackSignatureError::UntrustedPublicKeyUrl(_))));
+        }
+
+        let err = resolve_public_key_url(&HashMap::new()).unwrap_err();
+        assert!(matches!(err, Error::CallbackSignature(CallbackSignatureError::InvalidHeader(_))));
+    }
+
+    #[test]
+    fn test_verify_callback_signature() {
+        assert_eq!(
+            b"/call back?id=1&name=a%20b\nbucket=examplebucket&object=exampleobject.txt&size=120".to_vec(),
+            build_callback_string_to_sign(PATH_AND_QUERY, BODY.as_bytes()).unwrap()
+        );
+
+        let public_key = parse_public_key(PUBLIC_KEY).unwrap();
+
+        assert!(verify_callback_signature(&public_key, &signed_headers(), PATH_AND_QUERY, BODY.as_bytes()).is_ok());
+
+        let err = verify_callback_signature(&public_key, &signed_headers(), PATH_AND_QUERY, b"bucket=examplebucket").unwrap_err();
+        assert!(matches!(err, Error::CallbackSignature(CallbackSignatureError::SignatureMismatch)));
+
+        let err = verify_callback_signature(&public_key, &signed_headers(), "/call%20back?id=2&name=a%20b", BODY.as_bytes()).unwrap_err();
+        assert!(matches!(err, Error::CallbackSignature(CallbackSignatureError::SignatureMismatch)));
+
+        assert!(parse_public_key("not a key").is_err());
+    }
+
+    #[test]
+    fn test_parse_form_callback_payload() {
+        let callback = CallbackBuilder::new("https://example.com/callback")
+            .body_parameter(CallbackBodyParameter::OssBucket("b"))
+            .body_parameter(CallbackBodyParameter::OssObject("key"))
+            .body_parameter(CallbackBodyParameter::OssETag("etag"))
+            .body_parameter(CallbackBodyParameter::OssSize("size"))
+            .body_parameter(CallbackBodyParameter::OssMimeType("mime"))
+            .body_parameter(CallbackBodyParameter::OssImageHeight("h"))
+            .body_parameter(CallbackBodyParameter::OssImageWidth("w"))
+            .body_parameter(CallbackBodyParameter::OssImageFormat("fmt"))
+            .body_parameter(CallbackBodyParameter::Custom("foo", "bar", "Are you OK?".to_string()))
+            .body_parameter(CallbackBodyParameter::Constant("hello", "world"))
+            .build();
+
+        let body = "b=examplebucket&key=a%2Fb.jpg&etag=%22D41D8CD98F00B204E9800998ECF8427E%22&size=120&mime=image%2Fjpeg&h=100&w=200&fmt=jpg&foo=Are+you+OK%3F&hello=world";
+
+        let payload = CallbackPayload::parse(&callback, body.as_bytes()).unwrap();
+        assert_eq!(Some("examplebucket".to_string()), payload.bucket);
+        assert_eq!(Some("a/b.jpg".to_string()), payload.object);
+        assert_eq!(Some("D41D8CD98F00B204E9800998ECF8427E".to_string()), payload.etag);
+        assert_eq!(Some(120), payload.size);
+        assert_eq!(Some("image/jpeg".to_string()), payload.mime_type);
+        assert_eq!(
+            Some(CallbackImageInfo {
+                height: Some(100),
+                width: Some(200),
+                format: Some("jpg".to_string())
+            }),
+            payload.image_info
+        );
+        assert_eq!(callback.custom_variables, payload.custom_variables);
+        assert_eq!(Some(&"world".to_string()), payload.fields.get("hello"));
+
+        // image info is empty for non-image objects
+        let body = "b=examplebucket&key=a.txt&size=5&h=&w=&fmt=";
+        let payload = CallbackPayload::parse(&callback, body.as_bytes()).unwrap();
+        assert_eq!(Some(5), payload.size);
+        assert!(payload.image_info.is_none());
+        assert!(payload.custom_variables.is_empty());
+    }
+
+    #[test]
+    fn test_parse_json_callback_payload() {
+        let callback = Callback {
+            url: "https://example.com/callback".to_string(),
+            host: None,
+            body: r#"{"bucket":${bucket},"key":"${object}","size":${size},"image":{"height":${imageInfo.height}},"my_var":${x:my_var}}"#.to_string(),
+            sni: None,
+            body_type: Some(CallbackBodyType::Json),
+            custom_variables: [("x:my_var".to_string(), "hello".to_string())].into_iter().collect(),
+        };
+
+        let body = r#"{"bucket":"examplebucket","key":"a.png","size":1024,"image":{"height":768},"my_var":"hello"}"#;
+
+        let payload = CallbackPayload::parse(&callback, body.as_bytes()).unwrap();
+        assert_eq!(Some("examplebucket".to_string()), payload.bucket);
+        assert_eq!(Some("a.png".to_string()), payload.object);
+        assert_eq!(Some(1024), payload.size);
+        assert_eq!(Some(768), payload.image_info.unwrap().height);
+        assert_eq!(callback.custom_variables, payload.custom_variables);
+        assert_eq!(Some(&"768".to_string()), payload.fields.get("image.height"));
+
+        let body = r#"{"bucket":"examplebucket","object":"a.png","mimeType":"image/png","x:my_var":"hello"}"#;
+        let payload = CallbackPayload::parse_with_body_type(CallbackBodyType::Json, body.as_bytes()).unwrap();
+        assert_eq!(Some("a.png".to_string()), payload.object);
+        assert_eq!(Some("image/png".to_string()), payload.mime_type);
+        assert_eq!(Some(&"hello".to_string()), payload.custom_variables.get("x:my_var"));
+
+        assert!(CallbackPayload::parse(&callback, b"not json").is_err());
+    }
+
+    #[test]
+    fn test_parse_json_callback_body_template() {
+        let template = r#"{"a":{"height":${imageInfo.height}},"b":{"height":"${imageInfo.width}","name":"img-${object}"},"c":[${size}],"bucket":"${bucket}"}"#;
+        assert_eq!(
+            vec![
+                ("a.height".to_string(), "imageInfo.height".to_string()),
+                ("b.height".to_string(), "imageInfo.width".to_string()),
+                ("bucket".to_string(), "bucket".to_string()),
+            ],
+            parse_callback_body_template(CallbackBodyType::Json, template)
+        );
+
+        let callback = Callback {
+            url: "https://example.com/callback".to_string(),
+            host: None,
+            body: template.to_string(),
+            sni: None,
+            body_type: Some(CallbackBodyType::Json),
+            custom_variables: HashMap::new(),
+        };
+
+        let body = r#"{"a":{"height":768},"b":{"height":1024,"name":"img-a.png"},"c":[100],"bucket":"examplebucket"}"#;
+        let payload = CallbackPayload::parse(&callback, body.as_bytes()).unwrap();
+        let image_info = payload.image_info.unwrap();
+        assert_eq!(Some(768), image_info.height);
+        assert_eq!(Some(1024), image_info.width);
+        assert_eq!(None, payload.object);
+        assert_eq!(None, payload.size);
+        assert_eq!(Some("examplebucket".to_string()), payload.bucket);
+    }
+}
@@ -1,7 +1,10 @@
 //! Bucket cname
 
 use crate::{
-    cname_common::{CnameInfo, ListCnameResult},
+    cname_common::{
+        build_create_cname_token_request, build_delete_cname_request, build_get_cname_token_request, build_put_cname_request, CnameInfo, CnameToken,
+        ListCnameResult, PutCnameOptions,
+    },
     error::Error,
     request::{OssRequest, RequestMethod},
     util::validate_bucket_name,
@@ -17,6 +20,38 @@ pub trait CnameOperations {
     async fn list_cname<S>(&self, bucket_name: S) -> Result<Vec<CnameInfo>>
     where
         S: AsRef<str> + Send;
+
+    /// Create a token for verifying the ownership of the domain
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/createcnametoken>
+    async fn create_cname_token<S1, S2>(&self, bucket_name: S1, domain: S2) -> Result<CnameToken>
+    where
+        S1: AsRef<str> + Send,
+        S2: AsRef<str> + Send;
+
+    /// Get the token which is created by `create_cname_token`
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/getcnametoken>
+    async fn get_cname_token<S1, S2>(&self, bucket_name: S1, domain: S2) -> Result<CnameToken>
+    where
+        S1: AsRef<str> + Send,
+        S2: AsRef<str> + Send;
+
+    /// Bind a domain to the bucket. You can bind, update or delete the certificate at the same time with `options`
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/putcname>
+    async fn put_cname<S1, S2>(&self, bucket_name: S1, domain: S2, options: Option<PutCnameOptions>) -> Result<()>
+    where
+        S1: AsRef<str> + Send,
+        S2: AsRef<str> + Send;
+
+    /// Unbind a domain from the bucket
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/deletecname>
+    async fn delete_cname<S1, S2>(&self, bucket_name: S1, domain: S2) -> Result<()>
+    where
+        S1: AsRef<str> + Send,
+        S2: AsRef<str> + Send;
 }
 
 #[async_trait]
@@ -31,7 +66,7 @@ impl CnameOperations for Client {
         let bucket_name = bucket_name.as_ref();
 
         if !validate_bucket_name(bucket_name) {
-            return Err(Error::Other(format!("invalid bucket name: {}", bucket_name)));
+            return Err(Error::InvalidBucketName(bucket_name.to_string()));
         }
 
         let request = OssRequest::new().method(RequestMethod::Get).bucket(bucket_name).add_query("cname", "");
@@ -42,6 +77,58 @@ impl CnameOperations for Client {
 
         Ok(cnames)
     }
+
+    /// Create a token for verifying the ownership of the domain
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/createcnametoken>
+    async fn create_cname_token<S1, S2>(&self, bucket_name: S1, domain: S2) -> Result<CnameToken>
+    where
+        S1: AsRef<str> + Send,
+        S2: AsRef<str> + Send,
+    {
+        let request = build_create_cname_token_request(bucket_name.as_ref(), domain.as_ref())?;
+        let (_, xml) = self.do_request::<String>(request).await?;
+        CnameToken::from_xml(&xml)
+    }
+
+    /// Get the token which is created by `create_cname_token`
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/getcnametoken>
+    async fn get_cname_token<S1, S2>(&self, bucket_name: S1, domain: S2) -> Result<CnameToken>
+    where
+        S1: AsRef<str> + Send,
+        S2: AsRef<str> + Send,
+    {
+        let request = build_get_cname_token_request(bucket_name.as_ref(), domain.as_ref())?;
+        let (_, xml) = self.do_request::<String>(request).await?;
+        CnameToken::from_xml(&xml)
+    }
+
+    /// Bind a domain to the bucket. You can bind, update or delete the certificate at the same time with `options`
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/putcname>
+    async fn put_cname<S1, S2>(&self, bucket_name: S1, domain: S2, options: Option<PutCnameOptions>) -> Result<()>
+    where
+        S1: AsRef<str> + Send,
+        S2: AsRef<str> + Send,
+    {
+        let request = build_put_cname_request(bucket_name.as_ref(), domain.as_ref(), &options)?;
+        self.do_request::<()>(request).await?;
+        Ok(())
+    }
+
+    /// Unbind a domain from the bucket
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/deletecname>
+    async fn delete_cname<S1, S2>(&self, bucket_name: S1, domain: S2) -> Result<()>
+    where
+        S1: AsRef<str> + Send,
+        S2: AsRef<str> + Send,
+    {
+        let request = build_delete_cname_request(bucket_name.as_ref(), domain.as_ref())?;
+        self.do_request::<()>(request).await?;
+        Ok(())
+    }
 }
 
 #[cfg(test)]
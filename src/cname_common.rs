@@ -1,31 +1,72 @@
-use quick_xml::events::Event;
+use quick_xml::events::{BytesDecl, BytesEnd, BytesStart, BytesText, Event};
 
-use crate::common::OnOff;
+use crate::common::{self, OnOff};
+use crate::error::Error;
+use crate::request::{OssRequest, RequestMethod};
+use crate::util::validate_bucket_name;
 use crate::Result;
 
 pub type CnameStatus = OnOff;
 
-// /// Cname certificate
-// #[derive(Debug, Clone, Default)]
-// #[cfg_attr(feature = "serde-support", derive(serde::Serialize, serde::Deserialize))]
-// #[cfg_attr(feature = "serde-camelcase", serde(rename_all = "camelCase"))]
-// pub struct CnameCertificate {
+/// Cname certificate
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde-support", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde-camelcase", serde(rename_all = "camelCase"))]
+pub struct CnameCertificate {
+    /// 证书来源
+    ///
+    /// - `CAS`: 证书中心
+    /// - `Upload`: 用户自行上传的
+    pub cert_type: String,
 
-//     /// 证书来源
-//     ///
-//     /// - `CAS`: 证书中心
-//     /// - `Upload`: 用户自行上传的
-//     pub cert_type: String,
+    pub cert_id: String,
+    pub status: OnOff,
+    pub creation_date: String,
+
+    /// 证书签名。e.g. `DE:01:CF:EC:7C:A7:98:CB:D8:6E:FB:1D:97:EB:A9:64:1D:4E:**:**`.
+    pub fingerprint: String,
+    pub valid_start_date: String,
+    pub valid_end_date: String,
+}
 
-//     pub cert_id: String,
-//     pub status: OnOff,
-//     pub creation_date: String,
+impl CnameCertificate {
+    pub(crate) fn from_xml_reader(reader: &mut quick_xml::Reader<&[u8]>) -> Result<Self> {
+        let mut tag = String::new();
+        let mut data = CnameCertificate::default();
 
-//     /// 证书签名。e.g. `DE:01:CF:EC:7C:A7:98:CB:D8:6E:FB:1D:97:EB:A9:64:1D:4E:**:**`.
-//     pub fingerprint: String,
-//     pub valid_start_date: String,
-//     pub valid_end_date: String,
-// }
+        loop {
+            match reader.read_event()? {
+                Event::Eof => break,
+                Event::Start(t) => {
+                    tag = String::from_utf8_lossy(t.local_name().as_ref()).to_string();
+                }
+                Event::Text(text) => {
+                    let s = text.unescape()?.trim().to_string();
+
+                    match tag.as_str() {
+                        "Type" => data.cert_type = s,
+                        "CertId" => data.cert_id = s,
+                        "Status" => data.status = OnOff::try_from(s)?,
+                        "CreationDate" => data.creation_date = s,
+                        "Fingerprint" => data.fingerprint = s,
+                        "ValidStartDate" => data.valid_start_date = s,
+                        "ValidEndDate" => data.valid_end_date = s,
+                        _ => {}
+                    }
+                }
+                Event::End(t) => {
+                    tag.clear();
+                    if t.local_name().as_ref() == b"Certificate" {
+                        break;
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        Ok(data)
+    }
+}
 
 /// Bucket cname data
 #[derive(Debug, Clone, Default)]
@@ -36,6 +77,7 @@ pub struct CnameInfo {
     pub last_modified: String,
     pub status: CnameStatus,
     pub is_purge_cdn_cache: bool,
+    pub certificate: Option<CnameCertificate>,
 }
 
 impl CnameInfo {
@@ -46,6 +88,9 @@ impl CnameInfo {
         loop {
             match reader.read_event()? {
                 Event::Eof => break,
+                Event::Start(t) if t.local_name().as_ref() == b"Certificate" => {
+                    data.certificate = Some(CnameCertificate::from_xml_reader(reader)?);
+                }
                 Event::Start(t) => {
                     tag = String::from_utf8_lossy(t.local_name().as_ref()).to_string();
                 }
@@ -99,3 +144,342 @@ impl ListCnameResult {
         Ok(Self { cnames })
     }
 }
+
+/// Cname token which is used to verify the ownership of the domain.
+/// Add a TXT record with the token to your DNS before binding the domain to a bucket.
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde-support", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde-camelcase", serde(rename_all = "camelCase"))]
+pub struct CnameToken {
+    pub bucket: String,
+    pub cname: String,
+    pub token: String,
+    pub expire_time: String,
+}
+
+impl CnameToken {
+    pub(crate) fn from_xml(xml: &str) -> Result<Self> {
+        let mut reader = quick_xml::Reader::from_str(xml);
+        let mut tag = String::new();
+        let mut data = Self::default();
+
+        loop {
+            match reader.read_event()? {
+                Event::Eof => break,
+                Event::Start(t) => tag = String::from_utf8_lossy(t.local_name().as_ref()).to_string(),
+                Event::Text(text) => {
+                    let s = text.unescape()?.trim().to_string();
+                    match tag.as_str() {
+                        "Bucket" => data.bucket = s,
+                        "Cname" => data.cname = s,
+                        "Token" => data.token = s,
+                        "ExpireTime" => data.expire_time = s,
+                        _ => {}
+                    }
+                }
+                Event::End(_) => tag.clear(),
+                _ => {}
+            }
+        }
+
+        Ok(data)
+    }
+}
+
+/// Options for binding a domain to a bucket. Mostly about the certificate.
+///
+/// Official document: <https://help.aliyun.com/zh/oss/developer-reference/putcname>
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde-support", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde-camelcase", serde(rename_all = "camelCase"))]
+pub struct PutCnameOptions {
+    /// 证书 ID。证书中心的证书需要指定此项
+    pub cert_id: Option<String>,
+
+    /// 证书公钥（PEM 格式）
+    pub certificate: Option<String>,
+
+    /// 证书私钥（PEM 格式）
+    pub private_key: Option<String>,
+
+    /// 当前证书 ID。更新证书时需要指定
+    pub previous_cert_id: Option<String>,
+
+    /// 是否强制覆盖证书
+    pub force: Option<bool>,
+
+    /// 是否删除证书。设置为 `true` 时解绑证书
+    pub delete_certificate: Option<bool>,
+}
+
+impl PutCnameOptions {
+    fn has_certificate_configuration(&self) -> bool {
+        self.cert_id.is_some()
+            || self.certificate.is_some()
+            || self.private_key.is_some()
+            || self.previous_cert_id.is_some()
+            || self.force.is_some()
+            || self.delete_certificate.is_some()
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct PutCnameOptionsBuilder {
+    options: PutCnameOptions,
+}
+
+impl PutCnameOptionsBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn cert_id<S: Into<String>>(mut self, cert_id: S) -> Self {
+        self.options.cert_id = Some(cert_id.into());
+        self
+    }
+
+    pub fn certificate<S: Into<String>>(mut self, certificate: S) -> Self {
+        self.options.certificate = Some(certificate.into());
+        self
+    }
+
+    pub fn private_key<S: Into<String>>(mut self, private_key: S) -> Self {
+        self.options.private_key = Some(private_key.into());
+        self
+    }
+
+    pub fn previous_cert_id<S: Into<String>>(mut self, previous_cert_id: S) -> Self {
+        self.options.previous_cert_id = Some(previous_cert_id.into());
+        self
+    }
+
+    pub fn force(mut self, force: bool) -> Self {
+        self.options.force = Some(force);
+        self
+    }
+
+    pub fn delete_certificate(mut self, delete_certificate: bool) -> Self {
+        self.options.delete_certificate = Some(delete_certificate);
+        self
+    }
+
+    pub fn build(self) -> PutCnameOptions {
+        self.options
+    }
+}
+
+fn write_text_element(writer: &mut quick_xml::Writer<Vec<u8>>, name: &str, value: &str) -> Result<()> {
+    writer.write_event(Event::Start(BytesStart::new(name)))?;
+    writer.write_event(Event::Text(BytesText::new(value)))?;
+    writer.write_event(Event::End(BytesEnd::new(name)))?;
+    Ok(())
+}
+
+/// Build `BucketCnameConfiguration` XML
+fn build_cname_configuration_xml(domain: &str, options: &Option<PutCnameOptions>) -> Result<String> {
+    let mut writer = quick_xml::Writer::new(Vec::new());
+    writer.write_event(Event::Decl(BytesDecl::new("1.0", Some("UTF-8"), None)))?;
+    writer.write_event(Event::Start(BytesStart::new("BucketCnameConfiguration")))?;
+    writer.write_event(Event::Start(BytesStart::new("Cname")))?;
+
+    write_text_element(&mut writer, "Domain", domain)?;
+
+    if let Some(options) = options {
+        if options.has_certificate_configuration() {
+            writer.write_event(Event::Start(BytesStart::new("CertificateConfiguration")))?;
+
+            if let Some(s) = &options.cert_id {
+                write_text_element(&mut writer, "CertId", s)?;
+            }
+
+            if let Some(s) = &options.certificate {
+                write_text_element(&mut writer, "Certificate", s)?;
+            }
+
+            if let Some(s) = &options.private_key {
+                write_text_element(&mut writer, "PrivateKey", s)?;
+            }
+
+            if let Some(s) = &options.previous_cert_id {
+                write_text_element(&mut writer, "PreviousCertId", s)?;
+            }
+
+            if let Some(b) = options.force {
+                write_text_element(&mut writer, "Force", &b.to_string())?;
+            }
+
+            if let Some(b) = options.delete_certificate {
+                write_text_element(&mut writer, "DeleteCertificate", &b.to_string())?;
+            }
+
+            writer.write_event(Event::End(BytesEnd::new("CertificateConfiguration")))?;
+        }
+    }
+
+    writer.write_event(Event::End(BytesEnd::new("Cname")))?;
+    writer.write_event(Event::End(BytesEnd::new("BucketCnameConfiguration")))?;
+
+    Ok(String::from_utf8(writer.into_inner())?)
+}
+
+fn validate_cname_params(bucket_name: &str, domain: &str) -> Result<()> {
+    if !validate_bucket_name(bucket_name) {
+        return Err(Error::InvalidBucketName(bucket_name.to_string()));
+    }
+
+    if domain.is_empty() {
+        return Err(Error::InvalidArgument("domain must not be empty".to_string()));
+    }
+
+    Ok(())
+}
+
+pub(crate) fn build_create_cname_token_request(bucket_name: &str, domain: &str) -> Result<OssRequest> {
+    validate_cname_params(bucket_name, domain)?;
+
+    let xml = build_cname_configuration_xml(domain, &None)?;
+
+    let request = OssRequest::new()
+        .method(RequestMethod::Post)
+        .bucket(bucket_name)
+        .add_query("cname", "")
+        .add_query("comp", "token")
+        .content_type(common::MIME_TYPE_XML)
+        .text_body(xml);
+
+    Ok(request)
+}
+
+pub(crate) fn build_get_cname_token_request(bucket_name: &str, domain: &str) -> Result<OssRequest> {
+    validate_cname_params(bucket_name, domain)?;
+
+    let request = OssRequest::new()
+        .method(RequestMethod::Get)
+        .bucket(bucket_name)
+        .add_query("cname", domain)
+        .add_query("comp", "token");
+
+    Ok(request)
+}
+
+pub(crate) fn build_put_cname_request(bucket_name: &str, domain: &str, options: &Option<PutCnameOptions>) -> Result<OssRequest> {
+    validate_cname_params(bucket_name, domain)?;
+
+    let xml = build_cname_configuration_xml(domain, options)?;
+
+    let request = OssRequest::new()
+        .method(RequestMethod::Post)
+        .bucket(bucket_name)
+        .add_query("cname", "")
+        .add_query("comp", "add")
+        .content_type(common::MIME_TYPE_XML)
+        .text_body(xml);
+
+    Ok(request)
+}
+
+pub(crate) fn build_delete_cname_request(bucket_name: &str, domain: &str) -> Result<OssRequest> {
+    validate_cname_params(bucket_name, domain)?;
+
+    let xml = build_cname_configuration_xml(domain, &None)?;
+
+    let request = OssRequest::new()
+        .method(RequestMethod::Post)
+        .bucket(bucket_name)
+        .add_query("cname", "")
+        .add_query("comp", "delete")
+        .content_type(common::MIME_TYPE_XML)
+        .text_body(xml);
+
+    Ok(request)
+}
+
+#[cfg(test)]
+mod test_cname_common {
+    use crate::{common::OnOff, request::RequestBody};
+
+    use super::{build_put_cname_request, CnameToken, ListCnameResult, PutCnameOptionsBuilder};
+
+    #[test]
+    fn test_parse_list_cname_result() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+        <ListCnameResult>
+          <Bucket>targetbucket</Bucket>
+          <Owner>testowner</Owner>
+          <Cname>
+            <Domain>example.com</Domain>
+            <LastModified>2021-09-15T02:35:07.000Z</LastModified>
+            <Status>Enabled</Status>
+            <Certificate>
+              <Type>CAS</Type>
+              <CertId>493****-cn-hangzhou</CertId>
+              <Status>Disabled</Status>
+              <CreationDate>Wed, 15 Sep 2021 02:35:06 GMT</CreationDate>
+              <Fingerprint>DE:01:CF:EC:7C:A7:98:CB:D8:6E:FB:1D:97:EB:A9:64:1D:4E:**:**</Fingerprint>
+              <ValidStartDate>Wed, 12 Apr 2023 10:14:51 GMT</ValidStartDate>
+              <ValidEndDate>Mon, 4 May 2048 10:14:51 GMT</ValidEndDate>
+            </Certificate>
+            <IsPurgeCdnCache>true</IsPurgeCdnCache>
+          </Cname>
+          <Cname>
+            <Domain>example.org</Domain>
+            <LastModified>2021-09-15T02:34:58.000Z</LastModified>
+            <Status>Enabled</Status>
+          </Cname>
+        </ListCnameResult>"#;
+
+        let ret = ListCnameResult::from_xml(xml).unwrap();
+        assert_eq!(2, ret.cnames.len());
+
+        let cname = &ret.cnames[0];
+        assert_eq!("example.com", cname.domain);
+        assert_eq!(OnOff::Enabled, cname.status);
+        assert!(cname.is_purge_cdn_cache);
+
+        let cert = cname.certificate.as_ref().unwrap();
+        assert_eq!("CAS", cert.cert_type);
+        assert_eq!("493****-cn-hangzhou", cert.cert_id);
+        assert_eq!(OnOff::Disabled, cert.status);
+        assert_eq!("Mon, 4 May 2048 10:14:51 GMT", cert.valid_end_date);
+
+        assert!(ret.cnames[1].certificate.is_none());
+    }
+
+    #[test]
+    fn test_parse_cname_token() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+        <CnameToken>
+          <Bucket>examplebucket</Bucket>
+          <Cname>example.com</Cname>
+          <Token>be1d49d863dea9ffeff3df7d6455****</Token>
+          <ExpireTime>Wed, 23 Feb 2022 21:16:37 GMT</ExpireTime>
+        </CnameToken>"#;
+
+        let token = CnameToken::from_xml(xml).unwrap();
+        assert_eq!("examplebucket", token.bucket);
+        assert_eq!("example.com", token.cname);
+        assert_eq!("be1d49d863dea9ffeff3df7d6455****", token.token);
+        assert_eq!("Wed, 23 Feb 2022 21:16:37 GMT", token.expire_time);
+    }
+
+    #[test]
+    fn test_build_put_cname_request() {
+        let request = build_put_cname_request("test-bucket", "example.com", &None).unwrap();
+        assert_eq!("cname&comp=add", request.build_canonical_query_string());
+
+        let xml = match &request.body {
+            RequestBody::Text(s) => s.clone(),
+            _ => panic!("unexpected request body"),
+        };
+        assert!(xml.ends_with("<BucketCnameConfiguration><Cname><Domain>example.com</Domain></Cname></BucketCnameConfiguration>"));
+
+        let options = PutCnameOptionsBuilder::new().cert_id("493****-cn-hangzhou").force(true).build();
+        let request = build_put_cname_request("test-bucket", "example.com", &Some(options)).unwrap();
+
+        let xml = match &request.body {
+            RequestBody::Text(s) => s.clone(),
+            _ => panic!("unexpected request body"),
+        };
+        assert!(xml.contains("<CertificateConfiguration><CertId>493****-cn-hangzhou</CertId><Force>true</Force></CertificateConfiguration>"));
+    }
+}
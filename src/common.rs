@@ -1,9 +1,14 @@
 //! Common types: structs and enumerations
-use std::{collections::HashMap, fmt::Display};
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    ops::{Range, RangeFrom, RangeInclusive},
+};
 
 use quick_xml::events::Event;
 
 use crate::error::Error;
+use crate::request::OssRequest;
 use crate::Result;
 
 pub const VERSION: &str = "0.2.1";
@@ -124,7 +129,10 @@ impl TryFrom<&str> for StorageClass {
             "Archive" => Ok(StorageClass::Archive),
             "ColdArchive" => Ok(StorageClass::ColdArchive),
             "DeepColdArchive" => Ok(StorageClass::DeepColdArchive),
-            _ => Err(Error::Other(format!("Invalid StorageClass value: {}", s))),
+            _ => Err(Error::InvalidEnumValue {
+                name: "StorageClass",
+                value: s.to_string(),
+            }),
         }
     }
 }
@@ -187,7 +195,10 @@ impl TryFrom<&str> for DataRedundancyType {
         match value {
             "LRS" => Ok(DataRedundancyType::LRS),
             "ZRS" => Ok(DataRedundancyType::ZRS),
-            _ => Err(Error::Other(format!("Invalid DataRedundancyType value: {}", value))),
+            _ => Err(Error::InvalidEnumValue {
+                name: "DataRedundancyType",
+                value: value.to_string(),
+            }),
         }
     }
 }
@@ -258,7 +269,10 @@ impl TryFrom<&str> for OnOff {
         match value {
             "Enabled" => Ok(OnOff::Enabled),
             "Disabled" => Ok(OnOff::Disabled),
-            _ => Err(Error::Other(format!("Invalid CrossRegionReplication value: {}", value))),
+            _ => Err(Error::InvalidEnumValue {
+                name: "CrossRegionReplication",
+                value: value.to_string(),
+            }),
         }
     }
 }
@@ -284,6 +298,222 @@ pub type CrossRegionReplication = OnOff;
 pub type TransferAcceleration = OnOff;
 pub type AccessMonitor = OnOff;
 
+///
+/// Who pays for the requests and traffic of a bucket.
+///
+/// - `BucketOwner`: The bucket owner pays. This is the default value.
+/// - `Requester`: The requester pays. Requesters must send `x-oss-request-payer: requester` to access objects in the bucket.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde-support", derive(serde::Serialize, serde::Deserialize))]
+pub enum RequestPayer {
+    #[default]
+    #[cfg_attr(feature = "serde-support", serde(rename = "BucketOwner"))]
+    BucketOwner,
+
+    #[cfg_attr(feature = "serde-support", serde(rename = "Requester"))]
+    Requester,
+}
+
+impl RequestPayer {
+    pub fn as_str(&self) -> &str {
+        match self {
+            RequestPayer::BucketOwner => "BucketOwner",
+            RequestPayer::Requester => "Requester",
+        }
+    }
+}
+
+impl AsRef<str> for RequestPayer {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl Display for RequestPayer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl TryFrom<&str> for RequestPayer {
+    type Error = crate::error::Error;
+
+    fn try_from(value: &str) -> std::result::Result<Self, Self::Error> {
+        match value {
+            "BucketOwner" => Ok(RequestPayer::BucketOwner),
+            "Requester" => Ok(RequestPayer::Requester),
+            _ => Err(Error::InvalidEnumValue {
+                name: "RequestPayer",
+                value: value.to_string(),
+            }),
+        }
+    }
+}
+
+impl TryFrom<String> for RequestPayer {
+    type Error = crate::error::Error;
+
+    fn try_from(value: String) -> std::result::Result<Self, Self::Error> {
+        Self::try_from(value.as_str())
+    }
+}
+
+impl TryFrom<&String> for RequestPayer {
+    type Error = crate::error::Error;
+
+    fn try_from(value: &String) -> std::result::Result<Self, Self::Error> {
+        Self::try_from(value.as_str())
+    }
+}
+
+/// Add `x-oss-request-payer: requester` header to the request if the requester pays.
+pub(crate) fn apply_request_payer(request: OssRequest, request_payer: &Option<RequestPayer>) -> OssRequest {
+    match request_payer {
+        Some(RequestPayer::Requester) => request.add_header("x-oss-request-payer", "requester"),
+        _ => request,
+    }
+}
+
+///
+/// Transfer acceleration endpoints.
+/// The transfer acceleration must be enabled on the bucket before sending requests to these endpoints.
+///
+/// - `Global`: `oss-accelerate.aliyuncs.com`
+/// - `Overseas`: `oss-accelerate-overseas.aliyuncs.com`, for the regions outside of the Chinese mainland
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde-support", derive(serde::Serialize, serde::Deserialize))]
+pub enum AccelerateEndpoint {
+    #[default]
+    #[cfg_attr(feature = "serde-support", serde(rename = "oss-accelerate.aliyuncs.com"))]
+    Global,
+
+    #[cfg_attr(feature = "serde-support", serde(rename = "oss-accelerate-overseas.aliyuncs.com"))]
+    Overseas,
+}
+
+impl AccelerateEndpoint {
+    pub fn as_str(&self) -> &str {
+        match self {
+            AccelerateEndpoint::Global => "oss-accelerate.aliyuncs.com",
+            AccelerateEndpoint::Overseas => "oss-accelerate-overseas.aliyuncs.com",
+        }
+    }
+}
+
+impl AsRef<str> for AccelerateEndpoint {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl Display for AccelerateEndpoint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl TryFrom<&str> for AccelerateEndpoint {
+    type Error = crate::error::Error;
+
+    fn try_from(value: &str) -> std::result::Result<Self, Self::Error> {
+        match value {
+            "oss-accelerate.aliyuncs.com" => Ok(AccelerateEndpoint::Global),
+            "oss-accelerate-overseas.aliyuncs.com" => Ok(AccelerateEndpoint::Overseas),
+            _ => Err(Error::InvalidEnumValue {
+                name: "AccelerateEndpoint",
+                value: value.to_string(),
+            }),
+        }
+    }
+}
+
+impl TryFrom<String> for AccelerateEndpoint {
+    type Error = crate::error::Error;
+
+    fn try_from(value: String) -> std::result::Result<Self, Self::Error> {
+        Self::try_from(value.as_str())
+    }
+}
+
+impl TryFrom<&String> for AccelerateEndpoint {
+    type Error = crate::error::Error;
+
+    fn try_from(value: &String) -> std::result::Result<Self, Self::Error> {
+        Self::try_from(value.as_str())
+    }
+}
+
+/// Signature version used to sign requests and presigned urls.
+///
+/// - `V4`: `OSS4-HMAC-SHA256`. This is the default and recommended version.
+/// - `V1`: The legacy `OSS AccessKeyId:Signature` HMAC-SHA1 signature,
+///   for private deployments and OSS-compatible servers which do not support V4.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde-support", derive(serde::Serialize, serde::Deserialize))]
+pub enum SignatureVersion {
+    #[cfg_attr(feature = "serde-support", serde(rename = "v1"))]
+    V1,
+
+    #[default]
+    #[cfg_attr(feature = "serde-support", serde(rename = "v4"))]
+    V4,
+}
+
+impl SignatureVersion {
+    pub fn as_str(&self) -> &str {
+        match self {
+            SignatureVersion::V1 => "v1",
+            SignatureVersion::V4 => "v4",
+        }
+    }
+}
+
+impl AsRef<str> for SignatureVersion {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl Display for SignatureVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl TryFrom<&str> for SignatureVersion {
+    type Error = crate::error::Error;
+
+    fn try_from(value: &str) -> std::result::Result<Self, Self::Error> {
+        match value {
+            "v1" => Ok(SignatureVersion::V1),
+            "v4" => Ok(SignatureVersion::V4),
+            _ => Err(Error::InvalidEnumValue {
+                name: "SignatureVersion",
+                value: value.to_string(),
+            }),
+        }
+    }
+}
+
+impl TryFrom<String> for SignatureVersion {
+    type Error = crate::error::Error;
+
+    fn try_from(value: String) -> std::result::Result<Self, Self::Error> {
+        Self::try_from(value.as_str())
+    }
+}
+
+impl TryFrom<&String> for SignatureVersion {
+    type Error = crate::error::Error;
+
+    fn try_from(value: &String) -> std::result::Result<Self, Self::Error> {
+        Self::try_from(value.as_str())
+    }
+}
+
 ///
 /// Versioning enumeration
 ///
@@ -326,7 +556,10 @@ impl TryFrom<&str> for Versioning {
         match value {
             "Enabled" => Ok(Versioning::Enabled),
             "Disabled" => Ok(Versioning::Suspended),
-            _ => Err(Error::Other(format!("Invalid Versioning value: {}", value))),
+            _ => Err(Error::InvalidEnumValue {
+                name: "Versioning",
+                value: value.to_string(),
+            }),
         }
     }
 }
@@ -391,7 +624,10 @@ impl TryFrom<&str> for ServerSideEncryptionAlgorithm {
             "KMS" => Ok(ServerSideEncryptionAlgorithm::KMS),
             "AES256" => Ok(ServerSideEncryptionAlgorithm::AES256),
             "SM4" => Ok(ServerSideEncryptionAlgorithm::SM4),
-            _ => Err(Error::Other(format!("Invalid ServerSideEncryptionAlgorithm value: {}", value))),
+            _ => Err(Error::InvalidEnumValue {
+                name: "ServerSideEncryptionAlgorithm",
+                value: value.to_string(),
+            }),
         }
     }
 }
@@ -471,7 +707,10 @@ impl TryFrom<&str> for ObjectType {
             "Multipart" => Ok(ObjectType::Multipart),
             "Appendable" => Ok(ObjectType::Appendable),
             "Symlink" => Ok(ObjectType::Symlink),
-            _ => Err(Error::Other(format!("Invalid ObjectType value: {}", value))),
+            _ => Err(Error::InvalidEnumValue {
+                name: "ObjectType",
+                value: value.to_string(),
+            }),
         }
     }
 }
@@ -539,7 +778,10 @@ impl TryFrom<&str> for MetadataDirective {
         match value {
             "COPY" => Ok(MetadataDirective::Copy),
             "REPLACE" => Ok(MetadataDirective::Replace),
-            _ => Err(Error::Other(format!("Invalid MetadataDirective value: {}", value))),
+            _ => Err(Error::InvalidEnumValue {
+                name: "MetadataDirective",
+                value: value.to_string(),
+            }),
         }
     }
 }
@@ -605,7 +847,10 @@ impl TryFrom<&str> for TagDirective {
         match value {
             "Copy" => Ok(TagDirective::Copy),
             "Replace" => Ok(TagDirective::Replace),
-            _ => Err(Error::Other(format!("Invalid MetadataDirective value: {}", value))),
+            _ => Err(Error::InvalidEnumValue {
+                name: "MetadataDirective",
+                value: value.to_string(),
+            }),
         }
     }
 }
@@ -633,6 +878,172 @@ pub struct VersionIdOnlyOptions {
     pub version_id: Option<String>,
 }
 
+///
+/// Byte range of an object, used in `Range` of get object and `x-oss-copy-source-range` of upload part copy.
+/// Positions start from `0` and both ends are inclusive.
+///
+/// - `FromTo(start, end)`: `bytes=start-end`
+/// - `From(start)`: `bytes=start-`, from `start` to the end of the object
+/// - `Suffix(n)`: `bytes=-n`, the last `n` bytes of the object
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde-support", derive(serde::Serialize, serde::Deserialize))]
+pub enum ByteRange {
+    FromTo(u64, u64),
+    From(u64),
+    Suffix(u64),
+}
+
+impl Display for ByteRange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ByteRange::FromTo(start, end) => write!(f, "bytes={}-{}", start, end),
+            ByteRange::From(start) => write!(f, "bytes={}-", start),
+            ByteRange::Suffix(n) => write!(f, "bytes=-{}", n),
+        }
+    }
+}
+
+/// `start..end` is converted to `bytes=start-(end - 1)`. `end` must be greater than `start`.
+impl TryFrom<Range<u64>> for ByteRange {
+    type Error = crate::error::Error;
+
+    fn try_from(value: Range<u64>) -> std::result::Result<Self, Self::Error> {
+        if value.start >= value.end {
+            return Err(Error::InvalidArgument(format!("empty byte range: {}..{}", value.start, value.end)));
+        }
+
+        Ok(ByteRange::FromTo(value.start, value.end - 1))
+    }
+}
+
+impl From<RangeInclusive<u64>> for ByteRange {
+    fn from(value: RangeInclusive<u64>) -> Self {
+        ByteRange::FromTo(*value.start(), *value.end())
+    }
+}
+
+impl From<RangeFrom<u64>> for ByteRange {
+    fn from(value: RangeFrom<u64>) -> Self {
+        ByteRange::From(value.start)
+    }
+}
+
+impl TryFrom<&str> for ByteRange {
+    type Error = crate::error::Error;
+
+    /// Parse from string like `bytes=0-9`, `bytes=10-` or `bytes=-10`
+    fn try_from(value: &str) -> std::result::Result<Self, Self::Error> {
+        let invalid = || Error::InvalidEnumValue {
+            name: "ByteRange",
+            value: value.to_string(),
+        };
+
+        let (start, end) = value.strip_prefix("bytes=").and_then(|s| s.split_once('-')).ok_or_else(invalid)?;
+
+        match (start.is_empty(), end.is_empty()) {
+            (false, false) => {
+                let start = start.parse::<u64>().map_err(|_| invalid())?;
+                let end = end.parse::<u64>().map_err(|_| invalid())?;
+                if start > end {
+                    return Err(invalid());
+                }
+                Ok(ByteRange::FromTo(start, end))
+            }
+            (false, true) => Ok(ByteRange::From(start.parse::<u64>().map_err(|_| invalid())?)),
+            (true, false) => Ok(ByteRange::Suffix(end.parse::<u64>().map_err(|_| invalid())?)),
+            (true, true) => Err(invalid()),
+        }
+    }
+}
+
+impl TryFrom<String> for ByteRange {
+    type Error = crate::error::Error;
+
+    fn try_from(value: String) -> std::result::Result<Self, Self::Error> {
+        Self::try_from(value.as_str())
+    }
+}
+
+impl TryFrom<&String> for ByteRange {
+    type Error = crate::error::Error;
+
+    fn try_from(value: &String) -> std::result::Result<Self, Self::Error> {
+        Self::try_from(value.as_str())
+    }
+}
+
+///
+/// Parsed `Content-Range` response header, e.g. `bytes 0-9/44`.
+/// `start` and `end` are both inclusive. `total` is `None` if the total size is unknown (`*`).
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde-support", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde-camelcase", serde(rename_all = "camelCase"))]
+pub struct ContentRange {
+    pub start: u64,
+    pub end: u64,
+    pub total: Option<u64>,
+}
+
+impl ContentRange {
+    /// The length of the returned content
+    pub fn len(&self) -> u64 {
+        self.end - self.start + 1
+    }
+
+    /// A valid content range always contains at least 1 byte
+    pub fn is_empty(&self) -> bool {
+        false
+    }
+}
+
+impl TryFrom<&str> for ContentRange {
+    type Error = crate::error::Error;
+
+    fn try_from(value: &str) -> std::result::Result<Self, Self::Error> {
+        let invalid = || Error::InvalidEnumValue {
+            name: "ContentRange",
+            value: value.to_string(),
+        };
+
+        let (range, total) = value.trim().strip_prefix("bytes ").and_then(|s| s.split_once('/')).ok_or_else(invalid)?;
+
+        // some documents show the range as `0~9`
+        let (start, end) = range.split_once('-').or_else(|| range.split_once('~')).ok_or_else(invalid)?;
+
+        let start = start.trim().parse::<u64>().map_err(|_| invalid())?;
+        let end = end.trim().parse::<u64>().map_err(|_| invalid())?;
+
+        if start > end {
+            return Err(invalid());
+        }
+
+        let total = match total.trim() {
+            "*" => None,
+            s => Some(s.parse::<u64>().map_err(|_| invalid())?),
+        };
+
+        Ok(ContentRange { start, end, total })
+    }
+}
+
+impl TryFrom<String> for ContentRange {
+    type Error = crate::error::Error;
+
+    fn try_from(value: String) -> std::result::Result<Self, Self::Error> {
+        Self::try_from(value.as_str())
+    }
+}
+
+impl TryFrom<&String> for ContentRange {
+    type Error = crate::error::Error;
+
+    fn try_from(value: &String) -> std::result::Result<Self, Self::Error> {
+        Self::try_from(value.as_str())
+    }
+}
+
 /// Build tags string
 pub(crate) fn build_tag_string(tags: &HashMap<String, String>) -> String {
     tags.iter()
@@ -646,3 +1057,57 @@ pub(crate) fn build_tag_string(tags: &HashMap<String, String>) -> String {
         .collect::<Vec<_>>()
         .join("&")
 }
+
+#[cfg(test)]
+mod test_common {
+    use crate::error::Error;
+
+    use super::{ByteRange, ContentRange};
+
+    #[test]
+    fn test_byte_range() {
+        assert_eq!("bytes=0-9", ByteRange::try_from(0..10).unwrap().to_string());
+        assert!(matches!(ByteRange::try_from(0..0), Err(Error::InvalidArgument(_))));
+
+        // inverted range
+        let (start, end) = (5, 3);
+        assert!(matches!(ByteRange::try_from(start..end), Err(Error::InvalidArgument(_))));
+        assert_eq!("bytes=0-9", ByteRange::from(0..=9).to_string());
+        assert_eq!("bytes=100-", ByteRange::from(100..).to_string());
+        assert_eq!("bytes=-500", ByteRange::Suffix(500).to_string());
+
+        assert_eq!(ByteRange::FromTo(0, 499), ByteRange::try_from("bytes=0-499").unwrap());
+        assert_eq!(ByteRange::From(185001), ByteRange::try_from("bytes=185001-").unwrap());
+        assert_eq!(ByteRange::Suffix(10), ByteRange::try_from("bytes=-10").unwrap());
+        assert!(ByteRange::try_from("bytes=10-1").is_err());
+        assert!(ByteRange::try_from("bytes=-").is_err());
+        assert!(ByteRange::try_from("0-9").is_err());
+    }
+
+    #[test]
+    fn test_content_range() {
+        let r = ContentRange::try_from("bytes 0-9/44").unwrap();
+        assert_eq!(
+            ContentRange {
+                start: 0,
+                end: 9,
+                total: Some(44)
+            },
+            r
+        );
+        assert_eq!(10, r.len());
+
+        let r = ContentRange::try_from("bytes 100~199/*").unwrap();
+        assert_eq!(
+            ContentRange {
+                start: 100,
+                end: 199,
+                total: None
+            },
+            r
+        );
+
+        assert!(ContentRange::try_from("bytes */44").is_err());
+        assert!(ContentRange::try_from("0-9/44").is_err());
+    }
+}
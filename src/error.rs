@@ -1,7 +1,10 @@
-use std::fmt::Display;
+use std::{collections::HashMap, fmt::Display};
 
+use base64::{prelude::BASE64_STANDARD, Engine};
 use thiserror::Error;
 
+use crate::util::parse_server_time;
+
 //
 // Aliyun OSS API error response
 //
@@ -26,6 +29,15 @@ pub struct ErrorResponse {
     pub host_id: String,
     pub ec: String,
     pub recommend_doc: String,
+
+    /// Server time of `RequestTimeTooSkewed` error. e.g. `2015-01-19T08:09:52.000Z`
+    pub server_time: String,
+
+    /// HTTP status code of the response
+    pub status: u16,
+
+    /// Headers of the response
+    pub headers: HashMap<String, String>,
 }
 
 impl ErrorResponse {
@@ -50,6 +62,7 @@ impl ErrorResponse {
                     "HostId" => ret.host_id = String::from_utf8_lossy(t.as_ref()).to_string(),
                     "EC" => ret.ec = String::from_utf8_lossy(t.as_ref()).to_string(),
                     "RecommendDoc" => ret.recommend_doc = String::from_utf8_lossy(t.as_ref()).to_string(),
+                    "ServerTime" => ret.server_time = String::from_utf8_lossy(t.as_ref()).to_string(),
                     _ => {}
                 },
 
@@ -65,12 +78,243 @@ impl ErrorResponse {
     }
 }
 
+impl ErrorResponse {
+    /// Decode the base64 encoded error xml in `x-oss-err` response header.
+    /// Returns `None` if the header is absent or can not be decoded.
+    pub(crate) fn from_error_header(headers: &HashMap<String, String>) -> Option<Self> {
+        let encoded = headers.get("x-oss-err")?;
+        let decoded = BASE64_STANDARD.decode(encoded.trim()).ok()?;
+        let xml = String::from_utf8(decoded).ok()?;
+        Self::from_xml(&xml).ok().filter(|r| !r.code.is_empty())
+    }
+
+    /// The typed error code
+    pub fn error_code(&self) -> ApiErrorCode {
+        ApiErrorCode::from(self.code.as_str())
+    }
+}
+
 impl Display for ErrorResponse {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "Code: {}, Message: {}, Request Id: {}", self.code, self.message, self.request_id)
     }
 }
 
+/// Common error codes of OSS API. See the official document for all error codes:
+/// <https://help.aliyun.com/zh/oss/support/http-status-code>
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ApiErrorCode {
+    AccessDenied,
+    BucketAlreadyExists,
+    BucketNotEmpty,
+    CallbackFailed,
+    DownloadTrafficRateLimitExceeded,
+    EntityTooLarge,
+    EntityTooSmall,
+    FileAlreadyExists,
+    InternalError,
+    InvalidAccessKeyId,
+    InvalidArgument,
+    InvalidBucketName,
+    InvalidDigest,
+    InvalidObjectName,
+    InvalidPart,
+    InvalidPartOrder,
+    InvalidTargetBucketForLogging,
+    MalformedXML,
+    MethodNotAllowed,
+    MissingContentLength,
+    NoSuchBucket,
+    NoSuchKey,
+    NoSuchUpload,
+    NoSuchVersion,
+    NotImplemented,
+    ObjectNotAppendable,
+    PositionNotEqualToLength,
+    PreconditionFailed,
+    QpsLimitExceeded,
+    RequestTimeTooSkewed,
+    RequestTimeout,
+    SecurityTokenExpired,
+    ServiceUnavailable,
+    SignatureDoesNotMatch,
+    TooManyBuckets,
+    UploadTrafficRateLimitExceeded,
+
+    /// Codes not listed above
+    Unknown(String),
+}
+
+impl ApiErrorCode {
+    pub fn as_str(&self) -> &str {
+        match self {
+            ApiErrorCode::AccessDenied => "AccessDenied",
+            ApiErrorCode::BucketAlreadyExists => "BucketAlreadyExists",
+            ApiErrorCode::BucketNotEmpty => "BucketNotEmpty",
+            ApiErrorCode::CallbackFailed => "CallbackFailed",
+            ApiErrorCode::DownloadTrafficRateLimitExceeded => "DownloadTrafficRateLimitExceeded",
+            ApiErrorCode::EntityTooLarge => "EntityTooLarge",
+            ApiErrorCode::EntityTooSmall => "EntityTooSmall",
+            ApiErrorCode::FileAlreadyExists => "FileAlreadyExists",
+            ApiErrorCode::InternalError => "InternalError",
+            ApiErrorCode::InvalidAccessKeyId => "InvalidAccessKeyId",
+            ApiErrorCode::InvalidArgument => "InvalidArgument",
+            ApiErrorCode::InvalidBucketName => "InvalidBucketName",
+            ApiErrorCode::InvalidDigest => "InvalidDigest",
+            ApiErrorCode::InvalidObjectName => "InvalidObjectName",
+            ApiErrorCode::InvalidPart => "InvalidPart",
+            ApiErrorCode::InvalidPartOrder => "InvalidPartOrder",
+            ApiErrorCode::InvalidTargetBucketForLogging => "InvalidTargetBucketForLogging",
+            ApiErrorCode::MalformedXML => "MalformedXML",
+            ApiErrorCode::MethodNotAllowed => "MethodNotAllowed",
+            ApiErrorCode::MissingContentLength => "MissingContentLength",
+            ApiErrorCode::NoSuchBucket => "NoSuchBucket",
+            ApiErrorCode::NoSuchKey => "NoSuchKey",
+            ApiErrorCode::NoSuchUpload => "NoSuchUpload",
+            ApiErrorCode::NoSuchVersion => "NoSuchVersion",
+            ApiErrorCode::NotImplemented => "NotImplemented",
+            ApiErrorCode::ObjectNotAppendable => "ObjectNotAppendable",
+            ApiErrorCode::PositionNotEqualToLength => "PositionNotEqualToLength",
+            ApiErrorCode::PreconditionFailed => "PreconditionFailed",
+            ApiErrorCode::QpsLimitExceeded => "QpsLimitExceeded",
+            ApiErrorCode::RequestTimeTooSkewed => "RequestTimeTooSkewed",
+            ApiErrorCode::RequestTimeout => "RequestTimeout",
+            ApiErrorCode::SecurityTokenExpired => "SecurityTokenExpired",
+            ApiErrorCode::ServiceUnavailable => "ServiceUnavailable",
+            ApiErrorCode::SignatureDoesNotMatch => "SignatureDoesNotMatch",
+            ApiErrorCode::TooManyBuckets => "TooManyBuckets",
+            ApiErrorCode::UploadTrafficRateLimitExceeded => "UploadTrafficRateLimitExceeded",
+            ApiErrorCode::Unknown(s) => s.as_str(),
+        }
+    }
+}
+
+impl AsRef<str> for ApiErrorCode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl Display for ApiErrorCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl From<&str> for ApiErrorCode {
+    fn from(value: &str) -> Self {
+        match value {
+            "AccessDenied" => ApiErrorCode::AccessDenied,
+            "BucketAlreadyExists" => ApiErrorCode::BucketAlreadyExists,
+            "BucketNotEmpty" => ApiErrorCode::BucketNotEmpty,
+            "CallbackFailed" => ApiErrorCode::CallbackFailed,
+            "DownloadTrafficRateLimitExceeded" => ApiErrorCode::DownloadTrafficRateLimitExceeded,
+            "EntityTooLarge" => ApiErrorCode::EntityTooLarge,
+            "EntityTooSmall" => ApiErrorCode::EntityTooSmall,
+            "FileAlreadyExists" => ApiErrorCode::FileAlreadyExists,
+            "InternalError" => ApiErrorCode::InternalError,
+            "InvalidAccessKeyId" => ApiErrorCode::InvalidAccessKeyId,
+            "InvalidArgument" => ApiErrorCode::InvalidArgument,
+            "InvalidBucketName" => ApiErrorCode::InvalidBucketName,
+            "InvalidDigest" => ApiErrorCode::InvalidDigest,
+            "InvalidObjectName" => ApiErrorCode::InvalidObjectName,
+            "InvalidPart" => ApiErrorCode::InvalidPart,
+            "InvalidPartOrder" => ApiErrorCode::InvalidPartOrder,
+            "InvalidTargetBucketForLogging" => ApiErrorCode::InvalidTargetBucketForLogging,
+            "MalformedXML" => ApiErrorCode::MalformedXML,
+            "MethodNotAllowed" => ApiErrorCode::MethodNotAllowed,
+            "MissingContentLength" => ApiErrorCode::MissingContentLength,
+            "NoSuchBucket" => ApiErrorCode::NoSuchBucket,
+            "NoSuchKey" => ApiErrorCode::NoSuchKey,
+            "NoSuchUpload" => ApiErrorCode::NoSuchUpload,
+            "NoSuchVersion" => ApiErrorCode::NoSuchVersion,
+            "NotImplemented" => ApiErrorCode::NotImplemented,
+            "ObjectNotAppendable" => ApiErrorCode::ObjectNotAppendable,
+            "PositionNotEqualToLength" => ApiErrorCode::PositionNotEqualToLength,
+            "PreconditionFailed" => ApiErrorCode::PreconditionFailed,
+            "QpsLimitExceeded" => ApiErrorCode::QpsLimitExceeded,
+            "RequestTimeTooSkewed" => ApiErrorCode::RequestTimeTooSkewed,
+            "RequestTimeout" => ApiErrorCode::RequestTimeout,
+            "SecurityTokenExpired" => ApiErrorCode::SecurityTokenExpired,
+            "ServiceUnavailable" => ApiErrorCode::ServiceUnavailable,
+            "SignatureDoesNotMatch" => ApiErrorCode::SignatureDoesNotMatch,
+            "TooManyBuckets" => ApiErrorCode::TooManyBuckets,
+            "UploadTrafficRateLimitExceeded" => ApiErrorCode::UploadTrafficRateLimitExceeded,
+            s => ApiErrorCode::Unknown(s.to_string()),
+        }
+    }
+}
+
+impl From<String> for ApiErrorCode {
+    fn from(value: String) -> Self {
+        Self::from(value.as_str())
+    }
+}
+
+impl From<&String> for ApiErrorCode {
+    fn from(value: &String) -> Self {
+        Self::from(value.as_str())
+    }
+}
+
+/// Reasons why a presigned url fails the verification
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PresignedUrlError {
+    /// Required query parameters are missing or malformed
+    Malformed(String),
+
+    /// The credential scope (access key id, date, region and service) does not match
+    CredentialMismatch(String),
+
+    /// The signature does not match the one calculated from the canonical request
+    SignatureMismatch,
+
+    /// The signature is valid, but the url is expired
+    Expired,
+
+    /// The url can not be verified with the configuration of the client, e.g. signature version 1 or cname mode
+    Unsupported(String),
+}
+
+impl Display for PresignedUrlError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PresignedUrlError::Malformed(s) => write!(f, "malformed presigned url: {}", s),
+            PresignedUrlError::CredentialMismatch(s) => write!(f, "credential mismatch: {}", s),
+            PresignedUrlError::SignatureMismatch => write!(f, "signature mismatch"),
+            PresignedUrlError::Expired => write!(f, "presigned url is expired"),
+            PresignedUrlError::Unsupported(s) => write!(f, "unsupported presigned url verification: {}", s),
+        }
+    }
+}
+
+/// Reasons why a callback request fails the signature verification
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CallbackSignatureError {
+    /// Required header is missing or malformed, e.g. `authorization` and `x-oss-pub-key-url`
+    InvalidHeader(String),
+
+    /// The public key url is not hosted on the trusted `gosspublic.alicdn.com`
+    UntrustedPublicKeyUrl(String),
+
+    /// The public key can not be fetched or parsed
+    InvalidPublicKey(String),
+
+    /// The signature does not match the request
+    SignatureMismatch,
+}
+
+impl Display for CallbackSignatureError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CallbackSignatureError::InvalidHeader(s) => write!(f, "invalid callback header: {}", s),
+            CallbackSignatureError::UntrustedPublicKeyUrl(s) => write!(f, "untrusted public key url: {}", s),
+            CallbackSignatureError::InvalidPublicKey(s) => write!(f, "invalid public key: {}", s),
+            CallbackSignatureError::SignatureMismatch => write!(f, "callback signature mismatch"),
+        }
+    }
+}
+
 #[derive(Error, Debug)]
 pub enum Error {
     #[error("{0}")]
@@ -91,6 +335,22 @@ pub enum Error {
     #[error("{0}")]
     ApiError(Box<ErrorResponse>),
 
+    /// The API responds `412 Precondition Failed`, e.g. `If-Match` or `If-None-Match` condition is not met.
+    #[error("precondition failed: {0}")]
+    PreconditionFailed(Box<ErrorResponse>),
+
+    /// The select operation fails in the middle of the response, reported by the end frame
+    #[error("select object failed, status: {status}, message: {message}")]
+    SelectObjectFailed { status: u32, message: String },
+
+    /// Verification of a presigned url fails
+    #[error("{0}")]
+    PresignedUrl(PresignedUrlError),
+
+    /// Verification of a callback request from OSS fails
+    #[error("{0}")]
+    CallbackSignature(CallbackSignatureError),
+
     #[error("{0}")]
     IoError(#[from] std::io::Error),
 
@@ -100,8 +360,12 @@ pub enum Error {
     #[error("{0}")]
     ParseIntError(#[from] std::num::ParseIntError),
 
-    #[error("{0}")]
-    StatusError(reqwest::StatusCode),
+    /// The API call fails without response body and `x-oss-err` header
+    #[error("{status}")]
+    StatusError {
+        status: reqwest::StatusCode,
+        headers: HashMap<String, String>,
+    },
 
     #[error("{0}")]
     SerdeJsonError(#[from] serde_json::Error),
@@ -109,6 +373,366 @@ pub enum Error {
     #[error("{0}")]
     DecodeError(#[from] base64::DecodeError),
 
+    /// The bucket name is invalid
+    #[error("invalid bucket name: {0}")]
+    InvalidBucketName(String),
+
+    /// The object key is invalid
+    #[error("invalid object key: {0}")]
+    InvalidObjectKey(String),
+
+    /// The string can not be converted to the enum
+    #[error("Invalid {name} value: {value}")]
+    InvalidEnumValue { name: &'static str, value: String },
+
+    /// Other invalid arguments
+    #[error("{0}")]
+    InvalidArgument(String),
+
+    /// The response from OSS can not be recognized
     #[error("{0}")]
-    Other(String),
+    InvalidResponse(String),
+}
+
+impl Error {
+    /// Build the error of a failed API call. `error_response` is parsed from the response body if there is one.
+    /// `412 Precondition Failed` is mapped to [`Error::PreconditionFailed`] even if there is no response body, e.g. `HEAD` requests.
+    pub(crate) fn from_api_response(status: reqwest::StatusCode, headers: &HashMap<String, String>, error_response: Option<ErrorResponse>) -> Self {
+        // For requests without response body, e.g. `HEAD`, the error xml is base64 encoded in `x-oss-err` header
+        let error_response = error_response.or_else(|| ErrorResponse::from_error_header(headers));
+
+        let error_response = error_response.map(|r| {
+            let request_id = if r.request_id.is_empty() {
+                headers.get("x-oss-request-id").cloned().unwrap_or_default()
+            } else {
+                r.request_id
+            };
+
+            let ec = if r.ec.is_empty() {
+                headers.get("x-oss-ec").cloned().unwrap_or_default()
+            } else {
+                r.ec
+            };
+
+            ErrorResponse {
+                request_id,
+                ec,
+                status: status.as_u16(),
+                headers: headers.clone(),
+                ..r
+            }
+        });
+
+        match (status, error_response) {
+            (reqwest::StatusCode::PRECONDITION_FAILED, Some(r)) => Error::PreconditionFailed(Box::new(r)),
+            (reqwest::StatusCode::PRECONDITION_FAILED, None) => Error::PreconditionFailed(Box::new(ErrorResponse {
+                code: "PreconditionFailed".to_string(),
+                message: "At least one of the pre-conditions you specified did not hold".to_string(),
+                request_id: headers.get("x-oss-request-id").cloned().unwrap_or_default(),
+                ec: headers.get("x-oss-ec").cloned().unwrap_or_default(),
+                status: status.as_u16(),
+                headers: headers.clone(),
+                ..Default::default()
+            })),
+            (_, Some(r)) => Error::ApiError(Box::new(r)),
+            (_, None) => Error::StatusError {
+                status,
+                headers: headers.clone(),
+            },
+        }
+    }
+
+    /// The error response of failed API calls
+    pub fn error_response(&self) -> Option<&ErrorResponse> {
+        match self {
+            Error::ApiError(r) | Error::PreconditionFailed(r) => Some(r),
+            _ => None,
+        }
+    }
+
+    /// HTTP status code of failed API calls
+    pub fn status(&self) -> Option<reqwest::StatusCode> {
+        match self {
+            Error::StatusError { status, .. } => Some(*status),
+            Error::ReqwestError(e) => e.status(),
+            _ => self.error_response().and_then(|r| reqwest::StatusCode::from_u16(r.status).ok()),
+        }
+    }
+
+    /// Response headers of failed API calls
+    pub fn headers(&self) -> Option<&HashMap<String, String>> {
+        match self {
+            Error::StatusError { headers, .. } => Some(headers),
+            _ => self.error_response().map(|r| &r.headers),
+        }
+    }
+
+    /// The request id of failed API calls, which is useful for filing support tickets
+    pub fn request_id(&self) -> Option<&str> {
+        match self {
+            Error::StatusError { headers, .. } => headers.get("x-oss-request-id").map(|s| s.as_str()),
+            _ => self.error_response().map(|r| r.request_id.as_str()),
+        }
+        .filter(|s| !s.is_empty())
+    }
+
+    /// The `EC` (detail error code) of failed API calls
+    pub fn ec(&self) -> Option<&str> {
+        match self {
+            Error::StatusError { headers, .. } => headers.get("x-oss-ec").map(|s| s.as_str()),
+            _ => self.error_response().map(|r| r.ec.as_str()),
+        }
+        .filter(|s| !s.is_empty())
+    }
+
+    /// The typed error code of failed API calls which respond error body
+    pub fn api_error_code(&self) -> Option<ApiErrorCode> {
+        self.error_response().map(|r| r.error_code())
+    }
+
+    /// The offset in seconds of the server clock to the local clock if the error is `RequestTimeTooSkewed`.
+    /// The server time is read from `ServerTime` in the error response, or the `Date` response header.
+    pub(crate) fn server_clock_offset(&self) -> Option<i64> {
+        let r = self.error_response()?;
+
+        if r.error_code() != ApiErrorCode::RequestTimeTooSkewed {
+            return None;
+        }
+
+        let server_time = parse_server_time(&r.server_time).or_else(|| r.headers.get("date").and_then(|s| parse_server_time(s)))?;
+
+        Some(server_time.timestamp() - chrono::Utc::now().timestamp())
+    }
+
+    /// The bucket, object, multipart upload or version does not exist
+    pub fn is_not_found(&self) -> bool {
+        matches!(
+            self.api_error_code(),
+            Some(ApiErrorCode::NoSuchBucket | ApiErrorCode::NoSuchKey | ApiErrorCode::NoSuchUpload | ApiErrorCode::NoSuchVersion)
+        ) || self.status() == Some(reqwest::StatusCode::NOT_FOUND)
+    }
+
+    /// The request is rejected because of the QPS or bandwidth limits
+    pub fn is_throttled(&self) -> bool {
+        matches!(
+            self.api_error_code(),
+            Some(ApiErrorCode::QpsLimitExceeded | ApiErrorCode::DownloadTrafficRateLimitExceeded | ApiErrorCode::UploadTrafficRateLimitExceeded)
+        ) || self.status() == Some(reqwest::StatusCode::TOO_MANY_REQUESTS)
+    }
+
+    /// The request could succeed if it is sent again, e.g. throttled, timeout, network errors and server side errors
+    pub fn is_retryable(&self) -> bool {
+        if self.is_throttled() {
+            return true;
+        }
+
+        if let Error::ReqwestError(e) = self {
+            if e.is_timeout() || e.is_connect() {
+                return true;
+            }
+        }
+
+        if matches!(
+            self.api_error_code(),
+            Some(ApiErrorCode::RequestTimeout | ApiErrorCode::RequestTimeTooSkewed | ApiErrorCode::InternalError | ApiErrorCode::ServiceUnavailable)
+        ) {
+            return true;
+        }
+
+        matches!(self.status(), Some(s) if s.is_server_error() && s != reqwest::StatusCode::NOT_IMPLEMENTED)
+    }
+}
+
+#[cfg(test)]
+mod test_error {
+    use std::collections::HashMap;
+
+    use super::{ApiErrorCode, Error, ErrorResponse};
+
+    #[test]
+    fn test_precondition_failed() {
+        let headers = HashMap::from([("x-oss-request-id".to_string(), "534B371674E88A4D8906****".to_string())]);
+
+        let err = Error::from_api_response(reqwest::StatusCode::PRECONDITION_FAILED, &headers, None);
+        match err {
+            Error::PreconditionFailed(r) => {
+                assert_eq!("PreconditionFailed", r.code);
+                assert_eq!("534B371674E88A4D8906****", r.request_id);
+            }
+            _ => panic!("unexpected error"),
+        }
+
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<Error>
+  <Code>PreconditionFailed</Code>
+  <Message>At least one of the pre-conditions you specified did not hold</Message>
+  <RequestId>534B371674E88A4D8906****</RequestId>
+  <HostId>oss-example.oss-cn-hangzhou.aliyuncs.com</HostId>
+  <Condition>If-Match</Condition>
+</Error>"#;
+
+        let err = Error::from_api_response(reqwest::StatusCode::PRECONDITION_FAILED, &headers, Some(ErrorResponse::from_xml(xml).unwrap()));
+        assert!(matches!(err, Error::PreconditionFailed(_)));
+
+        let err = Error::from_api_response(reqwest::StatusCode::NOT_FOUND, &headers, Some(ErrorResponse::default()));
+        assert!(matches!(err, Error::ApiError(_)));
+
+        let err = Error::from_api_response(reqwest::StatusCode::NOT_FOUND, &headers, None);
+        assert!(matches!(err, Error::StatusError { .. }));
+    }
+
+    #[test]
+    fn test_error_classification() {
+        let headers = HashMap::from([("x-oss-request-id".to_string(), "534B371674E88A4D8906****".to_string())]);
+
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<Error>
+  <Code>NoSuchKey</Code>
+  <Message>The specified key does not exist.</Message>
+  <RequestId>534B371674E88A4D8906****</RequestId>
+  <HostId>oss-example.oss-cn-hangzhou.aliyuncs.com</HostId>
+</Error>"#;
+
+        let err = Error::from_api_response(reqwest::StatusCode::NOT_FOUND, &headers, Some(ErrorResponse::from_xml(xml).unwrap()));
+        assert_eq!(Some(ApiErrorCode::NoSuchKey), err.api_error_code());
+        assert_eq!(Some(reqwest::StatusCode::NOT_FOUND), err.status());
+        assert_eq!(Some(&headers), err.headers());
+        assert!(err.is_not_found());
+        assert!(!err.is_retryable());
+
+        let err = Error::from_api_response(reqwest::StatusCode::NOT_FOUND, &headers, None);
+        assert!(err.is_not_found());
+        assert_eq!(None, err.api_error_code());
+
+        let err = Error::from_api_response(
+            reqwest::StatusCode::SERVICE_UNAVAILABLE,
+            &headers,
+            Some(ErrorResponse {
+                code: "QpsLimitExceeded".to_string(),
+                ..Default::default()
+            }),
+        );
+        assert!(err.is_throttled());
+        assert!(err.is_retryable());
+        assert!(!err.is_not_found());
+
+        let err = Error::from_api_response(reqwest::StatusCode::INTERNAL_SERVER_ERROR, &headers, None);
+        assert!(err.is_retryable());
+        assert!(!err.is_throttled());
+
+        let err = Error::from_api_response(reqwest::StatusCode::FORBIDDEN, &headers, None);
+        assert!(!err.is_retryable());
+
+        let err = Error::InvalidBucketName("A".to_string());
+        assert!(!err.is_retryable());
+        assert_eq!(None, err.status());
+    }
+
+    #[test]
+    fn test_api_error_code() {
+        assert_eq!(ApiErrorCode::SignatureDoesNotMatch, ApiErrorCode::from("SignatureDoesNotMatch"));
+        assert_eq!("RequestTimeTooSkewed", ApiErrorCode::RequestTimeTooSkewed.as_str());
+
+        let code = ApiErrorCode::from("SomethingNew");
+        assert_eq!(ApiErrorCode::Unknown("SomethingNew".to_string()), code);
+        assert_eq!("SomethingNew", code.to_string());
+    }
+
+    #[test]
+    fn test_error_header() {
+        use base64::{prelude::BASE64_STANDARD, Engine};
+
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<Error>
+  <Code>NoSuchKey</Code>
+  <Message>The specified key does not exist.</Message>
+  <RequestId>5C3D9175B6FC201293AD****</RequestId>
+  <HostId>oss-example.oss-cn-hangzhou.aliyuncs.com</HostId>
+  <Key>example.txt</Key>
+  <EC>0026-00000001</EC>
+</Error>"#;
+
+        let headers = HashMap::from([
+            ("x-oss-request-id".to_string(), "5C3D9175B6FC201293AD****".to_string()),
+            ("x-oss-ec".to_string(), "0026-00000001".to_string()),
+            ("x-oss-err".to_string(), BASE64_STANDARD.encode(xml)),
+        ]);
+
+        let err = Error::from_api_response(reqwest::StatusCode::NOT_FOUND, &headers, None);
+        match &err {
+            Error::ApiError(r) => {
+                assert_eq!("NoSuchKey", r.code);
+                assert_eq!("The specified key does not exist.", r.message);
+                assert_eq!("0026-00000001", r.ec);
+                assert_eq!(404, r.status);
+            }
+            _ => panic!("unexpected error"),
+        }
+        assert_eq!(Some("5C3D9175B6FC201293AD****"), err.request_id());
+        assert!(err.is_not_found());
+
+        // invalid `x-oss-err` falls back to status error, request id and ec are still available
+        let headers = HashMap::from([
+            ("x-oss-request-id".to_string(), "5C3D9175B6FC201293AD****".to_string()),
+            ("x-oss-ec".to_string(), "0003-00000001".to_string()),
+            ("x-oss-err".to_string(), "not-base64!".to_string()),
+        ]);
+
+        let err = Error::from_api_response(reqwest::StatusCode::FORBIDDEN, &headers, None);
+        assert!(matches!(err, Error::StatusError { .. }));
+        assert_eq!(Some("5C3D9175B6FC201293AD****"), err.request_id());
+        assert_eq!(Some("0003-00000001"), err.ec());
+
+        // request id and ec missing in error body are taken from headers
+        let err = Error::from_api_response(
+            reqwest::StatusCode::FORBIDDEN,
+            &headers,
+            Some(ErrorResponse {
+                code: "AccessDenied".to_string(),
+                ..Default::default()
+            }),
+        );
+        assert_eq!(Some("5C3D9175B6FC201293AD****"), err.request_id());
+        assert_eq!(Some("0003-00000001"), err.ec());
+    }
+
+    #[test]
+    fn test_server_clock_offset() {
+        let server_time = chrono::Utc::now() - chrono::Duration::seconds(1800);
+
+        let err = Error::from_api_response(
+            reqwest::StatusCode::FORBIDDEN,
+            &HashMap::new(),
+            Some(ErrorResponse {
+                code: "RequestTimeTooSkewed".to_string(),
+                server_time: server_time.format("%Y-%m-%dT%H:%M:%S.000Z").to_string(),
+                ..Default::default()
+            }),
+        );
+        let offset = err.server_clock_offset().unwrap();
+        assert!((-1805..=-1795).contains(&offset));
+
+        // fallback to `Date` header
+        let headers = HashMap::from([("date".to_string(), server_time.format("%a, %d %b %Y %H:%M:%S GMT").to_string())]);
+        let err = Error::from_api_response(
+            reqwest::StatusCode::FORBIDDEN,
+            &headers,
+            Some(ErrorResponse {
+                code: "RequestTimeTooSkewed".to_string(),
+                ..Default::default()
+            }),
+        );
+        let offset = err.server_clock_offset().unwrap();
+        assert!((-1805..=-1795).contains(&offset));
+
+        let err = Error::from_api_response(
+            reqwest::StatusCode::FORBIDDEN,
+            &headers,
+            Some(ErrorResponse {
+                code: "AccessDenied".to_string(),
+                ..Default::default()
+            }),
+        );
+        assert!(err.server_clock_offset().is_none());
+    }
 }
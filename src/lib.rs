@@ -3,6 +3,8 @@ pub mod acl;
 pub mod acl_common;
 pub mod bucket;
 pub mod bucket_common;
+pub mod callback;
+pub mod callback_common;
 pub mod cname;
 pub mod cname_common;
 pub mod common;
@@ -11,9 +13,21 @@ pub mod multipart;
 pub mod multipart_common;
 pub mod object;
 pub mod object_common;
+pub mod object_reader;
+pub mod object_reader_common;
+pub mod object_writer;
+pub mod object_writer_common;
+pub mod post_object;
+pub mod post_object_common;
 pub mod presign;
 pub mod presign_common;
+pub mod process;
+pub mod process_common;
+pub mod public_access_block;
+pub mod public_access_block_common;
 pub mod request;
+pub mod select_object;
+pub mod select_object_common;
 pub mod symlink;
 pub mod symlink_common;
 pub mod tagging;
@@ -24,13 +38,26 @@ pub mod blocking;
 
 mod util;
 
-use std::{collections::HashMap, pin::Pin, str::FromStr};
+#[cfg(test)]
+mod mock_server;
+
+use std::{
+    collections::HashMap,
+    pin::Pin,
+    str::FromStr,
+    sync::{
+        atomic::{AtomicI64, Ordering},
+        Arc,
+    },
+};
 
 use async_trait::async_trait;
+use base64::{prelude::BASE64_STANDARD, Engine};
 use bytes::Bytes;
+use common::{AccelerateEndpoint, SignatureVersion};
 use error::{Error, ErrorResponse};
 use futures::{Stream, StreamExt};
-use request::RequestBody;
+use request::{OssRequest, RequestBody, StreamBody};
 use reqwest::{
     header::{HeaderMap, HeaderName, HeaderValue},
     Body,
@@ -44,7 +71,7 @@ pub use tokio;
 use tokio::io::{AsyncReadExt, AsyncSeekExt};
 use tokio_util::codec::{BytesCodec, FramedRead};
 use url::Url;
-use util::{get_region_from_endpoint, hmac_sha256};
+use util::{get_region_from_endpoint, hmac_sha1, hmac_sha256};
 
 pub type Result<T> = std::result::Result<T, crate::error::Error>;
 
@@ -57,6 +84,10 @@ pub struct ClientBuilder {
     region: Option<String>,
     scheme: Option<String>,
     sts_token: Option<String>,
+    accelerate_endpoint: Option<AccelerateEndpoint>,
+    cname: bool,
+    path_style: bool,
+    signature_version: SignatureVersion,
     client: Option<reqwest::Client>,
 }
 
@@ -108,6 +139,37 @@ impl ClientBuilder {
         self
     }
 
+    /// Send object related requests (put, get, multipart uploads and presigned urls, etc.) to the transfer acceleration endpoint.
+    /// Requests on bucket configurations are still sent to the `endpoint`.
+    /// The signature is still calculated with the region of the bucket.
+    pub fn accelerate_endpoint(mut self, accelerate_endpoint: AccelerateEndpoint) -> Self {
+        self.accelerate_endpoint = Some(accelerate_endpoint);
+        self
+    }
+
+    /// Set to `true` if the `endpoint` is a custom domain (CNAME) which is bound to a bucket.
+    /// Requests and presigned urls are sent to the custom domain directly without the bucket name prefix.
+    /// The `region` must be set explicitly because it can not be guessed from a custom domain.
+    pub fn cname(mut self, cname: bool) -> Self {
+        self.cname = cname;
+        self
+    }
+
+    /// Set to `true` to use path-style addressing: `scheme://endpoint/bucket/key`.
+    /// This is useful for OSS-compatible servers and private deployments, e.g. `http://127.0.0.1:9000`.
+    /// In such case, the `region` should be set explicitly because it can not be guessed from the endpoint.
+    pub fn path_style(mut self, path_style: bool) -> Self {
+        self.path_style = path_style;
+        self
+    }
+
+    /// Set the signature version. `SignatureVersion::V4` is used by default.
+    /// `SignatureVersion::V1` is only for legacy deployments and OSS-compatible servers which do not support V4.
+    pub fn signature_version(mut self, signature_version: SignatureVersion) -> Self {
+        self.signature_version = signature_version;
+        self
+    }
+
     /// You can build your own `reqwest::Client` and set to the OSS client.
     /// I do not expose each option of `reqwest::Client` because there are many options to build a `reqwest::Client`.
     pub fn client(mut self, client: reqwest::Client) -> Self {
@@ -128,6 +190,10 @@ impl ClientBuilder {
             region,
             scheme,
             sts_token,
+            accelerate_endpoint,
+            cname,
+            path_style,
+            signature_version,
             client,
         } = self;
 
@@ -162,7 +228,12 @@ impl ClientBuilder {
             region,
             scheme,
             sts_token,
+            accelerate_endpoint,
+            cname,
+            path_style,
+            signature_version,
             http_client: if let Some(c) = client { c } else { reqwest::Client::new() },
+            clock_offset: Arc::new(AtomicI64::new(0)),
         })
     }
 }
@@ -175,7 +246,15 @@ pub struct Client {
     endpoint: String,
     scheme: String,
     sts_token: Option<String>,
+    accelerate_endpoint: Option<AccelerateEndpoint>,
+    cname: bool,
+    path_style: bool,
+    signature_version: SignatureVersion,
     http_client: reqwest::Client,
+
+    /// Offset in seconds of the server clock to the local clock, learned from `RequestTimeTooSkewed` errors.
+    /// It is shared by the clients cloned by `clone_to`.
+    clock_offset: Arc<AtomicI64>,
 }
 
 impl Client {
@@ -246,8 +325,13 @@ impl Client {
             region: region.as_ref().to_string(),
             endpoint: lc_endpoint,
             sts_token: None,
+            accelerate_endpoint: None,
+            cname: false,
+            path_style: false,
+            signature_version: SignatureVersion::V4,
             scheme,
             http_client: reqwest::Client::new(),
+            clock_offset: Arc::new(AtomicI64::new(0)),
         }
     }
 
@@ -262,27 +346,24 @@ impl Client {
         hex::encode(hmac_sha256(&signing_key, string_to_sign.as_bytes()))
     }
 
-    /// Some of the strings are used multiple times,
-    /// So I put them in this method to prevent re-generating
-    /// and better debugging output.
-    /// And add some default headers to the request builder.
-    async fn do_request<T>(&self, mut oss_request: crate::request::OssRequest) -> Result<(HashMap<String, String>, T)>
-    where
-        T: FromResponse,
-    {
-        // check if sign `host` header
-        if oss_request.additional_headers.contains("host") {
-            let host = if oss_request.bucket_name.is_empty() {
-                self.endpoint.clone()
-            } else {
-                format!("{}.{}", oss_request.bucket_name, self.endpoint)
-            };
+    /// Calculate the signature of signature version 1: base64 encoded HMAC-SHA1 of the string to sign
+    fn calculate_signature_v1(&self, string_to_sign: &str) -> String {
+        BASE64_STANDARD.encode(hmac_sha1(self.access_key_secret.as_bytes(), string_to_sign.as_bytes()))
+    }
 
-            oss_request.headers_mut().insert("host".to_string(), host);
-        }
+    /// Build the `authorization` header value with the signature version of the client.
+    /// `http_date` is the `Date` header which is used in the string to sign of signature version 1.
+    fn build_authorization(&self, oss_request: &mut OssRequest, http_date: &str) -> String {
+        if self.signature_version == SignatureVersion::V1 {
+            // these headers are for signature version 4 only
+            oss_request.headers_mut().remove("x-oss-date");
+            oss_request.headers_mut().remove("x-oss-content-sha256");
 
-        if let Some(s) = &self.sts_token {
-            oss_request.headers_mut().insert("x-oss-security-token".to_string(), s.to_string());
+            let string_to_sign = oss_request.build_string_to_sign_v1(http_date);
+
+            log::debug!("string to sign: \n--------\n{}\n--------", string_to_sign);
+
+            return format!("OSS {}:{}", self.access_key_id, self.calculate_signature_v1(&string_to_sign));
         }
 
         let date_time_string = oss_request.headers.get("x-oss-date").unwrap();
@@ -298,7 +379,7 @@ impl Client {
 
         log::debug!("signature: {}", sig);
 
-        let auth_string = format!(
+        format!(
             "OSS4-HMAC-SHA256 Credential={}/{}/{}/oss/aliyun_v4_request,{}Signature={}",
             self.access_key_id,
             date_string,
@@ -309,40 +390,128 @@ impl Client {
                 format!("{},", additional_headers)
             },
             sig
-        );
-
-        let mut header_map = HeaderMap::new();
+        )
+    }
 
-        for (k, v) in oss_request.headers.iter() {
-            header_map.insert(HeaderName::from_str(k)?, HeaderValue::from_str(v)?);
+    /// The host (without scheme) which the request is sent to.
+    /// Requests on objects are sent to the transfer acceleration endpoint if it is set.
+    /// In cname mode, all requests are sent to the custom domain without bucket name prefix.
+    /// In path-style mode, the bucket name is put in the path instead of the host.
+    fn request_host(&self, oss_request: &OssRequest) -> String {
+        if self.cname {
+            return self.endpoint.clone();
         }
 
-        let http_date = util::get_http_date();
+        let endpoint = match &self.accelerate_endpoint {
+            Some(e) if !oss_request.object_key.is_empty() => e.as_str(),
+            _ => self.endpoint.as_str(),
+        };
 
-        header_map.insert(HeaderName::from_static("authorization"), HeaderValue::from_str(&auth_string)?);
-        header_map.insert(HeaderName::from_static("date"), HeaderValue::from_str(&http_date)?);
+        if oss_request.bucket_name.is_empty() || self.path_style {
+            endpoint.to_string()
+        } else {
+            format!("{}.{}", oss_request.bucket_name, endpoint)
+        }
+    }
 
+    /// Build the full url of the request, including the query string.
+    fn request_url(&self, oss_request: &OssRequest) -> String {
         let uri = oss_request.build_request_uri();
         let query_string = oss_request.build_canonical_query_string();
 
-        let domain_name = if oss_request.bucket_name.is_empty() {
-            format!("{}://{}{}", self.scheme, self.endpoint, uri)
+        let uri = if self.path_style && !self.cname && !oss_request.bucket_name.is_empty() {
+            format!("/{}{}", oss_request.bucket_name, uri)
         } else {
-            format!("{}://{}.{}{}", self.scheme, oss_request.bucket_name, self.endpoint, uri)
+            uri
         };
 
-        let full_url = if query_string.is_empty() {
-            domain_name
+        let url = format!("{}://{}{}", self.scheme, self.request_host(oss_request), uri);
+
+        if query_string.is_empty() {
+            url
         } else {
-            format!("{}?{}", domain_name, query_string)
-        };
+            format!("{}?{}", url, query_string)
+        }
+    }
+
+    /// Send the request. If it fails with `RequestTimeTooSkewed` because of the local clock,
+    /// the clock offset is learned from the error and the request is sent once again if its body could be sent again.
+    async fn do_request<T>(&self, oss_request: crate::request::OssRequest) -> Result<(HashMap<String, String>, T)>
+    where
+        T: FromResponse,
+    {
+        let retry_request = oss_request.try_clone();
+
+        match self.send_request(oss_request).await {
+            Err(e) => match e.server_clock_offset() {
+                Some(offset) => {
+                    log::warn!("request time too skewed, clock offset of server to local is {} seconds", offset);
+                    self.clock_offset.store(offset, Ordering::Relaxed);
+
+                    match retry_request {
+                        Some(r) => self.send_request(r).await,
+                        None => Err(e),
+                    }
+                }
+                None => Err(e),
+            },
+            ret => ret,
+        }
+    }
+
+    /// Some of the strings are used multiple times,
+    /// So I put them in this method to prevent re-generating
+    /// and better debugging output.
+    /// And add some default headers to the request builder.
+    async fn send_request<T>(&self, mut oss_request: crate::request::OssRequest) -> Result<(HashMap<String, String>, T)>
+    where
+        T: FromResponse,
+    {
+        oss_request.shift_date(self.clock_offset());
+
+        // check if sign `host` header
+        if oss_request.additional_headers.contains("host") {
+            let host = self.request_host(&oss_request);
+
+            oss_request.headers_mut().insert("host".to_string(), host);
+        }
+
+        if let Some(s) = &self.sts_token {
+            oss_request.headers_mut().insert("x-oss-security-token".to_string(), s.to_string());
+        }
+
+        let http_date = util::get_http_date_with_offset(self.clock_offset());
+
+        let auth_string = self.build_authorization(&mut oss_request, &http_date);
+
+        let mut header_map = HeaderMap::new();
+
+        for (k, v) in oss_request.headers.iter() {
+            header_map.insert(HeaderName::from_str(k)?, HeaderValue::from_str(v)?);
+        }
+
+        header_map.insert(HeaderName::from_static("authorization"), HeaderValue::from_str(&auth_string)?);
+        header_map.insert(HeaderName::from_static("date"), HeaderValue::from_str(&http_date)?);
+
+        let full_url = self.request_url(&oss_request);
 
         log::debug!("full url: {}", full_url);
 
         let mut req_builder = self.http_client.request(oss_request.method.into(), Url::parse(&full_url)?).headers(header_map);
 
+        // streaming body is sent instead of `body`
+        let body = match oss_request.stream_body {
+            Some(StreamBody::Stream(body)) => {
+                req_builder = req_builder.body(body);
+                RequestBody::Empty
+            }
+            #[cfg(feature = "blocking")]
+            Some(StreamBody::Reader(_)) => return Err(Error::InvalidArgument("blocking reader body is not supported by async client".to_string())),
+            None => oss_request.body,
+        };
+
         // 根据 body 类型设置请求体
-        req_builder = match oss_request.body {
+        req_builder = match body {
             RequestBody::Empty => req_builder,
             RequestBody::Text(text) => req_builder.body(text),
             RequestBody::Bytes(bytes) => req_builder.body(bytes),
@@ -362,6 +531,15 @@ impl Client {
 
         let req = req_builder.build()?;
 
+        self.execute_request(req, &full_url).await
+    }
+
+    /// Send the built request and parse the response.
+    /// Non-2xx responses are converted to errors with the error response body if there is one.
+    async fn execute_request<T>(&self, req: reqwest::Request, full_url: &str) -> Result<(HashMap<String, String>, T)>
+    where
+        T: FromResponse,
+    {
         for (k, v) in req.headers() {
             log::debug!(">> headers: {}: {}", k, v.to_str().unwrap_or_default());
         }
@@ -384,15 +562,15 @@ impl Client {
                     log::error!("{}", s);
                     if s.is_empty() {
                         log::error!("call api failed with status: \"{}\". full url: {}", status, full_url);
-                        Err(Error::StatusError(status))
+                        Err(Error::from_api_response(status, &response_headers, None))
                     } else {
                         let error_response = ErrorResponse::from_xml(&s)?;
-                        Err(Error::ApiError(Box::new(error_response)))
+                        Err(Error::from_api_response(status, &response_headers, Some(error_response)))
                     }
                 }
                 Err(_) => {
                     log::error!("call api failed with status: \"{}\". full url: {}", status, full_url);
-                    Err(Error::StatusError(status))
+                    Err(Error::from_api_response(status, &response_headers, None))
                 }
             }
         } else {
@@ -420,9 +598,20 @@ impl Client {
             endpoint: endpoint.to_string(),
             scheme: self.scheme.clone(),
             sts_token: self.sts_token.clone(),
+            accelerate_endpoint: self.accelerate_endpoint,
+            cname: self.cname,
+            path_style: self.path_style,
+            signature_version: self.signature_version,
             http_client: self.http_client.clone(),
+            clock_offset: self.clock_offset.clone(),
         }
     }
+
+    /// The offset in seconds of the server clock to the local clock.
+    /// It is learned from `RequestTimeTooSkewed` errors and applied to the signing time of later requests and presigned urls.
+    pub fn clock_offset(&self) -> i64 {
+        self.clock_offset.load(Ordering::Relaxed)
+    }
 }
 
 #[async_trait]
@@ -464,3 +653,160 @@ fn test_client_build() {
     assert_eq!(config.scheme, "https");
     assert_eq!(config.endpoint, "oss-cn-hangzhou.aliyuncs.com");
 }
+
+#[test]
+fn test_client_accelerate_endpoint() {
+    let client = ClientBuilder::new("access_key_id", "access_key_secret", "oss-cn-hangzhou.aliyuncs.com")
+        .accelerate_endpoint(AccelerateEndpoint::Global)
+        .build()
+        .unwrap();
+
+    assert_eq!(client.region, "cn-hangzhou");
+
+    let request = OssRequest::new().bucket("test-bucket").object("a/b.txt");
+    assert_eq!(client.request_url(&request), "https://test-bucket.oss-accelerate.aliyuncs.com/a/b.txt");
+
+    let request = OssRequest::new().bucket("test-bucket").add_query("transferAcceleration", "");
+    assert_eq!(
+        client.request_url(&request),
+        "https://test-bucket.oss-cn-hangzhou.aliyuncs.com/?transferAcceleration"
+    );
+}
+
+#[test]
+fn test_client_cname() {
+    let client = ClientBuilder::new("access_key_id", "access_key_secret", "https://assets.example.com")
+        .region("cn-hangzhou")
+        .cname(true)
+        .build()
+        .unwrap();
+
+    let request = OssRequest::new().bucket("test-bucket").object("a/b.txt");
+    assert_eq!(client.request_url(&request), "https://assets.example.com/a/b.txt");
+    assert_eq!(request.build_canonical_uri(), "/test-bucket/a/b.txt");
+}
+
+#[test]
+fn test_client_path_style() {
+    assert!(ClientBuilder::new("access_key_id", "access_key_secret", "http://localhost:9000")
+        .build()
+        .is_err());
+
+    let client = ClientBuilder::new("access_key_id", "access_key_secret", "http://127.0.0.1:9000")
+        .region("cn-hangzhou")
+        .path_style(true)
+        .build()
+        .unwrap();
+
+    assert_eq!(client.scheme, "http");
+    assert_eq!(client.region, "cn-hangzhou");
+
+    let request = OssRequest::new().bucket("test-bucket").object("a/b.txt");
+    assert_eq!(client.request_url(&request), "http://127.0.0.1:9000/test-bucket/a/b.txt");
+
+    let request = OssRequest::new().bucket("test-bucket").add_query("acl", "");
+    assert_eq!(client.request_url(&request), "http://127.0.0.1:9000/test-bucket/?acl");
+
+    let request = OssRequest::new();
+    assert_eq!(client.request_url(&request), "http://127.0.0.1:9000/");
+}
+
+#[test]
+fn test_signature_v1() {
+    // example from the official document of signature version 1
+    let client = ClientBuilder::new(
+        "44CF9590006BF252F707",
+        "OtxrzxIsfpFjA7SwPzILwy8Bw21TLhquhboDYROV",
+        "oss-cn-hangzhou.aliyuncs.com",
+    )
+    .signature_version(SignatureVersion::V1)
+    .build()
+    .unwrap();
+
+    let mut request = OssRequest::new()
+        .method(request::RequestMethod::Put)
+        .bucket("oss-example")
+        .object("nelson")
+        .add_header("Content-MD5", "ODBGOERFMDMzQTczRUY3NUE3NzA5QzdFNUYzMDQxNEM=")
+        .add_header("Content-Type", "text/html")
+        .add_header("X-OSS-Meta-Author", "foo@bar.com")
+        .add_header("X-OSS-Magic", "abracadabra");
+    request.headers_mut().remove("x-sdk-client");
+
+    let date = "Thu, 17 Nov 2005 18:49:58 GMT";
+    let auth = client.build_authorization(&mut request, date);
+
+    assert_eq!(
+        "PUT\nODBGOERFMDMzQTczRUY3NUE3NzA5QzdFNUYzMDQxNEM=\ntext/html\nThu, 17 Nov 2005 18:49:58 GMT\nx-oss-magic:abracadabra\nx-oss-meta-author:foo@bar.com\n/oss-example/nelson",
+        request.build_string_to_sign_v1(date)
+    );
+    assert_eq!("OSS 44CF9590006BF252F707:26NBxoKdsyly4EDv6inkoDft/yA=", auth);
+    assert!(!request.headers.contains_key("x-oss-date"));
+
+    let request = OssRequest::new()
+        .bucket("oss-example")
+        .add_query("acl", "")
+        .add_query("uploadId", "0004B9894A22E5B1888A1E29F823****")
+        .add_query("prefix", "a/");
+    assert_eq!(
+        "/oss-example/?acl&uploadId=0004B9894A22E5B1888A1E29F823****",
+        request.build_canonical_resource_v1()
+    );
+}
+
+#[tokio::test]
+async fn test_clock_skew_retry() {
+    use mock_server::MockResponse;
+    use object::ObjectOperations;
+
+    // the server clock is one hour ahead of the local clock
+    let server_time = chrono::Utc::now() + chrono::Duration::seconds(3600);
+
+    let mut count = 0;
+    let (port, server) = mock_server::start(2, move |_| {
+        count += 1;
+
+        if count == 1 {
+            let body = format!(
+                "<?xml version=\"1.0\" encoding=\"UTF-8\"?><Error><Code>RequestTimeTooSkewed</Code><Message>The difference between the request time and the current time is too large.</Message><RequestId>1234</RequestId><ServerTime>{}</ServerTime></Error>",
+                server_time.format("%Y-%m-%dT%H:%M:%S.000Z")
+            );
+            MockResponse::new(403).body(body)
+        } else {
+            MockResponse::new(204).header("x-oss-request-id", "5678")
+        }
+    });
+
+    let client = ClientBuilder::new("ak", "sk", format!("http://127.0.0.1:{}", port))
+        .region("cn-hangzhou")
+        .path_style(true)
+        .build()
+        .unwrap();
+
+    client.delete_object("example-bucket", "a.txt", None).await.unwrap();
+
+    let offset = client.clock_offset();
+    assert!((3595..=3600).contains(&offset), "unexpected clock offset: {}", offset);
+
+    let requests = tokio::task::spawn_blocking(move || server.join().unwrap()).await.unwrap();
+    let oss_date = |r: &mock_server::MockRequest| {
+        chrono::NaiveDateTime::parse_from_str(&r.headers["x-oss-date"], "%Y%m%dT%H%M%SZ")
+            .unwrap()
+            .and_utc()
+            .timestamp()
+    };
+
+    assert!(oss_date(&requests[1]) - oss_date(&requests[0]) >= 3595);
+    assert!((oss_date(&requests[1]) - server_time.timestamp()).abs() <= 5);
+
+    // later requests and presigned urls use the corrected time
+    let signed = client.presign_raw_request(OssRequest::new().bucket("example-bucket").object("a.txt"));
+    let oss_date = chrono::NaiveDateTime::parse_from_str(&signed.headers["x-oss-date"], "%Y%m%dT%H%M%SZ")
+        .unwrap()
+        .and_utc()
+        .timestamp();
+    assert!((oss_date - server_time.timestamp()).abs() <= 5);
+
+    let cloned = client.clone_to("cn-hangzhou", "oss-cn-hangzhou.aliyuncs.com");
+    assert_eq!(offset, cloned.clock_offset());
+}
@@ -0,0 +1,168 @@
+//! A minimal HTTP server for tests which need to check the requests sent by the client.
+//!
+//! The server runs on its own thread, so it works with both async and blocking clients.
+//! Every response is sent with `Connection: close`, so each request comes on a new connection.
+
+use std::{
+    collections::HashMap,
+    io::{Read, Write},
+    net::TcpListener,
+    thread::JoinHandle,
+    time::Duration,
+};
+
+/// A request received by the mock server
+#[derive(Debug, Clone, Default)]
+pub(crate) struct MockRequest {
+    pub method: String,
+
+    /// The request target, including query string
+    pub path: String,
+
+    /// Header names are in lower case
+    pub headers: HashMap<String, String>,
+    pub body: Vec<u8>,
+}
+
+impl MockRequest {
+    /// Check if the query string contains `name`
+    pub fn has_query(&self, name: &str) -> bool {
+        self.path
+            .split_once('?')
+            .map(|(_, q)| q.split('&').any(|kv| kv.split('=').next() == Some(name)))
+            .unwrap_or(false)
+    }
+}
+
+/// The response sent by the mock server
+#[derive(Debug, Clone)]
+pub(crate) struct MockResponse {
+    status: u16,
+    headers: Vec<(String, String)>,
+    body: Vec<u8>,
+    delay: Option<Duration>,
+}
+
+impl MockResponse {
+    pub fn new(status: u16) -> Self {
+        Self {
+            status,
+            headers: vec![],
+            body: vec![],
+            delay: None,
+        }
+    }
+
+    /// `Content-Length` is set to the length of body unless it is set here, e.g. for `HEAD` requests.
+    pub fn header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+
+    pub fn body(mut self, body: impl Into<Vec<u8>>) -> Self {
+        self.body = body.into();
+        self
+    }
+
+    /// Wait before sending the response
+    pub fn delay(mut self, delay: Duration) -> Self {
+        self.delay = Some(delay);
+        self
+    }
+}
+
+/// Start a server which handles `count` requests one by one and then returns all requests received.
+/// Returns the port of the server and the handle of the server thread.
+pub(crate) fn start<F>(count: usize, mut handler: F) -> (u16, JoinHandle<Vec<MockRequest>>)
+where
+    F: FnMut(&MockRequest) -> MockResponse + Send + 'static,
+{
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let port = listener.local_addr().unwrap().port();
+
+    let handle = std::thread::spawn(move || {
+        let mut requests = vec![];
+
+        for _ in 0..count {
+            let (mut stream, _) = listener.accept().unwrap();
+            let request = match read_request(&mut stream) {
+                Some(r) => r,
+                // the client closed the connection before sending the whole request
+                None => continue,
+            };
+
+            let response = handler(&request);
+            requests.push(request);
+
+            if let Some(d) = response.delay {
+                std::thread::sleep(d);
+            }
+
+            let mut data = format!("HTTP/1.1 {} Mock\r\nConnection: close\r\n", response.status);
+            for (k, v) in &response.headers {
+                data.push_str(&format!("{}: {}\r\n", k, v));
+            }
+            if !response.headers.iter().any(|(k, _)| k.eq_ignore_ascii_case("content-length")) {
+                data.push_str(&format!("Content-Length: {}\r\n", response.body.len()));
+            }
+            data.push_str("\r\n");
+
+            let mut data = data.into_bytes();
+            data.extend_from_slice(&response.body);
+
+            // the client may have gone away, e.g. the request was cancelled
+            let _ = stream.write_all(&data);
+        }
+
+        requests
+    });
+
+    (port, handle)
+}
+
+fn read_request(stream: &mut impl Read) -> Option<MockRequest> {
+    let mut data = vec![];
+    let mut buf = [0u8; 64 * 1024];
+
+    let header_end = loop {
+        if let Some(idx) = data.windows(4).position(|w| w == b"\r\n\r\n") {
+            break idx;
+        }
+
+        let n = stream.read(&mut buf).ok()?;
+        if n == 0 {
+            return None;
+        }
+        data.extend_from_slice(&buf[..n]);
+    };
+
+    let head = String::from_utf8_lossy(&data[..header_end]).to_string();
+    let mut lines = head.split("\r\n");
+    let mut request_line = lines.next()?.split(' ');
+
+    let mut request = MockRequest {
+        method: request_line.next()?.to_string(),
+        path: request_line.next()?.to_string(),
+        ..Default::default()
+    };
+
+    for line in lines {
+        if let Some((k, v)) = line.split_once(':') {
+            request.headers.insert(k.trim().to_lowercase(), v.trim().to_string());
+        }
+    }
+
+    let len = request.headers.get("content-length").map(|s| s.parse::<usize>().unwrap()).unwrap_or(0);
+    let mut body = data.split_off(header_end + 4);
+
+    while body.len() < len {
+        let n = stream.read(&mut buf).ok()?;
+        if n == 0 {
+            return None;
+        }
+        body.extend_from_slice(&buf[..n]);
+    }
+
+    request.body = body;
+    Some(request)
+}
@@ -4,19 +4,23 @@ use std::{ops::Range, path::Path};
 
 use async_trait::async_trait;
 use base64::{prelude::BASE64_STANDARD, Engine};
+use bytes::Bytes;
+use futures::{Stream, TryStreamExt};
+use tokio::io::AsyncRead;
+use tokio_util::codec::{BytesCodec, FramedRead};
 
 use crate::{
     error::Error,
     multipart_common::{
-        build_complete_multipart_uploads_request, build_initiate_multipart_uploads_request, build_list_multipart_uploads_request, build_list_parts_request,
-        build_upload_part_copy_request, build_upload_part_request, CompleteMultipartUploadApiResponse, CompleteMultipartUploadOptions,
+        build_abort_multipart_uploads_request, build_complete_multipart_uploads_request, build_initiate_multipart_uploads_request,
+        build_list_multipart_uploads_request, build_list_parts_request, build_upload_part_copy_request, build_upload_part_request,
+        build_upload_part_stream_request, AbortMultipartUploadOptions, CompleteMultipartUploadApiResponse, CompleteMultipartUploadOptions,
         CompleteMultipartUploadRequest, CompleteMultipartUploadResult, InitiateMultipartUploadOptions, InitiateMultipartUploadResult,
         ListMultipartUploadsOptions, ListMultipartUploadsResult, ListPartsOptions, ListPartsResult, UploadPartCopyOptions, UploadPartCopyRequest,
         UploadPartCopyResult, UploadPartRequest, UploadPartResult,
     },
-    request::{OssRequest, RequestMethod},
-    util::{validate_bucket_name, validate_object_key},
-    Client, RequestBody, Result,
+    util::validate_bucket_name,
+    Client, RequestBody, Result, StreamBody,
 };
 
 #[async_trait]
@@ -90,6 +94,41 @@ pub trait MultipartUploadsOperations {
         S2: AsRef<str> + Send,
         S3: AsRef<str> + Send;
 
+    /// Upload part from a stream of bytes.
+    /// If `content_length` is `None`, the data is uploaded with chunked transfer encoding.
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/uploadpart>
+    async fn upload_part_from_stream<S1, S2, St, E>(
+        &self,
+        bucket_name: S1,
+        object_key: S2,
+        stream: St,
+        content_length: Option<u64>,
+        params: UploadPartRequest,
+    ) -> Result<UploadPartResult>
+    where
+        S1: AsRef<str> + Send,
+        S2: AsRef<str> + Send,
+        St: Stream<Item = std::result::Result<Bytes, E>> + Send + 'static,
+        E: std::error::Error + Send + Sync + 'static;
+
+    /// Upload part from an async reader.
+    /// If `content_length` is `None`, the data is uploaded with chunked transfer encoding.
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/uploadpart>
+    async fn upload_part_from_reader<S1, S2, R>(
+        &self,
+        bucket_name: S1,
+        object_key: S2,
+        reader: R,
+        content_length: Option<u64>,
+        params: UploadPartRequest,
+    ) -> Result<UploadPartResult>
+    where
+        S1: AsRef<str> + Send,
+        S2: AsRef<str> + Send,
+        R: AsyncRead + Send + 'static;
+
     /// When you want to copy a file larger than 1GB, you must use `upload_part_copy`.
     /// First, initiate a multipart upload and get `uploadId`, then call this method to upload parts of the source object.
     /// Finally complete the multipart upload by invoking `complete_multipart_uploads`
@@ -123,7 +162,13 @@ pub trait MultipartUploadsOperations {
     /// About multipart uploads
     ///
     /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/abortmultipartupload>
-    async fn abort_multipart_uploads<S1, S2, S3>(&self, bucket_name: S1, object_key: S2, upload_id: S3) -> Result<()>
+    async fn abort_multipart_uploads<S1, S2, S3>(
+        &self,
+        bucket_name: S1,
+        object_key: S2,
+        upload_id: S3,
+        options: Option<AbortMultipartUploadOptions>,
+    ) -> Result<()>
     where
         S1: AsRef<str> + Send,
         S2: AsRef<str> + Send,
@@ -140,7 +185,7 @@ impl MultipartUploadsOperations for Client {
         S: AsRef<str> + Send,
     {
         if !validate_bucket_name(bucket_name.as_ref()) {
-            return Err(Error::Other(format!("invalid bucket name: {}", bucket_name.as_ref())));
+            return Err(Error::InvalidBucketName(bucket_name.as_ref().to_string()));
         }
         let request = build_list_multipart_uploads_request(bucket_name.as_ref(), &options)?;
         let (_, xml) = self.do_request::<String>(request).await?;
@@ -243,6 +288,58 @@ impl MultipartUploadsOperations for Client {
         self.upload_part_from_buffer(bucket_name, object_key, data, params).await
     }
 
+    /// Upload part from a stream of bytes.
+    /// If `content_length` is `None`, the data is uploaded with chunked transfer encoding.
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/uploadpart>
+    async fn upload_part_from_stream<S1, S2, St, E>(
+        &self,
+        bucket_name: S1,
+        object_key: S2,
+        stream: St,
+        content_length: Option<u64>,
+        params: UploadPartRequest,
+    ) -> Result<UploadPartResult>
+    where
+        S1: AsRef<str> + Send,
+        S2: AsRef<str> + Send,
+        St: Stream<Item = std::result::Result<Bytes, E>> + Send + 'static,
+        E: std::error::Error + Send + Sync + 'static,
+    {
+        let request = build_upload_part_stream_request(
+            bucket_name.as_ref(),
+            object_key.as_ref(),
+            StreamBody::Stream(reqwest::Body::wrap_stream(stream)),
+            content_length,
+            params,
+        )?;
+
+        let (headers, _) = self.do_request::<()>(request).await?;
+
+        Ok(headers.into())
+    }
+
+    /// Upload part from an async reader.
+    /// If `content_length` is `None`, the data is uploaded with chunked transfer encoding.
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/uploadpart>
+    async fn upload_part_from_reader<S1, S2, R>(
+        &self,
+        bucket_name: S1,
+        object_key: S2,
+        reader: R,
+        content_length: Option<u64>,
+        params: UploadPartRequest,
+    ) -> Result<UploadPartResult>
+    where
+        S1: AsRef<str> + Send,
+        S2: AsRef<str> + Send,
+        R: AsyncRead + Send + 'static,
+    {
+        let stream = FramedRead::new(reader, BytesCodec::new()).map_ok(|bytes| bytes.freeze());
+        self.upload_part_from_stream(bucket_name, object_key, stream, content_length, params).await
+    }
+
     /// When you want to copy a file larger than 1GB, you must use `upload_part_copy`.
     /// First, initiate a multipart upload and get `uploadId`, then call this method to upload parts of the source object.
     /// Finally complete the multipart upload by invoking `complete_multipart_uploads`
@@ -297,32 +394,19 @@ impl MultipartUploadsOperations for Client {
     /// About multipart uploads
     ///
     /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/abortmultipartupload>
-    async fn abort_multipart_uploads<S1, S2, S3>(&self, bucket_name: S1, object_key: S2, upload_id: S3) -> Result<()>
+    async fn abort_multipart_uploads<S1, S2, S3>(
+        &self,
+        bucket_name: S1,
+        object_key: S2,
+        upload_id: S3,
+        options: Option<AbortMultipartUploadOptions>,
+    ) -> Result<()>
     where
         S1: AsRef<str> + Send,
         S2: AsRef<str> + Send,
         S3: AsRef<str> + Send,
     {
-        let bucket_name = bucket_name.as_ref();
-        let object_key = object_key.as_ref();
-
-        if !validate_bucket_name(bucket_name) {
-            return Err(Error::Other(format!("invalid bucket name: {}", bucket_name)));
-        }
-
-        if !validate_object_key(object_key) {
-            return Err(Error::Other(format!("invalid object key: {}", object_key)));
-        }
-
-        if upload_id.as_ref().is_empty() {
-            return Err(Error::Other("invalid upload id: [empty]".to_string()));
-        }
-
-        let request = OssRequest::new()
-            .method(RequestMethod::Delete)
-            .bucket(bucket_name)
-            .object(object_key)
-            .add_query("uploadId", upload_id);
+        let request = build_abort_multipart_uploads_request(bucket_name.as_ref(), object_key.as_ref(), upload_id.as_ref(), &options)?;
 
         self.do_request::<()>(request).await?;
 
@@ -448,6 +532,7 @@ mod test_multipart_async {
             let upload_data = UploadPartRequest {
                 part_number: (i + 1) as u32,
                 upload_id: upload_id.clone(),
+                ..Default::default()
             };
 
             log::debug!("begin to upload part {}", i);
@@ -546,6 +631,7 @@ mod test_multipart_async {
             let upload_data = UploadPartRequest {
                 part_number: part_no,
                 upload_id: upload_id.clone(),
+                ..Default::default()
             };
 
             let upload_response = client.upload_part_from_buffer(bucket, &object, buf, upload_data).await;
@@ -565,7 +651,7 @@ mod test_multipart_async {
 
         assert_eq!(ranges.len(), list_parts_result.parts.len());
 
-        let abort_response = client.abort_multipart_uploads(bucket, &object, upload_id.clone()).await;
+        let abort_response = client.abort_multipart_uploads(bucket, &object, upload_id.clone(), None).await;
         log::debug!("{:#?}", abort_response);
         assert!(abort_response.is_ok());
 
@@ -596,7 +682,7 @@ mod test_multipart_async {
                 bucket,
                 &dest_object_key,
                 UploadPartCopyRequest::new(1, &upload_id, source_object_key),
-                Some(UploadPartCopyOptionsBuilder::new().copy_source_range("bytes=0-185000").build()),
+                Some(UploadPartCopyOptionsBuilder::new().copy_source_range(0..=185000).build()),
             )
             .await;
         assert!(upload_response.is_ok());
@@ -609,7 +695,7 @@ mod test_multipart_async {
                 bucket,
                 &dest_object_key,
                 UploadPartCopyRequest::new(2, &upload_id, source_object_key),
-                Some(UploadPartCopyOptionsBuilder::new().copy_source_range("bytes=185001-").build()),
+                Some(UploadPartCopyOptionsBuilder::new().copy_source_range(185001..).build()),
             )
             .await;
         assert!(upload_response.is_ok());
@@ -677,6 +763,7 @@ mod test_multipart_async {
             let upload_data = UploadPartRequest {
                 part_number: (i + 1) as u32,
                 upload_id: upload_id.clone(),
+                ..Default::default()
             };
 
             log::debug!("begin to upload part {}", i);
@@ -718,7 +805,10 @@ mod test_multipart_async {
             .custom_variable("v1", "this is value of v1")
             .build();
 
-        let options = CompleteMultipartUploadOptions { callback: Some(cb) };
+        let options = CompleteMultipartUploadOptions {
+            callback: Some(cb),
+            ..Default::default()
+        };
 
         let comp_response = client
             .complete_multipart_uploads(
@@ -6,10 +6,10 @@ use base64::{prelude::BASE64_STANDARD, Engine};
 use quick_xml::events::{BytesDecl, BytesEnd, BytesStart, BytesText, Event};
 
 use crate::{
-    common,
+    common::{self, apply_request_payer, ByteRange, RequestPayer},
     error::Error,
     object_common::{build_put_object_request, Callback, PutObjectOptions, PutObjectOptionsBuilder},
-    request::{OssRequest, RequestMethod},
+    request::{OssRequest, RequestMethod, StreamBody},
     util::{sanitize_etag, validate_bucket_name, validate_object_key},
     RequestBody, Result,
 };
@@ -56,7 +56,7 @@ impl InitiateMultipartUploadResult {
 }
 
 /// Request data for upload part
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 #[cfg_attr(feature = "serde-support", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "serde-camelcase", serde(rename_all = "camelCase"))]
 pub struct UploadPartRequest {
@@ -72,6 +72,9 @@ pub struct UploadPartRequest {
 
     /// The upload id returned from InitiateMultipartUpload
     pub upload_id: String,
+
+    /// Set to `RequestPayer::Requester` if you are uploading to a requester-pays bucket which is owned by others.
+    pub request_payer: Option<RequestPayer>,
 }
 
 impl UploadPartRequest {
@@ -82,8 +85,14 @@ impl UploadPartRequest {
         Self {
             part_number,
             upload_id: upload_id.as_ref().to_string(),
+            request_payer: None,
         }
     }
+
+    pub fn request_payer(mut self, request_payer: RequestPayer) -> Self {
+        self.request_payer = Some(request_payer);
+        self
+    }
 }
 
 /// Upload part result.
@@ -155,12 +164,13 @@ pub struct UploadPartCopyOptions {
     ///
     /// - 不指定该请求头时，表示拷贝整个源 Object。
     /// - 当指定的范围不符合规范时，则拷贝整个源 Object
-    pub copy_source_range: Option<String>,
+    pub copy_source_range: Option<ByteRange>,
 
     pub copy_source_if_match: Option<String>,
     pub copy_source_if_none_match: Option<String>,
     pub copy_source_if_unmodified_since: Option<String>,
     pub copy_source_if_modified_since: Option<String>,
+    pub request_payer: Option<RequestPayer>,
 }
 
 #[derive(Debug, Default)]
@@ -178,7 +188,7 @@ impl UploadPartCopyOptionsBuilder {
         self
     }
 
-    pub fn copy_source_range<S: Into<String>>(mut self, range: S) -> Self {
+    pub fn copy_source_range<R: Into<ByteRange>>(mut self, range: R) -> Self {
         self.options.copy_source_range = Some(range.into());
         self
     }
@@ -203,6 +213,11 @@ impl UploadPartCopyOptionsBuilder {
         self
     }
 
+    pub fn request_payer(mut self, request_payer: RequestPayer) -> Self {
+        self.options.request_payer = Some(request_payer);
+        self
+    }
+
     pub fn build(self) -> UploadPartCopyOptions {
         self.options
     }
@@ -295,6 +310,24 @@ impl CompleteMultipartUploadRequest {
 #[cfg_attr(feature = "serde-camelcase", serde(rename_all = "camelCase"))]
 pub struct CompleteMultipartUploadOptions {
     pub callback: Option<Callback>,
+    pub request_payer: Option<RequestPayer>,
+
+    /// Complete the upload only if the ETag of the existing object matches this value,
+    /// otherwise [`crate::error::Error::PreconditionFailed`] is returned.
+    pub if_match: Option<String>,
+
+    /// Complete the upload only if the ETag of the existing object does not match this value.
+    /// Set to `*` to complete the upload only if the object does not exist,
+    /// otherwise [`crate::error::Error::PreconditionFailed`] is returned.
+    pub if_none_match: Option<String>,
+}
+
+/// Options for abort multipart uploads
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde-support", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde-camelcase", serde(rename_all = "camelCase"))]
+pub struct AbortMultipartUploadOptions {
+    pub request_payer: Option<RequestPayer>,
 }
 
 /// Complete multipart upload result
@@ -531,6 +564,8 @@ pub struct ListPartsOptions {
 
     /// The start part number. only parts which numbers are greater than the give `part_number_marker` will be returned
     pub part_number_marker: Option<u32>,
+
+    pub request_payer: Option<RequestPayer>,
 }
 
 #[derive(Debug, Clone, Default)]
@@ -636,15 +671,19 @@ pub(crate) fn build_initiate_multipart_uploads_request(
     options: &Option<InitiateMultipartUploadOptions>,
 ) -> Result<OssRequest> {
     if !validate_bucket_name(bucket_name) {
-        return Err(Error::Other(format!("invalid bucket name: {}", bucket_name)));
+        return Err(Error::InvalidBucketName(bucket_name.to_string()));
     }
 
     if !validate_object_key(object_key) {
-        return Err(Error::Other(format!("invalid object key: {}", object_key)));
+        return Err(Error::InvalidObjectKey(object_key.to_string()));
     }
 
     let mut request = build_put_object_request(bucket_name, object_key, RequestBody::Empty, options)?;
 
+    // conditional headers are for complete multipart uploads only
+    request.headers_mut().remove("if-match");
+    request.headers_mut().remove("if-none-match");
+
     request = request
         .method(RequestMethod::Post)
         .bucket(bucket_name)
@@ -656,24 +695,28 @@ pub(crate) fn build_initiate_multipart_uploads_request(
 
 pub(crate) fn build_upload_part_request(bucket_name: &str, object_key: &str, body: RequestBody, params: UploadPartRequest) -> Result<OssRequest> {
     if !validate_bucket_name(bucket_name) {
-        return Err(Error::Other(format!("invalid bucket name: {}", bucket_name)));
+        return Err(Error::InvalidBucketName(bucket_name.to_string()));
     }
 
     if !validate_object_key(object_key) {
-        return Err(Error::Other(format!("invalid object key: {}", object_key)));
+        return Err(Error::InvalidObjectKey(object_key.to_string()));
     }
 
-    let UploadPartRequest { part_number, upload_id } = params;
+    let UploadPartRequest {
+        part_number,
+        upload_id,
+        request_payer,
+    } = params;
 
     if !(1..=10000).contains(&part_number) {
-        return Err(Error::Other(format!(
+        return Err(Error::InvalidArgument(format!(
             "invalid part number: {}. part number should be in range [1, 10000]",
             part_number
         )));
     }
 
     if upload_id.is_empty() {
-        return Err(Error::Other("invalid upload id. upload id must not be empty".to_string()));
+        return Err(Error::InvalidArgument("invalid upload id. upload id must not be empty".to_string()));
     }
 
     let request = OssRequest::new()
@@ -684,9 +727,25 @@ pub(crate) fn build_upload_part_request(bucket_name: &str, object_key: &str, bod
         .add_query("uploadId", upload_id)
         .body(body);
 
+    let request = apply_request_payer(request, &request_payer);
+
     Ok(request)
 }
 
+/// Build upload part request with streaming body.
+/// If the content length is `None`, the body is sent with chunked transfer encoding.
+pub(crate) fn build_upload_part_stream_request(
+    bucket_name: &str,
+    object_key: &str,
+    body: StreamBody,
+    content_length: Option<u64>,
+    params: UploadPartRequest,
+) -> Result<OssRequest> {
+    let request = build_upload_part_request(bucket_name, object_key, RequestBody::Empty, params)?;
+
+    Ok(request.stream_body(body, content_length))
+}
+
 pub(crate) fn build_upload_part_copy_request(
     bucket_name: &str,
     object_key: &str,
@@ -694,19 +753,19 @@ pub(crate) fn build_upload_part_copy_request(
     options: &Option<UploadPartCopyOptions>,
 ) -> Result<OssRequest> {
     if !validate_bucket_name(bucket_name) {
-        return Err(Error::Other(format!("invalid bucket name: {}", bucket_name)));
+        return Err(Error::InvalidBucketName(bucket_name.to_string()));
     }
 
     if !validate_object_key(object_key) {
-        return Err(Error::Other(format!("invalid destination object key: {}", object_key)));
+        return Err(Error::InvalidObjectKey(object_key.to_string()));
     }
 
     if !validate_object_key(&data.source_object_key) {
-        return Err(Error::Other(format!("invalid source object key: {}", data.source_object_key)));
+        return Err(Error::InvalidObjectKey(data.source_object_key.to_string()));
     }
 
     if !(1..=10000).contains(&data.part_number) {
-        return Err(Error::Other(format!("invalid part number: {}", data.part_number)));
+        return Err(Error::InvalidArgument(format!("invalid part number: {}", data.part_number)));
     }
 
     let UploadPartCopyRequest {
@@ -716,11 +775,11 @@ pub(crate) fn build_upload_part_copy_request(
     } = data;
 
     if upload_id.is_empty() {
-        return Err(Error::Other("invalid upload id: must not be empty".to_string()));
+        return Err(Error::InvalidArgument("invalid upload id: must not be empty".to_string()));
     }
 
     if !validate_object_key(&source_object_key) {
-        return Err(Error::Other(format!("invalid source object key: {}", source_object_key)));
+        return Err(Error::InvalidObjectKey(source_object_key.to_string()));
     }
 
     let mut request = OssRequest::new()
@@ -740,8 +799,8 @@ pub(crate) fn build_upload_part_copy_request(
     request = request.add_header("x-oss-copy-source", &copy_source);
 
     if let Some(options) = options {
-        if let Some(s) = &options.copy_source_range {
-            request = request.add_header("x-oss-copy-source-range", s);
+        if let Some(r) = &options.copy_source_range {
+            request = request.add_header("x-oss-copy-source-range", r.to_string());
         }
 
         if let Some(s) = &options.copy_source_if_match {
@@ -759,6 +818,8 @@ pub(crate) fn build_upload_part_copy_request(
         if let Some(s) = &options.copy_source_if_unmodified_since {
             request = request.add_header("x-oss-copy-source-if-unmodified-since", s);
         }
+
+        request = apply_request_payer(request, &options.request_payer);
     }
 
     Ok(request)
@@ -771,19 +832,19 @@ pub(crate) fn build_complete_multipart_uploads_request(
     options: &Option<CompleteMultipartUploadOptions>,
 ) -> Result<OssRequest> {
     if !validate_bucket_name(bucket_name) {
-        return Err(Error::Other(format!("invalid bucket name: {}", bucket_name)));
+        return Err(Error::InvalidBucketName(bucket_name.to_string()));
     }
 
     if !validate_object_key(object_key) {
-        return Err(Error::Other(format!("invalid object key: {}", object_key)));
+        return Err(Error::InvalidObjectKey(object_key.to_string()));
     }
 
     if data.upload_id.is_empty() {
-        return Err(Error::Other("upload id must not be empty".to_string()));
+        return Err(Error::InvalidArgument("upload id must not be empty".to_string()));
     }
 
     if data.parts.is_empty() {
-        return Err(Error::Other("multipart uploads items must not be empty".to_string()));
+        return Err(Error::InvalidArgument("multipart uploads items must not be empty".to_string()));
     }
 
     let upload_id = data.upload_id.clone();
@@ -811,6 +872,16 @@ pub(crate) fn build_complete_multipart_uploads_request(
                 request = request.add_header("x-oss-callback-var", callback_vars_base64);
             }
         }
+
+        if let Some(s) = &options.if_match {
+            request = request.add_header("if-match", s);
+        }
+
+        if let Some(s) = &options.if_none_match {
+            request = request.add_header("if-none-match", s);
+        }
+
+        request = apply_request_payer(request, &options.request_payer);
     }
 
     Ok(request)
@@ -818,7 +889,7 @@ pub(crate) fn build_complete_multipart_uploads_request(
 
 pub(crate) fn build_list_multipart_uploads_request(bucket_name: &str, options: &Option<ListMultipartUploadsOptions>) -> Result<OssRequest> {
     if !validate_bucket_name(bucket_name) {
-        return Err(Error::Other("invalid bucket name".to_string()));
+        return Err(Error::InvalidBucketName(bucket_name.to_string()));
     }
 
     let mut request = OssRequest::new().method(RequestMethod::Get).bucket(bucket_name).add_query("uploads", "");
@@ -849,15 +920,15 @@ pub(crate) fn build_list_multipart_uploads_request(bucket_name: &str, options: &
 
 pub(crate) fn build_list_parts_request(bucket_name: &str, object_key: &str, upload_id: &str, options: &Option<ListPartsOptions>) -> Result<OssRequest> {
     if !validate_bucket_name(bucket_name) {
-        return Err(Error::Other(format!("invalid bucket name: {}", bucket_name)));
+        return Err(Error::InvalidBucketName(bucket_name.to_string()));
     }
 
     if !validate_object_key(object_key) {
-        return Err(Error::Other(format!("invalid object key: {}", object_key)));
+        return Err(Error::InvalidObjectKey(object_key.to_string()));
     }
 
     if upload_id.is_empty() {
-        return Err(Error::Other("upload id must not be empty".to_string()));
+        return Err(Error::InvalidArgument("upload id must not be empty".to_string()));
     }
 
     let mut request = OssRequest::new()
@@ -874,6 +945,39 @@ pub(crate) fn build_list_parts_request(bucket_name: &str, object_key: &str, uplo
         if let Some(n) = options.part_number_marker {
             request = request.add_query("part-number-marker", n.to_string());
         }
+
+        request = apply_request_payer(request, &options.request_payer);
+    }
+
+    Ok(request)
+}
+
+pub(crate) fn build_abort_multipart_uploads_request(
+    bucket_name: &str,
+    object_key: &str,
+    upload_id: &str,
+    options: &Option<AbortMultipartUploadOptions>,
+) -> Result<OssRequest> {
+    if !validate_bucket_name(bucket_name) {
+        return Err(Error::InvalidBucketName(bucket_name.to_string()));
+    }
+
+    if !validate_object_key(object_key) {
+        return Err(Error::InvalidObjectKey(object_key.to_string()));
+    }
+
+    if upload_id.is_empty() {
+        return Err(Error::InvalidArgument("invalid upload id: [empty]".to_string()));
+    }
+
+    let mut request = OssRequest::new()
+        .method(RequestMethod::Delete)
+        .bucket(bucket_name)
+        .object(object_key)
+        .add_query("uploadId", upload_id);
+
+    if let Some(options) = options {
+        request = apply_request_payer(request, &options.request_payer);
     }
 
     Ok(request)
@@ -881,7 +985,49 @@ pub(crate) fn build_list_parts_request(bucket_name: &str, object_key: &str, uplo
 
 #[cfg(test)]
 mod test_multipart_common {
-    use super::ListMultipartUploadsResult;
+    use crate::{common::RequestPayer, object_common::PutObjectOptionsBuilder, request::RequestMethod};
+
+    use super::{
+        build_abort_multipart_uploads_request, build_complete_multipart_uploads_request, build_initiate_multipart_uploads_request, AbortMultipartUploadOptions,
+        CompleteMultipartUploadOptions, CompleteMultipartUploadRequest, ListMultipartUploadsResult,
+    };
+
+    #[test]
+    fn test_abort_multipart_uploads_request_payer() {
+        assert!(build_abort_multipart_uploads_request("test-bucket", "a/b.txt", "", &None).is_err());
+
+        let request = build_abort_multipart_uploads_request("test-bucket", "a/b.txt", "upload-id", &None).unwrap();
+        assert_eq!(RequestMethod::Delete, request.method);
+        assert_eq!(Some(&"upload-id".to_string()), request.query.get("uploadId"));
+        assert!(!request.headers.contains_key("x-oss-request-payer"));
+
+        let options = AbortMultipartUploadOptions {
+            request_payer: Some(RequestPayer::Requester),
+        };
+        let request = build_abort_multipart_uploads_request("test-bucket", "a/b.txt", "upload-id", &Some(options)).unwrap();
+        assert_eq!(Some(&"requester".to_string()), request.headers.get("x-oss-request-payer"));
+    }
+
+    #[test]
+    fn test_complete_multipart_uploads_conditional_headers() {
+        let data = CompleteMultipartUploadRequest {
+            upload_id: "upload-id".to_string(),
+            parts: vec![(1, "ETAG1".to_string())],
+        };
+
+        let options = CompleteMultipartUploadOptions {
+            if_none_match: Some("*".to_string()),
+            ..Default::default()
+        };
+
+        let request = build_complete_multipart_uploads_request("test-bucket", "a/b.txt", data, &Some(options)).unwrap();
+        assert_eq!(Some(&"*".to_string()), request.headers.get("if-none-match"));
+
+        // conditional headers are not sent when initiating multipart uploads
+        let options = PutObjectOptionsBuilder::new().if_none_match("*").build();
+        let request = build_initiate_multipart_uploads_request("test-bucket", "a/b.txt", &Some(options)).unwrap();
+        assert!(!request.headers.contains_key("if-none-match"));
+    }
 
     #[test]
     fn test_list_multipart_uploads_result() {
@@ -2,21 +2,23 @@ use std::path::Path;
 
 use async_trait::async_trait;
 use base64::{prelude::BASE64_STANDARD, Engine};
-use futures::TryStreamExt;
+use bytes::Bytes;
+use futures::{Stream, TryStreamExt};
 use reqwest::StatusCode;
-use tokio::io::AsyncWriteExt;
+use tokio::io::{AsyncRead, AsyncWriteExt};
+use tokio_util::codec::{BytesCodec, FramedRead};
 
 use crate::{
-    error::Error,
+    error::{ApiErrorCode, Error},
     object_common::{
         build_copy_object_request, build_delete_multiple_objects_request, build_get_object_request, build_head_object_request, build_put_object_request,
-        build_restore_object_request, AppendObjectOptions, AppendObjectResult, CopyObjectOptions, CopyObjectResult, DeleteMultipleObjectsConfig,
-        DeleteMultipleObjectsResult, DeleteObjectOptions, DeleteObjectResult, GetObjectMetadataOptions, GetObjectOptions, GetObjectResult, HeadObjectOptions,
-        ObjectMetadata, PutObjectOptions, PutObjectResult, RestoreObjectRequest, RestoreObjectResult,
+        build_put_object_stream_request, build_restore_object_request, AppendObjectOptions, AppendObjectResult, CopyObjectOptions, CopyObjectResult,
+        DeleteMultipleObjectsConfig, DeleteMultipleObjectsResult, DeleteObjectOptions, DeleteObjectResult, GetObjectMetadataOptions, GetObjectOptions,
+        GetObjectResult, HeadObjectOptions, ObjectMetadata, PutObjectOptions, PutObjectResult, RestoreObjectRequest, RestoreObjectResult,
     },
     request::{OssRequest, RequestMethod},
     util::{validate_bucket_name, validate_object_key, validate_path},
-    ByteStream, Client, RequestBody, Result,
+    ByteStream, Client, RequestBody, Result, StreamBody,
 };
 
 #[async_trait]
@@ -62,6 +64,43 @@ pub trait ObjectOperations {
         S2: AsRef<str> + Send,
         S3: AsRef<str> + Send;
 
+    /// Create an object from a stream of bytes, e.g. the body of another http response.
+    /// If `content_length` is `None`, the data is uploaded with chunked transfer encoding.
+    /// And, it is recommended to set `mime_type` in `options`
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/putobject>
+    async fn put_object_from_stream<S1, S2, St, E>(
+        &self,
+        bucket_name: S1,
+        object_key: S2,
+        stream: St,
+        content_length: Option<u64>,
+        options: Option<PutObjectOptions>,
+    ) -> Result<PutObjectResult>
+    where
+        S1: AsRef<str> + Send,
+        S2: AsRef<str> + Send,
+        St: Stream<Item = std::result::Result<Bytes, E>> + Send + 'static,
+        E: std::error::Error + Send + Sync + 'static;
+
+    /// Create an object from an async reader, e.g. an archive being built on the fly.
+    /// If `content_length` is `None`, the data is uploaded with chunked transfer encoding.
+    /// And, it is recommended to set `mime_type` in `options`
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/putobject>
+    async fn put_object_from_reader<S1, S2, R>(
+        &self,
+        bucket_name: S1,
+        object_key: S2,
+        reader: R,
+        content_length: Option<u64>,
+        options: Option<PutObjectOptions>,
+    ) -> Result<PutObjectResult>
+    where
+        S1: AsRef<str> + Send,
+        S2: AsRef<str> + Send,
+        R: AsyncRead + Send + 'static;
+
     /// Append object.
     ///
     /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/appendobject>
@@ -112,7 +151,7 @@ pub trait ObjectOperations {
         S2: AsRef<str> + Send,
         S3: AsRef<str> + Send;
 
-    /// Download object to local file
+    /// Download object to local file. The returned result contains metadata parsed from response headers.
     ///
     /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/getobject>
     async fn get_object_to_file<S1, S2, P>(&self, bucket_name: S1, object_key: S2, file_path: P, options: Option<GetObjectOptions>) -> Result<GetObjectResult>
@@ -121,17 +160,17 @@ pub trait ObjectOperations {
         S2: AsRef<str> + Send,
         P: AsRef<Path> + Send;
 
-    /// Get object content into memory (bytes array).
+    /// Get object content into memory (bytes array), along with metadata parsed from response headers.
     ///
     /// Large files can consume significant memory, exercise caution when using this function.
-    async fn get_object_to_buffer<S1, S2>(&self, bucket_name: S1, object_key: S2, options: Option<GetObjectOptions>) -> Result<Vec<u8>>
+    async fn get_object_to_buffer<S1, S2>(&self, bucket_name: S1, object_key: S2, options: Option<GetObjectOptions>) -> Result<(GetObjectResult, Vec<u8>)>
     where
         S1: AsRef<str> + Send,
         S2: AsRef<str> + Send;
 
-    /// Get object as a stream.
+    /// Get object as a stream, along with metadata parsed from response headers.
     /// This is useful for processing large files without loading the entire content into memory.
-    async fn get_object_stream<S1, S2>(&self, bucket_name: S1, object_key: S2, options: Option<GetObjectOptions>) -> Result<ByteStream>
+    async fn get_object_stream<S1, S2>(&self, bucket_name: S1, object_key: S2, options: Option<GetObjectOptions>) -> Result<(GetObjectResult, ByteStream)>
     where
         S1: AsRef<str> + Send,
         S2: AsRef<str> + Send;
@@ -312,12 +351,78 @@ impl ObjectOperations for Client {
         let data = if let Ok(d) = BASE64_STANDARD.decode(base64_string.as_ref()) {
             d
         } else {
-            return Err(Error::Other("Decoding base64 string failed".to_string()));
+            return Err(Error::InvalidArgument("Decoding base64 string failed".to_string()));
         };
 
         self.put_object_from_buffer(bucket_name, object_key, data, options).await
     }
 
+    /// Create an object from a stream of bytes, e.g. the body of another http response.
+    /// If `content_length` is `None`, the data is uploaded with chunked transfer encoding.
+    /// And, it is recommended to set `mime_type` in `options`
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/putobject>
+    async fn put_object_from_stream<S1, S2, St, E>(
+        &self,
+        bucket_name: S1,
+        object_key: S2,
+        stream: St,
+        content_length: Option<u64>,
+        options: Option<PutObjectOptions>,
+    ) -> Result<PutObjectResult>
+    where
+        S1: AsRef<str> + Send,
+        S2: AsRef<str> + Send,
+        St: Stream<Item = std::result::Result<Bytes, E>> + Send + 'static,
+        E: std::error::Error + Send + Sync + 'static,
+    {
+        let bucket_name = bucket_name.as_ref();
+        let object_key = object_key.as_ref();
+
+        let object_key = object_key.strip_prefix("/").unwrap_or(object_key);
+        let object_key = object_key.strip_suffix("/").unwrap_or(object_key);
+
+        let with_callback = if let Some(opt) = &options { opt.callback.is_some() } else { false };
+
+        let request = build_put_object_stream_request(
+            bucket_name,
+            object_key,
+            StreamBody::Stream(reqwest::Body::wrap_stream(stream)),
+            content_length,
+            &options,
+        )?;
+
+        let (headers, content) = self.do_request::<String>(request).await?;
+
+        if with_callback {
+            Ok(PutObjectResult::CallbackResponse(content))
+        } else {
+            Ok(PutObjectResult::ApiResponse(headers.into()))
+        }
+    }
+
+    /// Create an object from an async reader, e.g. an archive being built on the fly.
+    /// If `content_length` is `None`, the data is uploaded with chunked transfer encoding.
+    /// And, it is recommended to set `mime_type` in `options`
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/putobject>
+    async fn put_object_from_reader<S1, S2, R>(
+        &self,
+        bucket_name: S1,
+        object_key: S2,
+        reader: R,
+        content_length: Option<u64>,
+        options: Option<PutObjectOptions>,
+    ) -> Result<PutObjectResult>
+    where
+        S1: AsRef<str> + Send,
+        S2: AsRef<str> + Send,
+        R: AsyncRead + Send + 'static,
+    {
+        let stream = FramedRead::new(reader, BytesCodec::new()).map_ok(|bytes| bytes.freeze());
+        self.put_object_from_stream(bucket_name, object_key, stream, content_length, options).await
+    }
+
     /// Append object.
     ///
     /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/appendobject>
@@ -411,7 +516,7 @@ impl ObjectOperations for Client {
         let data = if let Ok(d) = BASE64_STANDARD.decode(base64_string.as_ref()) {
             d
         } else {
-            return Err(Error::Other("Decoding base64 string failed".to_string()));
+            return Err(Error::InvalidArgument("Decoding base64 string failed".to_string()));
         };
 
         self.append_object_from_buffer(bucket_name, object_key, data, position, options).await
@@ -439,7 +544,7 @@ impl ObjectOperations for Client {
         };
 
         if !validate_path(&file_path) {
-            return Err(Error::Other(format!("invalid file path: {:?}", file_path.as_os_str().to_str())));
+            return Err(Error::InvalidArgument(format!("invalid file path: {:?}", file_path.as_os_str().to_str())));
         }
 
         // check parent path
@@ -451,7 +556,7 @@ impl ObjectOperations for Client {
 
         let request = build_get_object_request(bucket_name, object_key, &options)?;
 
-        let (_, mut stream) = self.do_request::<ByteStream>(request).await?;
+        let (headers, mut stream) = self.do_request::<ByteStream>(request).await?;
 
         let mut file = tokio::fs::File::create(&file_path).await?;
 
@@ -461,13 +566,13 @@ impl ObjectOperations for Client {
 
         file.flush().await?;
 
-        Ok(GetObjectResult)
+        Ok(headers.into())
     }
 
     /// Get object content into memory (bytes array).
     ///
     /// Large files can consume significant memory, exercise caution when using this function.
-    async fn get_object_to_buffer<S1, S2>(&self, bucket_name: S1, object_key: S2, options: Option<GetObjectOptions>) -> Result<Vec<u8>>
+    async fn get_object_to_buffer<S1, S2>(&self, bucket_name: S1, object_key: S2, options: Option<GetObjectOptions>) -> Result<(GetObjectResult, Vec<u8>)>
     where
         S1: AsRef<str> + Send,
         S2: AsRef<str> + Send
@@ -477,7 +582,7 @@ impl ObjectOperations for Client {
 
         let request = build_get_object_request(bucket_name, object_key, &options)?;
 
-        let (_, mut stream) = self.do_request::<ByteStream>(request).await?;
+        let (headers, mut stream) = self.do_request::<ByteStream>(request).await?;
 
         let mut buf = Vec::new();
 
@@ -487,10 +592,10 @@ impl ObjectOperations for Client {
 
         buf.flush().await?;
 
-        Ok(buf)
+        Ok((headers.into(), buf))
     }
 
-    async fn get_object_stream<S1, S2>(&self, bucket_name: S1, object_key: S2, options: Option<GetObjectOptions>) -> Result<ByteStream>
+    async fn get_object_stream<S1, S2>(&self, bucket_name: S1, object_key: S2, options: Option<GetObjectOptions>) -> Result<(GetObjectResult, ByteStream)>
     where
         S1: AsRef<str> + Send,
         S2: AsRef<str> + Send
@@ -499,8 +604,8 @@ impl ObjectOperations for Client {
         let object_key = object_key.as_ref();
 
         let request = build_get_object_request(bucket_name, object_key, &options)?;
-        let (_, stream) = self.do_request::<ByteStream>(request).await?;
-        Ok(stream)
+        let (headers, stream) = self.do_request::<ByteStream>(request).await?;
+        Ok((headers.into(), stream))
     }
 
     /// Create a "folder".
@@ -522,7 +627,7 @@ impl ObjectOperations for Client {
         };
 
         if !validate_bucket_name(bucket_name) {
-            return Err(Error::Other(format!("invalid bucket name: {}", bucket_name)));
+            return Err(Error::InvalidBucketName(bucket_name.to_string()));
         }
 
         let request = OssRequest::new()
@@ -555,7 +660,7 @@ impl ObjectOperations for Client {
         };
 
         if !validate_bucket_name(bucket_name) {
-            return Err(Error::Other(format!("invalid bucket name: {}", bucket_name)));
+            return Err(Error::InvalidBucketName(bucket_name.to_string()));
         }
 
         let request = OssRequest::new().method(RequestMethod::Delete).bucket(bucket_name).object(object_key);
@@ -577,11 +682,11 @@ impl ObjectOperations for Client {
         let object_key = object_key.as_ref();
 
         if !validate_bucket_name(bucket_name) {
-            return Err(Error::Other(format!("invalid bucket name: {}", bucket_name)));
+            return Err(Error::InvalidBucketName(bucket_name.to_string()));
         }
 
         if !validate_object_key(object_key) {
-            return Err(Error::Other(format!("invalid object key: {}", object_key)));
+            return Err(Error::InvalidObjectKey(object_key.to_string()));
         }
 
         let mut request = OssRequest::new()
@@ -611,7 +716,8 @@ impl ObjectOperations for Client {
         match self.get_object_metadata(bucket_name, object_key, options).await {
             Ok(_) => Ok(true),
             Err(e) => match e {
-                Error::StatusError(status) if status == StatusCode::NOT_FOUND => Ok(false),
+                Error::StatusError { status, .. } if status == StatusCode::NOT_FOUND => Ok(false),
+                Error::ApiError(ref r) if r.error_code() == ApiErrorCode::NoSuchKey => Ok(false),
                 _ => Err(e),
             },
         }
@@ -676,11 +782,11 @@ impl ObjectOperations for Client {
         let object_key = object_key.as_ref();
 
         if !validate_bucket_name(bucket_name) {
-            return Err(Error::Other(format!("invalid bucket name: {}", bucket_name)));
+            return Err(Error::InvalidBucketName(bucket_name.to_string()));
         }
 
         if !validate_object_key(object_key) {
-            return Err(Error::Other(format!("invalid object key: {}", object_key)));
+            return Err(Error::InvalidObjectKey(object_key.to_string()));
         }
 
         let mut request = OssRequest::new().method(RequestMethod::Delete).bucket(bucket_name).object(object_key);
@@ -738,11 +844,11 @@ impl ObjectOperations for Client {
         let object_key = object_key.as_ref();
 
         if !validate_bucket_name(bucket_name) {
-            return Err(Error::Other(format!("invalid bucket name: {}", bucket_name)));
+            return Err(Error::InvalidBucketName(bucket_name.to_string()));
         }
 
         if !validate_object_key(object_key) {
-            return Err(Error::Other(format!("invalid object key: {}", object_key)));
+            return Err(Error::InvalidObjectKey(object_key.to_string()));
         }
 
         let request = OssRequest::new()
@@ -975,7 +1081,7 @@ mod test_object_async {
 
         let output_file = format!("/home/yuanyq/Downloads/ali-oss-rs-test/{}.zip.1", Uuid::new_v4());
 
-        let options = GetObjectOptionsBuilder::new().range("bytes=0-499").build();
+        let options = GetObjectOptionsBuilder::new().range(0..=499).build();
 
         let result = client
             .get_object_to_file("yuanyq", "rust-sdk-test/katex.zip", &output_file, Some(options))
@@ -1003,7 +1109,7 @@ mod test_object_async {
         ];
 
         for output_file in invalid_files {
-            let options = GetObjectOptionsBuilder::new().range("bytes=0-499").build();
+            let options = GetObjectOptionsBuilder::new().range(0..=499).build();
 
             let result = client.get_object_to_file("yuanyq", "rust-sdk-test/katex.zip", output_file, Some(options)).await;
 
@@ -1020,9 +1126,11 @@ mod test_object_async {
         let bucket_name = "mi-dev-public";
         let object_key = "quiz_template.xls";
         // 9b98f68671ec239958e58a6813960ab5
-        let buf = client.get_object_to_buffer(bucket_name, object_key, None).await.unwrap();
+        let (result, buf) = client.get_object_to_buffer(bucket_name, object_key, None).await.unwrap();
         let d = md5::compute(&buf).to_vec();
         assert_eq!(hex::encode(&d), "9b98f68671ec239958e58a6813960ab5");
+        assert_eq!(buf.len() as u64, result.metadata.content_length);
+        assert!(!result.metadata.etag.is_empty());
     }
 
     #[tokio::test]
@@ -4,9 +4,12 @@ use base64::prelude::{Engine, BASE64_STANDARD};
 use quick_xml::events::{BytesDecl, BytesEnd, BytesStart, BytesText, Event};
 
 use crate::{
-    common::{self, build_tag_string, MetadataDirective, ObjectType, ServerSideEncryptionAlgorithm, StorageClass, TagDirective, MIME_TYPE_XML},
+    common::{
+        self, apply_request_payer, build_tag_string, ByteRange, ContentRange, MetadataDirective, ObjectType, RequestPayer, ServerSideEncryptionAlgorithm,
+        StorageClass, TagDirective, MIME_TYPE_XML,
+    },
     error::Error,
-    request::{OssRequest, RequestMethod},
+    request::{OssRequest, RequestMethod, StreamBody},
     util::{sanitize_etag, validate_bucket_name, validate_meta_key, validate_object_key, validate_tag_key, validate_tag_value},
     RequestBody, Result,
 };
@@ -67,7 +70,10 @@ impl TryFrom<&str> for ObjectAcl {
             "public-read" => Ok(ObjectAcl::PublicRead),
             "private" => Ok(ObjectAcl::Private),
             "default" | "" => Ok(ObjectAcl::Default),
-            _ => Err(Error::Other(format!("Invalid ACL value: {}", s))),
+            _ => Err(Error::InvalidEnumValue {
+                name: "ACL",
+                value: s.to_string(),
+            }),
         }
     }
 }
@@ -137,7 +143,10 @@ impl TryFrom<&str> for ContentEncoding {
             "deflate" => Ok(ContentEncoding::Deflate),
             "compress" => Ok(ContentEncoding::Compress),
             "br" => Ok(ContentEncoding::Brotli),
-            _ => Err(Error::Other(format!("invalid content encoding: {}", value))),
+            _ => Err(Error::InvalidEnumValue {
+                name: "ContentEncoding",
+                value: value.to_string(),
+            }),
         }
     }
 }
@@ -211,6 +220,18 @@ pub struct PutObjectOptions {
     /// **设置 `x-oss-forbid-overwrite` 请求 Header 导致 QPS 处理性能下降，如果您有大量的操作需要使用 `x-oss-forbid-overwrite` 请求 Header（QPS > 1000），请联系技术支持，避免影响您的业务。**
     pub forbid_overwrite: Option<bool>,
 
+    /// ETag 值。For `put_object` only.
+    ///
+    /// 只有当目标 Object 存在且 ETag 与此值匹配时才写入，否则返回 `412 Precondition Failed`，
+    /// 对应的错误为 [`crate::error::Error::PreconditionFailed`]。
+    pub if_match: Option<String>,
+
+    /// ETag 值，或者 `*`。For `put_object` only.
+    ///
+    /// 只有当目标 Object 的 ETag 与此值不匹配时才写入，设置为 `*` 表示只有当目标 Object 不存在时才写入，
+    /// 否则返回 `412 Precondition Failed`，对应的错误为 [`crate::error::Error::PreconditionFailed`]。
+    pub if_none_match: Option<String>,
+
     /// 创建 Object 时，指定服务器端加密方式。
     /// 指定此选项后，在响应头中会返回此选项，OSS 会对上传的 Object 进行加密编码存储。当下载该 Object 时，响应头中会包含 `x-oss-server-side-encryption`，且该值会被设置成此 Object 的加密算法。
     pub server_side_encryption: Option<ServerSideEncryptionAlgorithm>,
@@ -240,6 +261,9 @@ pub struct PutObjectOptions {
 
     /// For `put_object` only.
     pub callback: Option<Callback>,
+
+    /// Set to `RequestPayer::Requester` if you are uploading to a requester-pays bucket which is owned by others.
+    pub request_payer: Option<RequestPayer>,
 }
 
 pub struct PutObjectOptionsBuilder {
@@ -250,6 +274,8 @@ pub struct PutObjectOptionsBuilder {
     content_md5: Option<String>,
     expires: Option<String>,
     forbid_overwrite: Option<bool>,
+    if_match: Option<String>,
+    if_none_match: Option<String>,
     server_side_encryption: Option<ServerSideEncryptionAlgorithm>,
     server_side_data_encryption: Option<ServerSideEncryptionAlgorithm>,
     server_side_encryption_key_id: Option<String>,
@@ -258,6 +284,7 @@ pub struct PutObjectOptionsBuilder {
     metadata: HashMap<String, String>,
     tags: HashMap<String, String>,
     callback: Option<Callback>,
+    request_payer: Option<RequestPayer>,
 }
 
 impl PutObjectOptionsBuilder {
@@ -270,6 +297,8 @@ impl PutObjectOptionsBuilder {
             content_md5: None,
             expires: None,
             forbid_overwrite: None,
+            if_match: None,
+            if_none_match: None,
             server_side_encryption: None,
             server_side_data_encryption: None,
             server_side_encryption_key_id: None,
@@ -278,6 +307,7 @@ impl PutObjectOptionsBuilder {
             metadata: HashMap::new(),
             tags: HashMap::new(),
             callback: None,
+            request_payer: None,
         }
     }
 
@@ -316,6 +346,16 @@ impl PutObjectOptionsBuilder {
         self
     }
 
+    pub fn if_match(mut self, etag: impl Into<String>) -> Self {
+        self.if_match = Some(etag.into());
+        self
+    }
+
+    pub fn if_none_match(mut self, etag: impl Into<String>) -> Self {
+        self.if_none_match = Some(etag.into());
+        self
+    }
+
     pub fn server_side_encryption(mut self, algorithm: ServerSideEncryptionAlgorithm) -> Self {
         self.server_side_encryption = Some(algorithm);
         self
@@ -356,6 +396,11 @@ impl PutObjectOptionsBuilder {
         self
     }
 
+    pub fn request_payer(mut self, request_payer: RequestPayer) -> Self {
+        self.request_payer = Some(request_payer);
+        self
+    }
+
     pub fn build(self) -> PutObjectOptions {
         PutObjectOptions {
             mime_type: self.mime_type,
@@ -365,6 +410,8 @@ impl PutObjectOptionsBuilder {
             content_md5: self.content_md5,
             expires: self.expires,
             forbid_overwrite: self.forbid_overwrite,
+            if_match: self.if_match,
+            if_none_match: self.if_none_match,
             server_side_encryption: self.server_side_encryption,
             server_side_data_encryption: self.server_side_data_encryption,
             server_side_encryption_key_id: self.server_side_encryption_key_id,
@@ -373,6 +420,7 @@ impl PutObjectOptionsBuilder {
             metadata: self.metadata,
             tags: self.tags,
             callback: self.callback,
+            request_payer: self.request_payer,
         }
     }
 }
@@ -392,7 +440,13 @@ pub struct GetObjectOptions {
     ///
     /// - 如果指定的范围符合规范，返回消息中会包含整个 Object 的大小和此次返回 Object 的范围。例如：`Content-Range: bytes 0~9/44`，表示整个 Object 大小为 `44`，此次返回的范围为 `0~9`。
     /// - 如果指定的范围不符合规范，则传送整个 Object，并且结果中不包含 `Content-Range`。
-    pub range: Option<String>,
+    ///   如果设置了 `standard_range_behavior`，则返回 `416 Requested Range Not Satisfiable` 错误。
+    pub range: Option<ByteRange>,
+
+    /// Send `x-oss-range-behavior: standard` header. The range is handled in standard way:
+    /// an invalid range gets `416 Requested Range Not Satisfiable` instead of the entire object,
+    /// and a range whose end exceeds the object size returns data up to the end of the object.
+    pub standard_range_behavior: bool,
 
     /// GMT 日期时间字符串，例如：`Fri, 13 Nov 2015 14:47:53 GMT`
     ///
@@ -461,10 +515,18 @@ pub struct GetObjectOptions {
 
     /// The version to retreive
     pub version_id: Option<String>,
+
+    /// Process the object before it is returned, sent as `x-oss-process` query parameter.
+    /// e.g. `style/thumbnail`, or an image process built by [`crate::process_common::ImageProcess`].
+    pub process: Option<String>,
+
+    /// Set to `RequestPayer::Requester` if you are reading from a requester-pays bucket which is owned by others.
+    pub request_payer: Option<RequestPayer>,
 }
 
 pub struct GetObjectOptionsBuilder {
-    range: Option<String>,
+    range: Option<ByteRange>,
+    standard_range_behavior: bool,
     if_modified_since: Option<String>,
     if_unmodified_since: Option<String>,
     if_match: Option<String>,
@@ -476,12 +538,15 @@ pub struct GetObjectOptionsBuilder {
     response_content_disposition: Option<String>,
     response_content_encoding: Option<ContentEncoding>,
     version_id: Option<String>,
+    process: Option<String>,
+    request_payer: Option<RequestPayer>,
 }
 
 impl GetObjectOptionsBuilder {
     pub fn new() -> Self {
         Self {
             range: None,
+            standard_range_behavior: false,
             if_modified_since: None,
             if_unmodified_since: None,
             if_match: None,
@@ -493,14 +558,21 @@ impl GetObjectOptionsBuilder {
             response_content_disposition: None,
             response_content_encoding: None,
             version_id: None,
+            process: None,
+            request_payer: None,
         }
     }
 
-    pub fn range(mut self, range: impl Into<String>) -> Self {
+    pub fn range(mut self, range: impl Into<ByteRange>) -> Self {
         self.range = Some(range.into());
         self
     }
 
+    pub fn standard_range_behavior(mut self, standard: bool) -> Self {
+        self.standard_range_behavior = standard;
+        self
+    }
+
     pub fn if_modified_since(mut self, if_modified_since: impl Into<String>) -> Self {
         self.if_modified_since = Some(if_modified_since.into());
         self
@@ -556,9 +628,21 @@ impl GetObjectOptionsBuilder {
         self
     }
 
+    /// Accepts a process string such as `style/thumbnail`, or an [`crate::process_common::ImageProcess`].
+    pub fn process(mut self, process: impl Into<String>) -> Self {
+        self.process = Some(process.into());
+        self
+    }
+
+    pub fn request_payer(mut self, request_payer: RequestPayer) -> Self {
+        self.request_payer = Some(request_payer);
+        self
+    }
+
     pub fn build(self) -> GetObjectOptions {
         GetObjectOptions {
             range: self.range,
+            standard_range_behavior: self.standard_range_behavior,
             if_modified_since: self.if_modified_since,
             if_unmodified_since: self.if_unmodified_since,
             if_match: self.if_match,
@@ -570,6 +654,8 @@ impl GetObjectOptionsBuilder {
             response_content_disposition: self.response_content_disposition,
             response_content_encoding: self.response_content_encoding,
             version_id: self.version_id,
+            process: self.process,
+            request_payer: self.request_payer,
         }
     }
 }
@@ -580,9 +666,29 @@ impl Default for GetObjectOptionsBuilder {
     }
 }
 
-/// A "placeholder" struct for adding more fields in the future
-#[derive(Debug)]
-pub struct GetObjectResult;
+/// Result of getting object
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde-support", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde-camelcase", serde(rename_all = "camelCase"))]
+pub struct GetObjectResult {
+    /// Parsed from `Content-Range` response header.
+    /// It is present only if a range is requested and the range is valid.
+    pub content_range: Option<ContentRange>,
+
+    /// Metadata parsed from response headers.
+    /// If a range is requested, `content_length` is the length of the returned range rather than the whole object.
+    pub metadata: ObjectMetadata,
+}
+
+impl From<HashMap<String, String>> for GetObjectResult {
+    /// Consumes the headers map and return GetObjectResult
+    fn from(headers: HashMap<String, String>) -> Self {
+        Self {
+            content_range: headers.get("content-range").and_then(|s| s.try_into().ok()),
+            metadata: headers.into(),
+        }
+    }
+}
 
 pub(crate) fn build_put_object_request(
     bucket_name: &str,
@@ -591,24 +697,24 @@ pub(crate) fn build_put_object_request(
     options: &Option<PutObjectOptions>,
 ) -> Result<OssRequest> {
     if !validate_bucket_name(bucket_name) {
-        return Err(Error::Other(format!("invalid bucket name: {}", bucket_name)));
+        return Err(Error::InvalidBucketName(bucket_name.to_string()));
     }
 
     if !validate_object_key(object_key) {
-        return Err(Error::Other(format!("invalid object key: {}", object_key)));
+        return Err(Error::InvalidObjectKey(object_key.to_string()));
     }
 
     // check for metadata and tags
     if let Some(options) = &options {
         for (k, v) in &options.metadata {
             if k.is_empty() || !validate_meta_key(k) || v.is_empty() {
-                return Err(Error::Other(format!("invalid meta data: \"{}: {}\". the key must starts with `x-oss-meta-`, and only `[0-9a-z\\-]` are allowed; the key and value must not be empty", k, v)));
+                return Err(Error::InvalidArgument(format!("invalid meta data: \"{}: {}\". the key must starts with `x-oss-meta-`, and only `[0-9a-z\\-]` are allowed; the key and value must not be empty", k, v)));
             }
         }
 
         for (k, v) in &options.tags {
             if k.is_empty() || !validate_tag_key(k) || (!v.is_empty() && !validate_tag_value(v)) {
-                return Err(Error::Other(format!(
+                return Err(Error::InvalidArgument(format!(
                     "invalid tagging data: \"{}={}\". only `[0-9a-zA-Z\\+\\-=\\.:/]` and space character are allowed",
                     k, v
                 )));
@@ -627,7 +733,7 @@ pub(crate) fn build_put_object_request(
                 r.end - r.start
             } else {
                 if !file_path.exists() || !file_path.is_file() {
-                    return Err(Error::Other(format!(
+                    return Err(Error::InvalidArgument(format!(
                         "{} does not exist or is not a regular file",
                         file_path.as_os_str().to_str().unwrap_or("UNKNOWN")
                     )));
@@ -642,7 +748,10 @@ pub(crate) fn build_put_object_request(
 
     // max file size for putting object is 5GB
     if content_length > 5_368_709_120 {
-        return Err(Error::Other(format!("length {} exceeds limitation. max allowed is 5GB", content_length)));
+        return Err(Error::InvalidArgument(format!(
+            "length {} exceeds limitation. max allowed is 5GB",
+            content_length
+        )));
     }
 
     request = request.content_length(content_length);
@@ -683,6 +792,14 @@ pub(crate) fn build_put_object_request(
             }
         }
 
+        if let Some(s) = &options.if_match {
+            request = request.add_header("if-match", s);
+        }
+
+        if let Some(s) = &options.if_none_match {
+            request = request.add_header("if-none-match", s);
+        }
+
         if let Some(a) = &options.server_side_encryption {
             request = request.add_header("x-oss-server-side-encryption", a.as_str());
         }
@@ -723,11 +840,34 @@ pub(crate) fn build_put_object_request(
                 request = request.add_header("x-oss-callback-var", callback_vars_base64);
             }
         }
+
+        request = apply_request_payer(request, &options.request_payer);
     }
 
     Ok(request)
 }
 
+/// Build put object request with streaming body.
+/// If the content length is `None`, the body is sent with chunked transfer encoding.
+pub(crate) fn build_put_object_stream_request(
+    bucket_name: &str,
+    object_key: &str,
+    body: StreamBody,
+    content_length: Option<u64>,
+    options: &Option<PutObjectOptions>,
+) -> Result<OssRequest> {
+    // max file size for putting object is 5GB
+    if let Some(len) = content_length {
+        if len > 5_368_709_120 {
+            return Err(Error::InvalidArgument(format!("length {} exceeds limitation. max allowed is 5GB", len)));
+        }
+    }
+
+    let request = build_put_object_request(bucket_name, object_key, RequestBody::Empty, options)?;
+
+    Ok(request.stream_body(body, content_length))
+}
+
 /// Options for getting object metadata
 ///
 /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/getobjectmeta>
@@ -750,6 +890,7 @@ pub struct HeadObjectOptions {
     pub if_unmodified_since: Option<String>,
     pub if_match: Option<String>,
     pub if_none_match: Option<String>,
+    pub request_payer: Option<RequestPayer>,
 }
 
 pub struct HeadObjectOptionsBuilder {
@@ -758,6 +899,7 @@ pub struct HeadObjectOptionsBuilder {
     if_unmodified_since: Option<String>,
     if_match: Option<String>,
     if_none_match: Option<String>,
+    request_payer: Option<RequestPayer>,
 }
 
 impl HeadObjectOptionsBuilder {
@@ -768,6 +910,7 @@ impl HeadObjectOptionsBuilder {
             if_unmodified_since: None,
             if_match: None,
             if_none_match: None,
+            request_payer: None,
         }
     }
 
@@ -796,6 +939,11 @@ impl HeadObjectOptionsBuilder {
         self
     }
 
+    pub fn request_payer(mut self, request_payer: RequestPayer) -> Self {
+        self.request_payer = Some(request_payer);
+        self
+    }
+
     pub fn build(self) -> HeadObjectOptions {
         HeadObjectOptions {
             version_id: self.version_id,
@@ -803,6 +951,7 @@ impl HeadObjectOptionsBuilder {
             if_unmodified_since: self.if_unmodified_since,
             if_match: self.if_match,
             if_none_match: self.if_none_match,
+            request_payer: self.request_payer,
         }
     }
 }
@@ -822,6 +971,7 @@ pub struct ObjectMetadata {
 
     /// 已经移除了首尾双引号（`"`）之后的字符串
     pub etag: String,
+    pub content_type: Option<String>,
     pub hash_crc64ecma: Option<u64>,
 
     /// Object 通过生命周期规则转储为冷归档或者深度冷归档存储类型的时间。
@@ -899,6 +1049,7 @@ impl From<HashMap<String, String>> for ObjectMetadata {
             request_id: headers.remove("x-oss-request-id").unwrap_or("".to_string()),
             content_length: headers.remove("content-length").unwrap_or("0".to_string()).parse().unwrap_or(0),
             etag: sanitize_etag(headers.remove("etag").unwrap_or_default()),
+            content_type: headers.remove("content-type"),
             hash_crc64ecma: headers.remove("x-oss-hash-crc64ecma").map(|s| s.parse::<u64>().unwrap_or(0)),
             transition_time: headers.remove("x-oss-transition-time"),
             last_access_time: headers.remove("x-oss-last-access-time"),
@@ -906,30 +1057,18 @@ impl From<HashMap<String, String>> for ObjectMetadata {
             version_id: headers.remove("x-oss-version-id"),
             server_side_encryption: if let Some(s) = headers.remove("x-oss-server-side-encryption") {
                 // Not good...
-                if let Ok(v) = s.try_into() {
-                    Some(v)
-                } else {
-                    None
-                }
+                s.try_into().ok()
             } else {
                 None
             },
             server_side_encryption_key_id: headers.remove("x-oss-server-side-encryption-key-id"),
             storage_class: if let Some(s) = headers.remove("x-oss-storage-class") {
-                if let Ok(v) = s.try_into() {
-                    Some(v)
-                } else {
-                    None
-                }
+                s.try_into().ok()
             } else {
                 None
             },
             object_type: if let Some(s) = headers.remove("x-oss-object-type") {
-                if let Ok(v) = s.try_into() {
-                    Some(v)
-                } else {
-                    None
-                }
+                s.try_into().ok()
             } else {
                 None
             },
@@ -1160,19 +1299,19 @@ pub(crate) fn build_copy_object_request(
     options: &Option<CopyObjectOptions>,
 ) -> Result<OssRequest> {
     if !validate_bucket_name(source_bucket_name) {
-        return Err(Error::Other(format!("invalid source bucket name: {}", source_bucket_name)));
+        return Err(Error::InvalidBucketName(source_bucket_name.to_string()));
     }
 
     if !validate_object_key(source_object_key) {
-        return Err(Error::Other(format!("invalid source object key: {}", source_object_key)));
+        return Err(Error::InvalidObjectKey(source_object_key.to_string()));
     }
 
     if !validate_bucket_name(dest_bucket_name) {
-        return Err(Error::Other(format!("invalid destination bucket name: {}", dest_bucket_name)));
+        return Err(Error::InvalidBucketName(dest_bucket_name.to_string()));
     }
 
     if !validate_object_key(dest_object_key) {
-        return Err(Error::Other(format!("invalid destination object key: {}", dest_object_key)));
+        return Err(Error::InvalidObjectKey(dest_object_key.to_string()));
     }
 
     let mut request = OssRequest::new()
@@ -1188,13 +1327,13 @@ pub(crate) fn build_copy_object_request(
         // validate metadata key and taggings
         for (k, _) in options.metadata.iter() {
             if !validate_meta_key(k) {
-                return Err(Error::Other(format!("invalid metadata key: {}", k)));
+                return Err(Error::InvalidArgument(format!("invalid metadata key: {}", k)));
             }
         }
 
         for (k, v) in options.tags.iter() {
             if !validate_tag_key(k) || !validate_tag_value(v) {
-                return Err(Error::Other(format!("invalid tagging data: {}={}", k, v)));
+                return Err(Error::InvalidArgument(format!("invalid tagging data: {}={}", k, v)));
             }
         }
 
@@ -1260,18 +1399,22 @@ pub(crate) fn build_copy_object_request(
 
 pub(crate) fn build_get_object_request(bucket_name: &str, object_key: &str, options: &Option<GetObjectOptions>) -> Result<OssRequest> {
     if !validate_bucket_name(bucket_name) {
-        return Err(Error::Other(format!("invalid bucket name: {}", bucket_name)));
+        return Err(Error::InvalidBucketName(bucket_name.to_string()));
     }
 
     if !validate_object_key(object_key) {
-        return Err(Error::Other(format!("invalid object key: {}", object_key)));
+        return Err(Error::InvalidObjectKey(object_key.to_string()));
     }
 
     let mut request = OssRequest::new().method(RequestMethod::Get).bucket(bucket_name).object(object_key);
 
     if let Some(options) = options {
-        if let Some(s) = &options.range {
-            request = request.add_header("range", s);
+        if let Some(r) = &options.range {
+            request = request.add_header("range", r.to_string());
+        }
+
+        if options.standard_range_behavior {
+            request = request.add_header("x-oss-range-behavior", "standard");
         }
 
         if let Some(s) = &options.if_modified_since {
@@ -1317,6 +1460,12 @@ pub(crate) fn build_get_object_request(bucket_name: &str, object_key: &str, opti
         if let Some(s) = &options.version_id {
             request = request.add_query("versionId", s);
         }
+
+        if let Some(s) = &options.process {
+            request = request.add_query("x-oss-process", s);
+        }
+
+        request = apply_request_payer(request, &options.request_payer);
     }
 
     Ok(request)
@@ -1324,11 +1473,11 @@ pub(crate) fn build_get_object_request(bucket_name: &str, object_key: &str, opti
 
 pub(crate) fn build_head_object_request(bucket_name: &str, object_key: &str, options: &Option<HeadObjectOptions>) -> Result<OssRequest> {
     if !validate_bucket_name(bucket_name) {
-        return Err(Error::Other(format!("invalid bucket name: {}", bucket_name)));
+        return Err(Error::InvalidBucketName(bucket_name.to_string()));
     }
 
     if !validate_object_key(object_key) {
-        return Err(Error::Other(format!("invalid object key: {}", object_key)));
+        return Err(Error::InvalidObjectKey(object_key.to_string()));
     }
 
     let mut request = OssRequest::new().method(RequestMethod::Head).bucket(bucket_name).object(object_key);
@@ -1353,6 +1502,8 @@ pub(crate) fn build_head_object_request(bucket_name: &str, object_key: &str, opt
         if let Some(s) = &options.version_id {
             request = request.add_query("versionId", s);
         }
+
+        request = apply_request_payer(request, &options.request_payer);
     }
 
     Ok(request)
@@ -1577,7 +1728,7 @@ where
     S: AsRef<str>,
 {
     if !validate_bucket_name(bucket_name) {
-        return Err(Error::Other(format!("invalid bucket name: {}", bucket_name)));
+        return Err(Error::InvalidBucketName(bucket_name.to_string()));
     }
 
     let mut request = OssRequest::new()
@@ -1592,7 +1743,7 @@ where
     };
 
     if items_len > common::DELETE_MULTIPLE_OBJECTS_LIMIT {
-        return Err(Error::Other(format!(
+        return Err(Error::InvalidArgument(format!(
             "{} exceeds the items count limits while deleting multiple objects",
             items_len
         )));
@@ -1917,7 +2068,10 @@ impl TryFrom<&str> for RestoreJobTier {
             "Standard" => Ok(Self::Standard),
             "Expedited" => Ok(Self::Expedited),
             "Bulk" => Ok(Self::Bulk),
-            _ => Err(Error::Other(format!("invalid job tier: {}", s))),
+            _ => Err(Error::InvalidEnumValue {
+                name: "RestoreJobTier",
+                value: s.to_string(),
+            }),
         }
     }
 }
@@ -2006,11 +2160,11 @@ impl From<HashMap<String, String>> for RestoreObjectResult {
 
 pub(crate) fn build_restore_object_request(bucket_name: &str, object_key: &str, config: RestoreObjectRequest) -> Result<OssRequest> {
     if !validate_bucket_name(bucket_name) {
-        return Err(Error::Other(format!("invalid bucket name: {}", bucket_name)));
+        return Err(Error::InvalidBucketName(bucket_name.to_string()));
     }
 
     if !validate_object_key(object_key) {
-        return Err(Error::Other(format!("invalid object key: {}", object_key)));
+        return Err(Error::InvalidObjectKey(object_key.to_string()));
     }
 
     let mut request = OssRequest::new()
@@ -2031,7 +2185,16 @@ pub(crate) fn build_restore_object_request(bucket_name: &str, object_key: &str,
 
 #[cfg(test)]
 mod test_object_common {
-    use crate::object_common::CallbackBodyParameter;
+    use std::collections::HashMap;
+
+    use crate::{
+        common::{ContentRange, RequestPayer},
+        object_common::{
+            build_get_object_request, build_put_object_request, build_put_object_stream_request, CallbackBodyParameter, GetObjectOptionsBuilder,
+            GetObjectResult, PutObjectOptionsBuilder,
+        },
+        request::{RequestBody, StreamBody},
+    };
 
     #[cfg(feature = "serde-support")]
     use super::PutObjectResult;
@@ -2051,6 +2214,82 @@ mod test_object_common {
         );
     }
 
+    #[test]
+    fn test_request_payer_header() {
+        let options = GetObjectOptionsBuilder::new().request_payer(RequestPayer::Requester).build();
+        let request = build_get_object_request("test-bucket", "a/b.txt", &Some(options)).unwrap();
+        assert_eq!(Some(&"requester".to_string()), request.headers.get("x-oss-request-payer"));
+
+        let options = GetObjectOptionsBuilder::new().request_payer(RequestPayer::BucketOwner).build();
+        let request = build_get_object_request("test-bucket", "a/b.txt", &Some(options)).unwrap();
+        assert!(!request.headers.contains_key("x-oss-request-payer"));
+    }
+
+    #[test]
+    fn test_get_object_process() {
+        let process = crate::process_common::ImageProcess::new().rotate(90);
+        let options = GetObjectOptionsBuilder::new().process(process).build();
+        let request = build_get_object_request("test-bucket", "a/b.jpg", &Some(options)).unwrap();
+        assert_eq!(Some(&"image/rotate,90".to_string()), request.query.get("x-oss-process"));
+    }
+
+    #[test]
+    fn test_put_object_stream_body_content_length() {
+        let body = StreamBody::Stream(reqwest::Body::from("hello"));
+        let request = build_put_object_stream_request("test-bucket", "a/b.txt", body, Some(5), &None).unwrap();
+        assert_eq!(Some(&"5".to_string()), request.headers.get("content-length"));
+        assert!(request.stream_body.is_some());
+
+        // unknown length is sent with chunked transfer encoding
+        let body = StreamBody::Stream(reqwest::Body::from("hello"));
+        let request = build_put_object_stream_request("test-bucket", "a/b.txt", body, None, &None).unwrap();
+        assert!(!request.headers.contains_key("content-length"));
+
+        let body = StreamBody::Stream(reqwest::Body::from("hello"));
+        assert!(build_put_object_stream_request("test-bucket", "a/b.txt", body, Some(5_368_709_121), &None).is_err());
+    }
+
+    #[test]
+    fn test_put_object_conditional_headers() {
+        let options = PutObjectOptionsBuilder::new().if_none_match("*").build();
+        let request = build_put_object_request("test-bucket", "a/b.txt", RequestBody::Empty, &Some(options)).unwrap();
+        assert_eq!(Some(&"*".to_string()), request.headers.get("if-none-match"));
+        assert!(!request.headers.contains_key("if-match"));
+
+        let options = PutObjectOptionsBuilder::new().if_match("5B3C1A2E053D763E1B002CC607C5A0FE").build();
+        let request = build_put_object_request("test-bucket", "a/b.txt", RequestBody::Empty, &Some(options)).unwrap();
+        assert_eq!(Some(&"5B3C1A2E053D763E1B002CC607C5A0FE".to_string()), request.headers.get("if-match"));
+    }
+
+    #[test]
+    fn test_get_object_result_from_headers() {
+        let headers = HashMap::from([
+            ("content-range".to_string(), "bytes 0-9/44".to_string()),
+            ("content-length".to_string(), "10".to_string()),
+            ("content-type".to_string(), "text/plain".to_string()),
+            ("etag".to_string(), "\"5B3C1A2E053D763E1B002CC607C5A0FE\"".to_string()),
+            ("x-oss-hash-crc64ecma".to_string(), "3161812495027030000".to_string()),
+            ("x-oss-version-id".to_string(), "v1".to_string()),
+            ("x-oss-meta-author".to_string(), "foo".to_string()),
+        ]);
+
+        let result = GetObjectResult::from(headers);
+        assert_eq!(
+            Some(ContentRange {
+                start: 0,
+                end: 9,
+                total: Some(44)
+            }),
+            result.content_range
+        );
+        assert_eq!(10, result.metadata.content_length);
+        assert_eq!("5B3C1A2E053D763E1B002CC607C5A0FE", result.metadata.etag);
+        assert_eq!(Some("text/plain".to_string()), result.metadata.content_type);
+        assert_eq!(Some(3161812495027030000), result.metadata.hash_crc64ecma);
+        assert_eq!(Some("v1".to_string()), result.metadata.version_id);
+        assert_eq!(Some(&"foo".to_string()), result.metadata.metadata.get("x-oss-meta-author"));
+    }
+
     #[test]
     #[cfg(feature = "serde-support")]
     fn test_put_object_result_serde() {
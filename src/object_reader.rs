@@ -0,0 +1,328 @@
+//! An object reader implementing `AsyncRead` and `AsyncSeek`.
+//!
+//! The length and ETag of the object are retrieved by `head_object` when the reader is created.
+//! Data is read with ranged get object requests, at least `read_ahead_size` bytes each time,
+//! and `If-Match` is sent with the ETag so that an overwrite during reading is detected.
+
+use std::{
+    future::Future,
+    io::SeekFrom,
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll},
+};
+
+use tokio::io::{AsyncRead, AsyncSeek, ReadBuf};
+
+use crate::{
+    object::ObjectOperations,
+    object_reader_common::{
+        build_head_options, build_range_options, calculate_fetch_end, calculate_seek_position, validate_object_reader_params, ObjectReaderOptions,
+    },
+    Client, Result,
+};
+
+type BoxedFuture<T> = Pin<Box<dyn Future<Output = Result<T>> + Send>>;
+
+/// Read an object with random access. See module level document for details.
+///
+/// # Examples
+///
+/// ```no_run
+/// use ali_oss_rs::object_reader::ObjectReader;
+/// use tokio::io::{AsyncReadExt, AsyncSeekExt};
+///
+/// async fn read_footer(client: &ali_oss_rs::Client) -> ali_oss_rs::Result<Vec<u8>> {
+///     let mut reader = ObjectReader::new(client, "example-bucket", "path/to/data.parquet", None).await?;
+///     reader.seek(std::io::SeekFrom::End(-8)).await?;
+///     let mut footer = vec![0u8; 8];
+///     reader.read_exact(&mut footer).await?;
+///     Ok(footer)
+/// }
+/// ```
+pub struct ObjectReader {
+    client: Arc<Client>,
+    bucket_name: String,
+    object_key: String,
+    options: ObjectReaderOptions,
+    len: u64,
+    etag: String,
+    pos: u64,
+
+    /// Buffered data and the position of its first byte in the object
+    buffer: Vec<u8>,
+    buffer_start: u64,
+
+    /// In-flight ranged get object request and its start position
+    fetching: Option<(u64, BoxedFuture<Vec<u8>>)>,
+}
+
+impl ObjectReader {
+    /// Create a new object reader. The length and ETag of the object are retrieved by `head_object`.
+    /// The client is cloned so the reader can be moved around freely.
+    pub async fn new<S1, S2>(client: &Client, bucket_name: S1, object_key: S2, options: Option<ObjectReaderOptions>) -> Result<Self>
+    where
+        S1: AsRef<str>,
+        S2: AsRef<str>,
+    {
+        let bucket_name = bucket_name.as_ref();
+        let object_key = object_key.as_ref();
+        let options = options.unwrap_or_default();
+
+        validate_object_reader_params(bucket_name, object_key, &options)?;
+
+        let metadata = client.head_object(bucket_name, object_key, Some(build_head_options(&options))).await?;
+
+        Ok(Self {
+            client: Arc::new(client.clone_to(&client.region, &client.endpoint)),
+            bucket_name: bucket_name.to_string(),
+            object_key: object_key.to_string(),
+            options,
+            len: metadata.content_length,
+            etag: metadata.etag,
+            pos: 0,
+            buffer: vec![],
+            buffer_start: 0,
+            fetching: None,
+        })
+    }
+
+    /// The length of the object
+    pub fn len(&self) -> u64 {
+        self.len
+    }
+
+    /// Returns `true` if the object is empty
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The ETag of the object when the reader was created
+    pub fn etag(&self) -> &str {
+        &self.etag
+    }
+
+    fn start_fetching(&mut self, buf_size: usize) {
+        let client = self.client.clone();
+        let bucket_name = self.bucket_name.clone();
+        let object_key = self.object_key.clone();
+        let start = self.pos;
+        let end = calculate_fetch_end(start, self.len, self.options.read_ahead_size, buf_size);
+        let options = build_range_options(&self.options, &self.etag, start, end);
+
+        self.fetching = Some((
+            start,
+            Box::pin(async move {
+                let (_, data) = client.get_object_to_buffer(bucket_name, object_key, Some(options)).await?;
+                Ok(data)
+            }),
+        ));
+    }
+}
+
+impl AsyncRead for ObjectReader {
+    fn poll_read(self: Pin<&mut Self>, cx: &mut Context<'_>, buf: &mut ReadBuf<'_>) -> Poll<std::io::Result<()>> {
+        let this = self.get_mut();
+
+        loop {
+            if this.pos >= this.len || buf.remaining() == 0 {
+                return Poll::Ready(Ok(()));
+            }
+
+            // serve from the buffered data
+            let buffer_end = this.buffer_start + this.buffer.len() as u64;
+            if this.pos >= this.buffer_start && this.pos < buffer_end {
+                let offset = (this.pos - this.buffer_start) as usize;
+                let n = buf.remaining().min(this.buffer.len() - offset);
+                buf.put_slice(&this.buffer[offset..offset + n]);
+                this.pos += n as u64;
+                return Poll::Ready(Ok(()));
+            }
+
+            match &mut this.fetching {
+                None => this.start_fetching(buf.remaining()),
+                Some((start, fut)) => {
+                    let start = *start;
+                    match fut.as_mut().poll(cx) {
+                        Poll::Pending => return Poll::Pending,
+                        Poll::Ready(ret) => {
+                            this.fetching = None;
+                            let data = ret.map_err(std::io::Error::other)?;
+                            if data.is_empty() {
+                                return Poll::Ready(Err(std::io::Error::new(std::io::ErrorKind::UnexpectedEof, "ranged get object returns no data")));
+                            }
+                            this.buffer = data;
+                            this.buffer_start = start;
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl AsyncSeek for ObjectReader {
+    fn start_seek(self: Pin<&mut Self>, position: SeekFrom) -> std::io::Result<()> {
+        let this = self.get_mut();
+        this.pos = calculate_seek_position(this.pos, this.len, position)?;
+
+        // the in-flight request is useless if it does not start at the new position
+        if matches!(&this.fetching, Some((start, _)) if *start != this.pos) {
+            this.fetching = None;
+        }
+
+        Ok(())
+    }
+
+    fn poll_complete(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<std::io::Result<u64>> {
+        Poll::Ready(Ok(self.pos))
+    }
+}
+
+#[cfg(test)]
+mod test_object_reader_async {
+    use std::{io::SeekFrom, time::Duration};
+
+    use tokio::io::{AsyncReadExt, AsyncSeekExt};
+
+    use crate::{
+        error::Error,
+        mock_server::{self, MockRequest, MockResponse},
+        object_reader_common::ObjectReaderOptionsBuilder,
+        Client, ClientBuilder,
+    };
+
+    use super::ObjectReader;
+
+    fn mock_client(port: u16) -> Client {
+        ClientBuilder::new("ak", "sk", format!("http://127.0.0.1:{}", port))
+            .region("cn-hangzhou")
+            .path_style(true)
+            .build()
+            .unwrap()
+    }
+
+    fn test_data() -> Vec<u8> {
+        (0..1000).map(|i| (i % 251) as u8).collect()
+    }
+
+    /// Respond like OSS does for head object and ranged get object, `etag` is the ETag of the current object
+    fn respond(request: &MockRequest, etag: &str) -> MockResponse {
+        let data = test_data();
+        let etag = format!("\"{}\"", etag);
+
+        if request.method == "HEAD" {
+            return MockResponse::new(200).header("Content-Length", data.len().to_string()).header("ETag", etag);
+        }
+
+        if request.headers.get("if-match") != Some(&etag) {
+            return MockResponse::new(412).body(
+                "<?xml version=\"1.0\" encoding=\"UTF-8\"?><Error><Code>PreconditionFailed</Code><Message>At least one of the pre-conditions you specified did not hold.</Message><RequestId>1234</RequestId></Error>",
+            );
+        }
+
+        let (start, end) = request.headers["range"].strip_prefix("bytes=").unwrap().split_once('-').unwrap();
+        let (start, end) = (start.parse::<usize>().unwrap(), end.parse::<usize>().unwrap());
+
+        MockResponse::new(206)
+            .header("Content-Range", format!("bytes {}-{}/{}", start, end, data.len()))
+            .header("ETag", etag)
+            .body(&data[start..=end])
+    }
+
+    fn handle(request: &MockRequest) -> MockResponse {
+        respond(request, "ETAG1")
+    }
+
+    #[tokio::test]
+    async fn test_object_reader_read_and_seek() {
+        let (port, server) = mock_server::start(4, handle);
+        let client = mock_client(port);
+        let data = test_data();
+        let options = ObjectReaderOptionsBuilder::new().read_ahead_size(100).build();
+
+        let mut reader = ObjectReader::new(&client, "example-bucket", "a.bin", Some(options)).await.unwrap();
+        assert_eq!(1000, reader.len());
+        assert_eq!("ETAG1", reader.etag());
+
+        let mut buf = vec![0u8; 10];
+        reader.read_exact(&mut buf).await.unwrap();
+        assert_eq!(&data[..10], &buf[..]);
+
+        // served from the buffered data
+        reader.read_exact(&mut buf).await.unwrap();
+        assert_eq!(&data[10..20], &buf[..]);
+
+        assert_eq!(500, reader.seek(SeekFrom::Start(500)).await.unwrap());
+        reader.read_exact(&mut buf).await.unwrap();
+        assert_eq!(&data[500..510], &buf[..]);
+
+        reader.seek(SeekFrom::End(-5)).await.unwrap();
+        let mut rest = vec![];
+        reader.read_to_end(&mut rest).await.unwrap();
+        assert_eq!(&data[995..], &rest[..]);
+
+        let requests = tokio::task::spawn_blocking(move || server.join().unwrap()).await.unwrap();
+        assert_eq!("HEAD", requests[0].method);
+        assert_eq!(
+            vec!["bytes=0-99", "bytes=500-599", "bytes=995-999"],
+            requests[1..].iter().map(|r| r.headers["range"].as_str()).collect::<Vec<_>>()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_object_reader_cancel_fetching_on_seek() {
+        let (port, server) = mock_server::start(3, |request: &MockRequest| {
+            let response = handle(request);
+            if request.headers.get("range").map(|s| s.as_str()) == Some("bytes=0-99") {
+                response.delay(Duration::from_millis(500))
+            } else {
+                response
+            }
+        });
+        let client = mock_client(port);
+        let data = test_data();
+        let options = ObjectReaderOptionsBuilder::new().read_ahead_size(100).build();
+
+        let mut reader = ObjectReader::new(&client, "example-bucket", "a.bin", Some(options)).await.unwrap();
+
+        // leave the request in flight
+        let mut buf = vec![0u8; 10];
+        assert!(tokio::time::timeout(Duration::from_millis(100), reader.read_exact(&mut buf)).await.is_err());
+
+        reader.seek(SeekFrom::Start(500)).await.unwrap();
+        reader.read_exact(&mut buf).await.unwrap();
+        assert_eq!(&data[500..510], &buf[..]);
+
+        let requests = tokio::task::spawn_blocking(move || server.join().unwrap()).await.unwrap();
+        assert_eq!(
+            vec!["bytes=0-99", "bytes=500-599"],
+            requests[1..].iter().map(|r| r.headers["range"].as_str()).collect::<Vec<_>>()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_object_reader_precondition_failed() {
+        // the object is overwritten after the reader was created
+        let (port, server) = mock_server::start(2, |request: &MockRequest| {
+            if request.method == "HEAD" {
+                respond(request, "ETAG1")
+            } else {
+                respond(request, "ETAG2")
+            }
+        });
+        let client = mock_client(port);
+
+        let mut reader = ObjectReader::new(&client, "example-bucket", "a.bin", None).await.unwrap();
+
+        let mut buf = vec![0u8; 10];
+        let err = reader.read_exact(&mut buf).await.unwrap_err();
+        assert!(matches!(
+            err.get_ref().and_then(|e| e.downcast_ref::<Error>()),
+            Some(Error::PreconditionFailed(_))
+        ));
+
+        let requests = tokio::task::spawn_blocking(move || server.join().unwrap()).await.unwrap();
+        assert_eq!(Some(&"\"ETAG1\"".to_string()), requests[1].headers.get("if-match"));
+    }
+}
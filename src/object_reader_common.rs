@@ -0,0 +1,172 @@
+//! Common types for object reader which reads an object with ranged get object requests.
+
+use std::io::SeekFrom;
+
+use crate::{
+    common::RequestPayer,
+    error::Error,
+    object_common::{GetObjectOptions, GetObjectOptionsBuilder, HeadObjectOptions, HeadObjectOptionsBuilder},
+    util::{validate_bucket_name, validate_object_key},
+    Result,
+};
+
+/// Default read ahead size of object reader: 1MB
+pub const DEFAULT_OBJECT_READER_READ_AHEAD_SIZE: usize = 1024 * 1024;
+
+/// Options for object reader
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde-support", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde-camelcase", serde(rename_all = "camelCase"))]
+pub struct ObjectReaderOptions {
+    /// The minimal size of each ranged get object request. Default is 1MB.
+    /// If the reading buffer is larger than this value, the size of the reading buffer is used.
+    pub read_ahead_size: usize,
+
+    /// Read the specified version of the object
+    pub version_id: Option<String>,
+
+    pub request_payer: Option<RequestPayer>,
+}
+
+impl Default for ObjectReaderOptions {
+    fn default() -> Self {
+        Self {
+            read_ahead_size: DEFAULT_OBJECT_READER_READ_AHEAD_SIZE,
+            version_id: None,
+            request_payer: None,
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct ObjectReaderOptionsBuilder {
+    options: ObjectReaderOptions,
+}
+
+impl ObjectReaderOptionsBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn read_ahead_size(mut self, read_ahead_size: usize) -> Self {
+        self.options.read_ahead_size = read_ahead_size;
+        self
+    }
+
+    pub fn version_id(mut self, version_id: impl Into<String>) -> Self {
+        self.options.version_id = Some(version_id.into());
+        self
+    }
+
+    pub fn request_payer(mut self, request_payer: RequestPayer) -> Self {
+        self.options.request_payer = Some(request_payer);
+        self
+    }
+
+    pub fn build(self) -> ObjectReaderOptions {
+        self.options
+    }
+}
+
+pub(crate) fn validate_object_reader_params(bucket_name: &str, object_key: &str, options: &ObjectReaderOptions) -> Result<()> {
+    if !validate_bucket_name(bucket_name) {
+        return Err(Error::InvalidBucketName(bucket_name.to_string()));
+    }
+
+    if !validate_object_key(object_key) {
+        return Err(Error::InvalidObjectKey(object_key.to_string()));
+    }
+
+    if options.read_ahead_size == 0 {
+        return Err(Error::InvalidArgument(
+            "invalid read ahead size. read ahead size must be greater than 0".to_string(),
+        ));
+    }
+
+    Ok(())
+}
+
+pub(crate) fn build_head_options(options: &ObjectReaderOptions) -> HeadObjectOptions {
+    let mut builder = HeadObjectOptionsBuilder::new();
+
+    if let Some(s) = &options.version_id {
+        builder = builder.version_id(s);
+    }
+
+    if let Some(p) = options.request_payer {
+        builder = builder.request_payer(p);
+    }
+
+    builder.build()
+}
+
+/// Build options for getting bytes in range `[start, end)`. `end` is greater than `start`.
+/// `If-Match` is set to the ETag so that an overwrite during reading is detected.
+pub(crate) fn build_range_options(options: &ObjectReaderOptions, etag: &str, start: u64, end: u64) -> GetObjectOptions {
+    let mut builder = GetObjectOptionsBuilder::new()
+        .range(start..=end - 1)
+        .standard_range_behavior(true)
+        .if_match(format!("\"{}\"", etag));
+
+    if let Some(s) = &options.version_id {
+        builder = builder.version_id(s);
+    }
+
+    if let Some(p) = options.request_payer {
+        builder = builder.request_payer(p);
+    }
+
+    builder.build()
+}
+
+/// Calculate the end (exclusive) of next ranged get object request which starts at `pos`.
+pub(crate) fn calculate_fetch_end(pos: u64, len: u64, read_ahead_size: usize, buf_size: usize) -> u64 {
+    let size = read_ahead_size.max(buf_size) as u64;
+    len.min(pos.saturating_add(size))
+}
+
+/// Calculate the new position after seeking. Seeking beyond the end is allowed, reading there returns EOF.
+pub(crate) fn calculate_seek_position(pos: u64, len: u64, seek: SeekFrom) -> std::io::Result<u64> {
+    let new_pos = match seek {
+        SeekFrom::Start(n) => Some(n),
+        SeekFrom::End(n) => len.checked_add_signed(n),
+        SeekFrom::Current(n) => pos.checked_add_signed(n),
+    };
+
+    new_pos.ok_or_else(|| std::io::Error::new(std::io::ErrorKind::InvalidInput, "invalid seek to a negative or overflowing position"))
+}
+
+#[cfg(test)]
+mod test_object_reader_common {
+    use std::io::SeekFrom;
+
+    use crate::common::ByteRange;
+
+    use super::{build_range_options, calculate_fetch_end, calculate_seek_position, ObjectReaderOptionsBuilder};
+
+    #[test]
+    fn test_range_options() {
+        let options = ObjectReaderOptionsBuilder::new().version_id("v1").build();
+        let get_options = build_range_options(&options, "ABC", 100, 200);
+        assert_eq!(Some(ByteRange::FromTo(100, 199)), get_options.range);
+        assert!(get_options.standard_range_behavior);
+        assert_eq!(Some("\"ABC\"".to_string()), get_options.if_match);
+        assert_eq!(Some("v1".to_string()), get_options.version_id);
+    }
+
+    #[test]
+    fn test_fetch_end() {
+        assert_eq!(1024, calculate_fetch_end(0, 4096, 1024, 100));
+        assert_eq!(2048, calculate_fetch_end(0, 4096, 1024, 2048));
+        assert_eq!(4096, calculate_fetch_end(4000, 4096, 1024, 100));
+    }
+
+    #[test]
+    fn test_seek_position() {
+        assert_eq!(10, calculate_seek_position(5, 100, SeekFrom::Start(10)).unwrap());
+        assert_eq!(90, calculate_seek_position(5, 100, SeekFrom::End(-10)).unwrap());
+        assert_eq!(2, calculate_seek_position(5, 100, SeekFrom::Current(-3)).unwrap());
+        assert_eq!(120, calculate_seek_position(5, 100, SeekFrom::End(20)).unwrap());
+        assert!(calculate_seek_position(5, 100, SeekFrom::Current(-6)).is_err());
+    }
+}
@@ -0,0 +1,432 @@
+//! An object writer implementing `AsyncWrite`.
+//!
+//! Data written to the writer is buffered into parts. If the total size is not greater than one part,
+//! the object is created by a single put object request when the writer is shut down.
+//! Otherwise a multipart upload is initiated and parts are uploaded when they are full,
+//! and the multipart upload is completed when the writer is shut down.
+//!
+//! If any request fails, the writer turns into failed state: later writes and shutdown return error,
+//! and the multipart upload (if initiated) is aborted when the writer is dropped.
+//! If the writer is dropped before being shut down, the multipart upload (if initiated) is aborted.
+
+use std::{
+    future::Future,
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll},
+};
+
+use tokio::io::AsyncWrite;
+
+use crate::{
+    multipart::MultipartUploadsOperations,
+    multipart_common::CompleteMultipartUploadRequest,
+    object::ObjectOperations,
+    object_writer_common::{
+        build_abort_options, build_complete_options, build_initiate_options, build_upload_part_params, validate_object_writer_params, ObjectWriterOptions,
+    },
+    Client, Result,
+};
+
+type BoxedFuture<T> = Pin<Box<dyn Future<Output = Result<T>> + Send>>;
+
+enum WriterState {
+    Idle,
+    Initiating(BoxedFuture<String>),
+    Uploading(BoxedFuture<(u32, String)>),
+    Completing(BoxedFuture<()>),
+    Finished,
+    Failed,
+}
+
+/// Write data to an object. See module level document for details.
+///
+/// # Examples
+///
+/// ```no_run
+/// use ali_oss_rs::object_writer::ObjectWriter;
+/// use tokio::io::AsyncWriteExt;
+///
+/// async fn copy_to_oss(client: &ali_oss_rs::Client) -> ali_oss_rs::Result<()> {
+///     let mut writer = ObjectWriter::new(client, "example-bucket", "path/to/object.txt", None)?;
+///     let mut file = tokio::fs::File::open("/path/to/local/file.txt").await?;
+///     tokio::io::copy(&mut file, &mut writer).await?;
+///     writer.shutdown().await?;
+///     Ok(())
+/// }
+/// ```
+pub struct ObjectWriter {
+    client: Arc<Client>,
+    bucket_name: String,
+    object_key: String,
+    options: ObjectWriterOptions,
+    buffer: Vec<u8>,
+    upload_id: Option<String>,
+    parts: Vec<(u32, String)>,
+    state: WriterState,
+}
+
+impl ObjectWriter {
+    /// Create a new object writer. The client is cloned so the writer can be moved around freely.
+    pub fn new<S1, S2>(client: &Client, bucket_name: S1, object_key: S2, options: Option<ObjectWriterOptions>) -> Result<Self>
+    where
+        S1: AsRef<str>,
+        S2: AsRef<str>,
+    {
+        let bucket_name = bucket_name.as_ref();
+        let object_key = object_key.as_ref();
+        let options = options.unwrap_or_default();
+
+        validate_object_writer_params(bucket_name, object_key, &options)?;
+
+        Ok(Self {
+            client: Arc::new(client.clone_to(&client.region, &client.endpoint)),
+            bucket_name: bucket_name.to_string(),
+            object_key: object_key.to_string(),
+            buffer: vec![],
+            options,
+            upload_id: None,
+            parts: vec![],
+            state: WriterState::Idle,
+        })
+    }
+
+    /// The upload id if the data is being uploaded with multipart uploads.
+    pub fn upload_id(&self) -> Option<&str> {
+        self.upload_id.as_deref()
+    }
+
+    fn start_initiating(&mut self) {
+        let client = self.client.clone();
+        let bucket_name = self.bucket_name.clone();
+        let object_key = self.object_key.clone();
+        let options = build_initiate_options(&self.options);
+
+        self.state = WriterState::Initiating(Box::pin(async move {
+            let ret = client.initiate_multipart_uploads(bucket_name, object_key, options).await?;
+            Ok(ret.upload_id)
+        }));
+    }
+
+    fn start_uploading(&mut self, data: Vec<u8>) {
+        let client = self.client.clone();
+        let bucket_name = self.bucket_name.clone();
+        let object_key = self.object_key.clone();
+        let part_number = self.parts.len() as u32 + 1;
+        let params = build_upload_part_params(&self.options, self.upload_id.as_deref().unwrap_or_default(), part_number);
+
+        self.state = WriterState::Uploading(Box::pin(async move {
+            let ret = client.upload_part_from_buffer(bucket_name, object_key, data, params).await?;
+            Ok((part_number, ret.etag))
+        }));
+    }
+
+    fn start_completing(&mut self) {
+        let client = self.client.clone();
+        let bucket_name = self.bucket_name.clone();
+        let object_key = self.object_key.clone();
+        let data = std::mem::take(&mut self.buffer);
+
+        match &self.upload_id {
+            None => {
+                let options = self.options.put_options.clone();
+                self.state = WriterState::Completing(Box::pin(async move {
+                    client.put_object_from_buffer(bucket_name, object_key, data, options).await?;
+                    Ok(())
+                }));
+            }
+            Some(upload_id) => {
+                let request = CompleteMultipartUploadRequest {
+                    upload_id: upload_id.clone(),
+                    parts: std::mem::take(&mut self.parts),
+                };
+                let options = build_complete_options(&self.options);
+                self.state = WriterState::Completing(Box::pin(async move {
+                    client.complete_multipart_uploads(bucket_name, object_key, request, options).await?;
+                    Ok(())
+                }));
+            }
+        }
+    }
+
+    /// Drive the in-flight request (if any) to finish. Returns `Ready(Ok(()))` if the writer is idle or finished.
+    fn poll_pending(&mut self, cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        let ret = match &mut self.state {
+            WriterState::Idle | WriterState::Finished => return Poll::Ready(Ok(())),
+            WriterState::Failed => return Poll::Ready(Err(std::io::Error::other("object writer is in failed state"))),
+            WriterState::Initiating(fut) => match fut.as_mut().poll(cx) {
+                Poll::Pending => return Poll::Pending,
+                Poll::Ready(r) => r.map(|upload_id| {
+                    self.upload_id = Some(upload_id);
+                    WriterState::Idle
+                }),
+            },
+            WriterState::Uploading(fut) => match fut.as_mut().poll(cx) {
+                Poll::Pending => return Poll::Pending,
+                Poll::Ready(r) => r.map(|part| {
+                    self.parts.push(part);
+                    WriterState::Idle
+                }),
+            },
+            WriterState::Completing(fut) => match fut.as_mut().poll(cx) {
+                Poll::Pending => return Poll::Pending,
+                Poll::Ready(r) => r.map(|_| WriterState::Finished),
+            },
+        };
+
+        match ret {
+            Ok(state) => {
+                self.state = state;
+                Poll::Ready(Ok(()))
+            }
+            Err(e) => {
+                self.state = WriterState::Failed;
+                Poll::Ready(Err(std::io::Error::other(e)))
+            }
+        }
+    }
+}
+
+impl AsyncWrite for ObjectWriter {
+    fn poll_write(self: Pin<&mut Self>, cx: &mut Context<'_>, buf: &[u8]) -> Poll<std::io::Result<usize>> {
+        let this = self.get_mut();
+
+        if buf.is_empty() {
+            return Poll::Ready(Ok(0));
+        }
+
+        loop {
+            match this.poll_pending(cx) {
+                Poll::Pending => return Poll::Pending,
+                Poll::Ready(Err(e)) => return Poll::Ready(Err(e)),
+                Poll::Ready(Ok(())) => {}
+            }
+
+            if let WriterState::Finished = this.state {
+                return Poll::Ready(Err(std::io::Error::other("object writer has been shut down")));
+            }
+
+            // the buffer is full and there are more data, so it must be uploaded with multipart uploads
+            if this.buffer.len() >= this.options.part_size {
+                if this.upload_id.is_none() {
+                    this.start_initiating();
+                } else {
+                    let rest = this.buffer.split_off(this.options.part_size);
+                    let data = std::mem::replace(&mut this.buffer, rest);
+                    this.start_uploading(data);
+                }
+                continue;
+            }
+
+            let n = buf.len().min(this.options.part_size - this.buffer.len());
+            this.buffer.extend_from_slice(&buf[..n]);
+            return Poll::Ready(Ok(n));
+        }
+    }
+
+    /// Wait for the in-flight part uploading. Buffered data which is not enough for a part is kept until shut down.
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        self.get_mut().poll_pending(cx)
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        let this = self.get_mut();
+
+        loop {
+            match this.poll_pending(cx) {
+                Poll::Pending => return Poll::Pending,
+                Poll::Ready(Err(e)) => return Poll::Ready(Err(e)),
+                Poll::Ready(Ok(())) => {}
+            }
+
+            match this.state {
+                WriterState::Finished => return Poll::Ready(Ok(())),
+                WriterState::Idle if this.upload_id.is_some() && !this.buffer.is_empty() => {
+                    let data = std::mem::take(&mut this.buffer);
+                    this.start_uploading(data);
+                }
+                _ => this.start_completing(),
+            }
+        }
+    }
+}
+
+impl Drop for ObjectWriter {
+    fn drop(&mut self) {
+        if let WriterState::Finished = self.state {
+            return;
+        }
+
+        let upload_id = match self.upload_id.take() {
+            Some(s) => s,
+            None => return,
+        };
+
+        let client = self.client.clone();
+        let bucket_name = std::mem::take(&mut self.bucket_name);
+        let object_key = std::mem::take(&mut self.object_key);
+        let options = build_abort_options(&self.options);
+
+        match tokio::runtime::Handle::try_current() {
+            Ok(handle) => {
+                handle.spawn(async move {
+                    if let Err(e) = client.abort_multipart_uploads(&bucket_name, &object_key, &upload_id, options).await {
+                        log::error!("abort multipart uploads {} failed: {}", upload_id, e);
+                    }
+                });
+            }
+            Err(_) => {
+                log::error!("no tokio runtime to abort multipart uploads {}", upload_id);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_object_writer_async {
+    use tokio::io::AsyncWriteExt;
+
+    use crate::{
+        common::RequestPayer,
+        mock_server::{self, MockRequest, MockResponse},
+        object_common::PutObjectOptionsBuilder,
+        object_writer_common::{ObjectWriterOptions, ObjectWriterOptionsBuilder},
+        Client, ClientBuilder,
+    };
+
+    use super::ObjectWriter;
+
+    const PART_SIZE: usize = 102_400;
+
+    fn mock_client(port: u16) -> Client {
+        ClientBuilder::new("ak", "sk", format!("http://127.0.0.1:{}", port))
+            .region("cn-hangzhou")
+            .path_style(true)
+            .build()
+            .unwrap()
+    }
+
+    fn writer_options() -> ObjectWriterOptions {
+        ObjectWriterOptionsBuilder::new()
+            .part_size(PART_SIZE)
+            .put_options(PutObjectOptionsBuilder::new().request_payer(RequestPayer::Requester).build())
+            .build()
+    }
+
+    fn test_data(len: usize) -> Vec<u8> {
+        (0..len).map(|i| (i % 251) as u8).collect()
+    }
+
+    /// Respond like OSS does for put object and multipart uploads
+    fn handle(request: &MockRequest) -> MockResponse {
+        match request.method.as_str() {
+            "POST" if request.has_query("uploads") => MockResponse::new(200).body(
+                "<?xml version=\"1.0\" encoding=\"UTF-8\"?><InitiateMultipartUploadResult><Bucket>example-bucket</Bucket><Key>a.txt</Key><UploadId>upload-1</UploadId></InitiateMultipartUploadResult>",
+            ),
+            "POST" => MockResponse::new(200).body(
+                "<?xml version=\"1.0\" encoding=\"UTF-8\"?><CompleteMultipartUploadResult><Bucket>example-bucket</Bucket><Key>a.txt</Key><ETag>\"ABC-3\"</ETag></CompleteMultipartUploadResult>",
+            ),
+            "DELETE" => MockResponse::new(204),
+            _ => MockResponse::new(200).header("ETag", format!("\"ETAG-{}\"", request.body.len())),
+        }
+    }
+
+    fn handle_complete_failure(request: &MockRequest) -> MockResponse {
+        if request.method == "POST" && request.has_query("uploadId") {
+            MockResponse::new(500).body(
+                "<?xml version=\"1.0\" encoding=\"UTF-8\"?><Error><Code>InternalError</Code><Message>We encountered an internal error.</Message><RequestId>1234</RequestId></Error>",
+            )
+        } else {
+            handle(request)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_object_writer_put_object() {
+        let (port, server) = mock_server::start(1, handle);
+        let client = mock_client(port);
+        let data = test_data(PART_SIZE);
+
+        let mut writer = ObjectWriter::new(&client, "example-bucket", "a.txt", Some(writer_options())).unwrap();
+        writer.write_all(&data).await.unwrap();
+        writer.shutdown().await.unwrap();
+        assert!(writer.upload_id().is_none());
+
+        let requests = tokio::task::spawn_blocking(move || server.join().unwrap()).await.unwrap();
+        assert_eq!(1, requests.len());
+        assert_eq!("PUT", requests[0].method);
+        assert_eq!("/example-bucket/a.txt", requests[0].path);
+        assert_eq!(data, requests[0].body);
+    }
+
+    #[tokio::test]
+    async fn test_object_writer_multipart_uploads() {
+        let (port, server) = mock_server::start(5, handle);
+        let client = mock_client(port);
+        let data = test_data(PART_SIZE * 2 + 51_200);
+
+        let mut writer = ObjectWriter::new(&client, "example-bucket", "a.txt", Some(writer_options())).unwrap();
+        writer.write_all(&data).await.unwrap();
+        writer.shutdown().await.unwrap();
+        assert_eq!(Some("upload-1"), writer.upload_id());
+
+        let requests = tokio::task::spawn_blocking(move || server.join().unwrap()).await.unwrap();
+        assert_eq!(5, requests.len());
+
+        assert_eq!("POST", requests[0].method);
+        assert!(requests[0].has_query("uploads"));
+
+        for (i, request) in requests[1..4].iter().enumerate() {
+            assert_eq!("PUT", request.method);
+            assert!(request.path.contains(&format!("partNumber={}", i + 1)));
+            assert!(request.path.contains("uploadId=upload-1"));
+            assert_eq!(Some(&"requester".to_string()), request.headers.get("x-oss-request-payer"));
+        }
+
+        assert_eq!(&data[..PART_SIZE], &requests[1].body[..]);
+        assert_eq!(&data[PART_SIZE..PART_SIZE * 2], &requests[2].body[..]);
+        assert_eq!(&data[PART_SIZE * 2..], &requests[3].body[..]);
+
+        assert_eq!("POST", requests[4].method);
+        assert!(requests[4].path.contains("uploadId=upload-1"));
+        let body = String::from_utf8_lossy(&requests[4].body);
+        assert!(body.contains("<PartNumber>1</PartNumber><ETag>&quot;ETAG-102400&quot;</ETag>"));
+        assert!(body.contains("<PartNumber>3</PartNumber><ETag>&quot;ETAG-51200&quot;</ETag>"));
+    }
+
+    #[tokio::test]
+    async fn test_object_writer_abort_on_drop() {
+        let (port, server) = mock_server::start(3, handle);
+        let client = mock_client(port);
+
+        let mut writer = ObjectWriter::new(&client, "example-bucket", "a.txt", Some(writer_options())).unwrap();
+        writer.write_all(&test_data(PART_SIZE + 1)).await.unwrap();
+        drop(writer);
+
+        let requests = tokio::task::spawn_blocking(move || server.join().unwrap()).await.unwrap();
+        assert_eq!(vec!["POST", "PUT", "DELETE"], requests.iter().map(|r| r.method.as_str()).collect::<Vec<_>>());
+        assert!(requests[2].path.contains("uploadId=upload-1"));
+        assert_eq!(Some(&"requester".to_string()), requests[2].headers.get("x-oss-request-payer"));
+    }
+
+    #[tokio::test]
+    async fn test_object_writer_retry_after_failure() {
+        let (port, server) = mock_server::start(5, handle_complete_failure);
+        let client = mock_client(port);
+
+        let mut writer = ObjectWriter::new(&client, "example-bucket", "a.txt", Some(writer_options())).unwrap();
+        writer.write_all(&test_data(PART_SIZE + 1)).await.unwrap();
+        assert!(writer.shutdown().await.is_err());
+
+        // the parts have been taken out, so retrying must not complete the upload with missing parts
+        assert!(writer.shutdown().await.is_err());
+        assert!(writer.write_all(b"more").await.is_err());
+        drop(writer);
+
+        let requests = tokio::task::spawn_blocking(move || server.join().unwrap()).await.unwrap();
+        assert_eq!(
+            vec!["POST", "PUT", "PUT", "POST", "DELETE"],
+            requests.iter().map(|r| r.method.as_str()).collect::<Vec<_>>()
+        );
+    }
+}
@@ -0,0 +1,161 @@
+//! Common types for object writer which uploads data written to it as an object.
+
+use crate::{
+    error::Error,
+    multipart_common::{AbortMultipartUploadOptions, CompleteMultipartUploadOptions, InitiateMultipartUploadOptions, UploadPartRequest},
+    object_common::PutObjectOptions,
+    util::{validate_bucket_name, validate_object_key},
+    Result,
+};
+
+/// Default part size of object writer: 8MB
+pub const DEFAULT_OBJECT_WRITER_PART_SIZE: usize = 8 * 1024 * 1024;
+
+/// Options for object writer
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde-support", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde-camelcase", serde(rename_all = "camelCase"))]
+pub struct ObjectWriterOptions {
+    /// The size of each part when the data is uploaded with multipart uploads.
+    /// Must be in range `[100KB, 5GB]`. Default is 8MB.
+    ///
+    /// If the total size of the data written is not greater than `part_size`,
+    /// the object is created by a single put object request.
+    pub part_size: usize,
+
+    /// Options for put object or initiate multipart uploads.
+    /// If `callback`, `if_match` or `if_none_match` is set, it is sent when putting object or completing multipart uploads.
+    pub put_options: Option<PutObjectOptions>,
+}
+
+impl Default for ObjectWriterOptions {
+    fn default() -> Self {
+        Self {
+            part_size: DEFAULT_OBJECT_WRITER_PART_SIZE,
+            put_options: None,
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct ObjectWriterOptionsBuilder {
+    options: ObjectWriterOptions,
+}
+
+impl ObjectWriterOptionsBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn part_size(mut self, part_size: usize) -> Self {
+        self.options.part_size = part_size;
+        self
+    }
+
+    pub fn put_options(mut self, put_options: PutObjectOptions) -> Self {
+        self.options.put_options = Some(put_options);
+        self
+    }
+
+    pub fn build(self) -> ObjectWriterOptions {
+        self.options
+    }
+}
+
+pub(crate) fn validate_object_writer_params(bucket_name: &str, object_key: &str, options: &ObjectWriterOptions) -> Result<()> {
+    if !validate_bucket_name(bucket_name) {
+        return Err(Error::InvalidBucketName(bucket_name.to_string()));
+    }
+
+    if !validate_object_key(object_key) {
+        return Err(Error::InvalidObjectKey(object_key.to_string()));
+    }
+
+    if !(102_400..=5_368_709_120).contains(&(options.part_size as u64)) {
+        return Err(Error::InvalidArgument(format!(
+            "invalid part size: {}. part size should be in range [100KB, 5GB]",
+            options.part_size
+        )));
+    }
+
+    Ok(())
+}
+
+/// Options for initiating multipart uploads.
+/// The callback and conditional headers are sent when completing multipart uploads, so they are removed here.
+pub(crate) fn build_initiate_options(options: &ObjectWriterOptions) -> Option<InitiateMultipartUploadOptions> {
+    options.put_options.clone().map(|mut opt| {
+        opt.callback = None;
+        opt.if_match = None;
+        opt.if_none_match = None;
+        opt
+    })
+}
+
+pub(crate) fn build_upload_part_params(options: &ObjectWriterOptions, upload_id: &str, part_number: u32) -> UploadPartRequest {
+    UploadPartRequest {
+        part_number,
+        upload_id: upload_id.to_string(),
+        request_payer: options.put_options.as_ref().and_then(|opt| opt.request_payer),
+    }
+}
+
+pub(crate) fn build_complete_options(options: &ObjectWriterOptions) -> Option<CompleteMultipartUploadOptions> {
+    options.put_options.as_ref().map(|opt| CompleteMultipartUploadOptions {
+        callback: opt.callback.clone(),
+        request_payer: opt.request_payer,
+        if_match: opt.if_match.clone(),
+        if_none_match: opt.if_none_match.clone(),
+    })
+}
+
+pub(crate) fn build_abort_options(options: &ObjectWriterOptions) -> Option<AbortMultipartUploadOptions> {
+    options.put_options.as_ref().map(|opt| AbortMultipartUploadOptions {
+        request_payer: opt.request_payer,
+    })
+}
+
+#[cfg(test)]
+mod test_object_writer_common {
+    use std::collections::HashMap;
+
+    use crate::{
+        common::RequestPayer,
+        object_common::{Callback, PutObjectOptionsBuilder},
+    };
+
+    use super::{
+        build_abort_options, build_complete_options, build_initiate_options, build_upload_part_params, validate_object_writer_params,
+        ObjectWriterOptionsBuilder,
+    };
+
+    #[test]
+    fn test_object_writer_options() {
+        let options = ObjectWriterOptionsBuilder::new().part_size(1024).build();
+        assert!(validate_object_writer_params("test-bucket", "a/b.txt", &options).is_err());
+
+        let put_options = PutObjectOptionsBuilder::new()
+            .callback(Callback {
+                url: "https://example.com/callback".to_string(),
+                host: None,
+                body: "bucket=${bucket}".to_string(),
+                sni: None,
+                body_type: None,
+                custom_variables: HashMap::new(),
+            })
+            .request_payer(RequestPayer::Requester)
+            .build();
+
+        let options = ObjectWriterOptionsBuilder::new().put_options(put_options).build();
+        assert!(validate_object_writer_params("test-bucket", "a/b.txt", &options).is_ok());
+
+        assert!(build_initiate_options(&options).unwrap().callback.is_none());
+        assert!(build_complete_options(&options).unwrap().callback.is_some());
+
+        let params = build_upload_part_params(&options, "upload-id", 3);
+        assert_eq!(3, params.part_number);
+        assert_eq!(Some(RequestPayer::Requester), params.request_payer);
+
+        assert_eq!(Some(RequestPayer::Requester), build_abort_options(&options).unwrap().request_payer);
+    }
+}
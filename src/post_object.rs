@@ -0,0 +1,147 @@
+//! PostObject module, which uploads an object with an HTML form signed by a policy.
+
+use async_trait::async_trait;
+use url::Url;
+
+use crate::{
+    object_common::PutObjectResult,
+    post_object_common::{build_post_object_form, sign_post_policy, PostObjectOptions, PostPolicy, SignedPostPolicy},
+    request::OssRequest,
+    util::{get_iso8601_date_time_string, shift_iso8601_date_time_string},
+    Client, Result,
+};
+
+impl Client {
+    /// Sign the policy for uploading objects to the bucket with HTML forms, e.g. direct uploads from browsers.
+    /// The returned value contains the url and form fields to post.
+    pub fn sign_post_policy<S: AsRef<str>>(&self, bucket_name: S, policy: &PostPolicy) -> Result<SignedPostPolicy> {
+        let bucket_name = bucket_name.as_ref();
+
+        let signed = sign_post_policy(
+            policy,
+            bucket_name,
+            &self.access_key_id,
+            &self.region,
+            self.sts_token.as_deref(),
+            &shift_iso8601_date_time_string(&get_iso8601_date_time_string(), self.clock_offset()),
+            |s, d| self.calculate_signature(s, d),
+        )?;
+
+        let url = self.request_url(&OssRequest::new().bucket(bucket_name));
+
+        Ok(SignedPostPolicy { url, ..signed })
+    }
+}
+
+#[async_trait]
+pub trait PostObjectOperations {
+    /// Upload an object with a form signed by the policy.
+    /// This is what browsers do with the result of [`Client::sign_post_policy`], mostly used for testing the policy.
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/postobject>
+    async fn post_object<S1, S2>(
+        &self,
+        bucket_name: S1,
+        object_key: S2,
+        content: Vec<u8>,
+        policy: PostPolicy,
+        options: Option<PostObjectOptions>,
+    ) -> Result<PutObjectResult>
+    where
+        S1: AsRef<str> + Send,
+        S2: AsRef<str> + Send;
+}
+
+#[async_trait]
+impl PostObjectOperations for Client {
+    /// Upload an object with a form signed by the policy.
+    /// This is what browsers do with the result of [`Client::sign_post_policy`], mostly used for testing the policy.
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/postobject>
+    async fn post_object<S1, S2>(
+        &self,
+        bucket_name: S1,
+        object_key: S2,
+        content: Vec<u8>,
+        policy: PostPolicy,
+        options: Option<PostObjectOptions>,
+    ) -> Result<PutObjectResult>
+    where
+        S1: AsRef<str> + Send,
+        S2: AsRef<str> + Send,
+    {
+        let signed = self.sign_post_policy(bucket_name, &policy)?;
+        let (content_type, body) = build_post_object_form(object_key.as_ref(), &signed, &content, &options)?;
+
+        let req = self
+            .http_client
+            .post(Url::parse(&signed.url)?)
+            .header("content-type", content_type)
+            .header("content-length", body.len())
+            .body(body)
+            .build()?;
+
+        let (headers, content) = self.execute_request::<String>(req, &signed.url).await?;
+
+        if policy.callback.is_some() {
+            Ok(PutObjectResult::CallbackResponse(content))
+        } else {
+            Ok(PutObjectResult::ApiResponse(headers.into()))
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_post_object_async {
+    use crate::{
+        mock_server::{self, MockResponse},
+        object_common::PutObjectResult,
+        post_object::PostObjectOperations,
+        post_object_common::{PostObjectOptions, PostPolicyBuilder},
+        ClientBuilder,
+    };
+
+    #[tokio::test]
+    async fn test_post_object_with_mock_server() {
+        // respond like OSS does for a successful post object
+        let (port, server) = mock_server::start(1, |_| MockResponse::new(200).header("ETag", "\"ABC\"").header("x-oss-request-id", "1234"));
+
+        let client = ClientBuilder::new("ak", "sk", format!("http://127.0.0.1:{}", port))
+            .region("cn-hangzhou")
+            .path_style(true)
+            .build()
+            .unwrap();
+
+        let policy = PostPolicyBuilder::new(3600).key_starts_with("uploads/").build();
+        let options = PostObjectOptions {
+            content_type: Some("text/plain".to_string()),
+            ..Default::default()
+        };
+
+        let ret = client
+            .post_object("example-bucket", "uploads/a.txt", b"hello".to_vec(), policy, Some(options))
+            .await
+            .unwrap();
+
+        match ret {
+            PutObjectResult::ApiResponse(r) => {
+                assert_eq!("ABC", r.etag);
+                assert_eq!("1234", r.request_id);
+            }
+            _ => panic!("api response expected"),
+        }
+
+        let requests = tokio::task::spawn_blocking(move || server.join().unwrap()).await.unwrap();
+        assert_eq!(1, requests.len());
+
+        let request = &requests[0];
+        assert_eq!("POST", request.method);
+        assert_eq!("/example-bucket/", request.path);
+        assert!(!request.headers.contains_key("authorization"));
+
+        let body = String::from_utf8_lossy(&request.body);
+        assert!(body.contains("name=\"key\"\r\n\r\nuploads/a.txt\r\n"));
+        assert!(body.contains("name=\"x-oss-signature-version\"\r\n\r\nOSS4-HMAC-SHA256\r\n"));
+        assert!(body.contains("\r\n\r\nhello\r\n"));
+    }
+}
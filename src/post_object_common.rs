@@ -0,0 +1,522 @@
+//! Common types for PostObject, which uploads an object with an HTML form.
+//!
+//! The form is signed by a policy which limits what can be uploaded, so the access key secret is never exposed to browsers.
+//!
+//! Official document: <https://help.aliyun.com/zh/oss/developer-reference/postobject>
+
+use std::collections::HashMap;
+
+use base64::{prelude::BASE64_STANDARD, Engine};
+use chrono::{Duration, NaiveDateTime};
+
+use crate::{
+    common,
+    error::Error,
+    object_common::Callback,
+    util::{validate_bucket_name, validate_meta_key, validate_object_key},
+    Result,
+};
+
+/// A condition in the post policy.
+/// Field names are the form field names without the leading `$`, e.g. `key`, `bucket`, `content-type`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde-support", derive(serde::Serialize, serde::Deserialize))]
+pub enum PostPolicyCondition {
+    /// The field must be equal to the value, serialized as `{"field": "value"}`
+    Equals(String, String),
+
+    /// The field must start with the value, serialized as `["starts-with", "$field", "value"]`
+    StartsWith(String, String),
+
+    /// The size of the file must be in range (inclusive), serialized as `["content-length-range", min, max]`
+    ContentLengthRange(u64, u64),
+
+    /// The field must be one of the values, serialized as `["in", "$field", ["value1", "value2"]]`
+    In(String, Vec<String>),
+
+    /// The field must not be any of the values, serialized as `["not-in", "$field", ["value1", "value2"]]`
+    NotIn(String, Vec<String>),
+}
+
+impl PostPolicyCondition {
+    fn to_json_value(&self) -> serde_json::Value {
+        match self {
+            PostPolicyCondition::Equals(k, v) => {
+                let mut map = serde_json::Map::new();
+                map.insert(k.clone(), serde_json::Value::String(v.clone()));
+                serde_json::Value::Object(map)
+            }
+            PostPolicyCondition::StartsWith(k, v) => serde_json::json!(["starts-with", format!("${}", k), v]),
+            PostPolicyCondition::ContentLengthRange(min, max) => serde_json::json!(["content-length-range", min, max]),
+            PostPolicyCondition::In(k, values) => serde_json::json!(["in", format!("${}", k), values]),
+            PostPolicyCondition::NotIn(k, values) => serde_json::json!(["not-in", format!("${}", k), values]),
+        }
+    }
+}
+
+/// Expiration of the post policy
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde-support", derive(serde::Serialize, serde::Deserialize))]
+pub enum PostPolicyExpiration {
+    /// ISO8601 UTC time of the server, e.g. `2025-01-01T12:00:00.000Z`
+    At(String),
+
+    /// Seconds after the policy is signed.
+    /// The expiration is calculated with the signing date, so the clock offset of the client to the server is applied.
+    After(u32),
+}
+
+/// Policy of PostObject.
+///
+/// The conditions required by signature V4 (`x-oss-signature-version`, `x-oss-credential`, `x-oss-date`
+/// and `x-oss-security-token` for STS) and the bucket are added when the policy is signed.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde-support", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde-camelcase", serde(rename_all = "camelCase"))]
+pub struct PostPolicy {
+    pub expiration: PostPolicyExpiration,
+    pub conditions: Vec<PostPolicyCondition>,
+
+    /// Callback after the object is uploaded. It is sent as `callback` form field,
+    /// and the custom variables are sent as `x:` prefixed form fields.
+    pub callback: Option<Callback>,
+}
+
+/// Builder for `PostPolicy`
+///
+/// # Examples
+///
+/// ```
+/// use ali_oss_rs::post_object_common::PostPolicyBuilder;
+///
+/// let policy = PostPolicyBuilder::new(3600)
+///     .key_starts_with("user-uploads/")
+///     .content_length_range(1, 10 * 1024 * 1024)
+///     .content_type_starts_with("image/")
+///     .build();
+/// ```
+#[derive(Debug)]
+pub struct PostPolicyBuilder {
+    policy: PostPolicy,
+}
+
+impl PostPolicyBuilder {
+    /// The policy expires after `expire_seconds` from the time it is signed
+    pub fn new(expire_seconds: u32) -> Self {
+        Self::with_expiration(PostPolicyExpiration::After(expire_seconds))
+    }
+
+    /// The policy expires at the ISO8601 UTC time of the server, e.g. `2025-01-01T12:00:00.000Z`
+    pub fn expire_at(expiration: impl Into<String>) -> Self {
+        Self::with_expiration(PostPolicyExpiration::At(expiration.into()))
+    }
+
+    fn with_expiration(expiration: PostPolicyExpiration) -> Self {
+        Self {
+            policy: PostPolicy {
+                expiration,
+                conditions: vec![],
+                callback: None,
+            },
+        }
+    }
+
+    pub fn condition(mut self, condition: PostPolicyCondition) -> Self {
+        self.policy.conditions.push(condition);
+        self
+    }
+
+    pub fn key(self, key: impl Into<String>) -> Self {
+        self.condition(PostPolicyCondition::Equals("key".to_string(), key.into()))
+    }
+
+    pub fn key_starts_with(self, prefix: impl Into<String>) -> Self {
+        self.condition(PostPolicyCondition::StartsWith("key".to_string(), prefix.into()))
+    }
+
+    pub fn bucket(self, bucket_name: impl Into<String>) -> Self {
+        self.condition(PostPolicyCondition::Equals("bucket".to_string(), bucket_name.into()))
+    }
+
+    pub fn content_type_starts_with(self, prefix: impl Into<String>) -> Self {
+        self.condition(PostPolicyCondition::StartsWith("content-type".to_string(), prefix.into()))
+    }
+
+    pub fn content_type_in(self, content_types: Vec<String>) -> Self {
+        self.condition(PostPolicyCondition::In("content-type".to_string(), content_types))
+    }
+
+    pub fn content_length_range(self, min: u64, max: u64) -> Self {
+        self.condition(PostPolicyCondition::ContentLengthRange(min, max))
+    }
+
+    pub fn callback(mut self, callback: Callback) -> Self {
+        self.policy.callback = Some(callback);
+        self
+    }
+
+    pub fn build(self) -> PostPolicy {
+        self.policy
+    }
+}
+
+/// The signed policy and all form fields required by PostObject except `key`, `file` and optional fields such as `Content-Type`.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde-support", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde-camelcase", serde(rename_all = "camelCase"))]
+pub struct SignedPostPolicy {
+    /// The url which the form is posted to, e.g. `https://example-bucket.oss-cn-hangzhou.aliyuncs.com/`
+    pub url: String,
+
+    /// Base64 encoded policy
+    pub policy: String,
+    pub signature_version: String,
+    pub credential: String,
+    pub date: String,
+    pub signature: String,
+    pub security_token: Option<String>,
+
+    /// Base64 encoded callback
+    pub callback: Option<String>,
+
+    /// Custom variables of the callback. They are sent as `x:` prefixed form fields
+    pub callback_variables: HashMap<String, String>,
+}
+
+impl SignedPostPolicy {
+    /// Form fields in `(name, value)` pairs
+    pub fn form_fields(&self) -> Vec<(String, String)> {
+        let mut fields = vec![
+            ("policy".to_string(), self.policy.clone()),
+            ("x-oss-signature-version".to_string(), self.signature_version.clone()),
+            ("x-oss-credential".to_string(), self.credential.clone()),
+            ("x-oss-date".to_string(), self.date.clone()),
+            ("x-oss-signature".to_string(), self.signature.clone()),
+        ];
+
+        if let Some(s) = &self.security_token {
+            fields.push(("x-oss-security-token".to_string(), s.clone()));
+        }
+
+        if let Some(s) = &self.callback {
+            fields.push(("callback".to_string(), s.clone()));
+        }
+
+        for (k, v) in &self.callback_variables {
+            fields.push((format!("x:{}", k.strip_prefix("x:").unwrap_or(k)), v.clone()));
+        }
+
+        fields
+    }
+}
+
+/// Options for post object
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde-support", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde-camelcase", serde(rename_all = "camelCase"))]
+pub struct PostObjectOptions {
+    /// Sent as `Content-Type` form field. It is guessed from the object key if not set.
+    pub content_type: Option<String>,
+
+    /// User metadata. Keys are without `x-oss-meta-` prefix
+    pub metadata: HashMap<String, String>,
+}
+
+/// Build the policy JSON with conditions required by signature V4.
+pub(crate) fn build_policy_json(policy: &PostPolicy, bucket_name: &str, credential: &str, date_time_string: &str, sts_token: Option<&str>) -> Result<String> {
+    let mut conditions: Vec<serde_json::Value> = policy.conditions.iter().map(|c| c.to_json_value()).collect();
+
+    let has_bucket = policy
+        .conditions
+        .iter()
+        .any(|c| matches!(c, PostPolicyCondition::Equals(k, _) if k == "bucket"));
+    if !has_bucket {
+        conditions.push(PostPolicyCondition::Equals("bucket".to_string(), bucket_name.to_string()).to_json_value());
+    }
+
+    conditions.push(serde_json::json!({ "x-oss-signature-version": common::SIGNATURE_VERSION }));
+    conditions.push(serde_json::json!({ "x-oss-credential": credential }));
+    conditions.push(serde_json::json!({ "x-oss-date": date_time_string }));
+
+    if let Some(s) = sts_token {
+        conditions.push(serde_json::json!({ "x-oss-security-token": s }));
+    }
+
+    let expiration = match &policy.expiration {
+        PostPolicyExpiration::After(n) => {
+            let signed_at = NaiveDateTime::parse_from_str(date_time_string, "%Y%m%dT%H%M%SZ")
+                .map_err(|_| Error::InvalidArgument(format!("invalid date time string: {}", date_time_string)))?;
+            (signed_at.and_utc() + Duration::seconds(*n as i64))
+                .format("%Y-%m-%dT%H:%M:%S%.3fZ")
+                .to_string()
+        }
+        PostPolicyExpiration::At(s) if s.is_empty() => return Err(Error::InvalidArgument("expiration of post policy must not be empty".to_string())),
+        PostPolicyExpiration::At(s) => s.clone(),
+    };
+
+    let json = serde_json::json!({
+        "expiration": expiration,
+        "conditions": conditions,
+    });
+
+    Ok(serde_json::to_string(&json)?)
+}
+
+/// Sign the policy. `signer` calculates the signature of the string to sign (the base64 encoded policy) with the date string.
+/// So the same key derivation as request signing is used. The `url` of the returned value is left empty.
+pub(crate) fn sign_post_policy<F>(
+    policy: &PostPolicy,
+    bucket_name: &str,
+    access_key_id: &str,
+    region: &str,
+    sts_token: Option<&str>,
+    date_time_string: &str,
+    signer: F,
+) -> Result<SignedPostPolicy>
+where
+    F: Fn(&str, &str) -> String,
+{
+    if !validate_bucket_name(bucket_name) {
+        return Err(Error::InvalidBucketName(bucket_name.to_string()));
+    }
+
+    let date_string = &date_time_string[..8];
+    let credential = format!("{}/{}/{}/oss/aliyun_v4_request", access_key_id, date_string, region);
+
+    let policy_json = build_policy_json(policy, bucket_name, &credential, date_time_string, sts_token)?;
+    let policy_base64 = BASE64_STANDARD.encode(&policy_json);
+
+    log::debug!("post policy: {}", policy_json);
+
+    let signature = signer(&policy_base64, date_string);
+
+    let (callback, callback_variables) = match &policy.callback {
+        Some(cb) => (Some(BASE64_STANDARD.encode(serde_json::to_string(cb)?)), cb.custom_variables.clone()),
+        None => (None, HashMap::new()),
+    };
+
+    Ok(SignedPostPolicy {
+        url: String::new(),
+        policy: policy_base64,
+        signature_version: common::SIGNATURE_VERSION.to_string(),
+        credential,
+        date: date_time_string.to_string(),
+        signature,
+        security_token: sts_token.map(|s| s.to_string()),
+        callback,
+        callback_variables,
+    })
+}
+
+/// Build `multipart/form-data` body of post object. Returns the content type with boundary and the body.
+pub(crate) fn build_post_object_form(
+    object_key: &str,
+    signed_policy: &SignedPostPolicy,
+    content: &[u8],
+    options: &Option<PostObjectOptions>,
+) -> Result<(String, Vec<u8>)> {
+    if !validate_object_key(object_key) {
+        return Err(Error::InvalidObjectKey(object_key.to_string()));
+    }
+
+    let mut fields = vec![("key".to_string(), object_key.to_string())];
+    fields.extend(signed_policy.form_fields());
+
+    let content_type = options
+        .as_ref()
+        .and_then(|o| o.content_type.clone())
+        .unwrap_or_else(|| mime_guess::from_path(object_key).first_or_octet_stream().to_string());
+    fields.push(("Content-Type".to_string(), content_type.clone()));
+
+    if let Some(options) = options {
+        for (k, v) in &options.metadata {
+            if !validate_meta_key(k) {
+                return Err(Error::InvalidArgument(format!("invalid metadata key: {}", k)));
+            }
+            fields.push((format!("x-oss-meta-{}", k), v.clone()));
+        }
+    }
+
+    fields.push(("success_action_status".to_string(), "200".to_string()));
+
+    let file_name = object_key.rsplit('/').next().unwrap_or(object_key);
+
+    // line breaks would end the part headers or the field value early
+    let has_line_break = |s: &str| s.contains(['\r', '\n']);
+    if let Some((k, _)) = fields.iter().find(|(k, v)| has_line_break(k) || has_line_break(v)) {
+        return Err(Error::InvalidArgument(format!("form field contains line breaks: {}", k.escape_default())));
+    }
+    if has_line_break(file_name) {
+        return Err(Error::InvalidArgument(format!(
+            "file name contains line breaks: {}",
+            file_name.escape_default()
+        )));
+    }
+
+    let boundary = format!(
+        "----ali-oss-rs-{}",
+        hex::encode(md5::compute(format!("{}{}", object_key, signed_policy.signature)).0)
+    );
+
+    let mut body = Vec::with_capacity(content.len() + 2048);
+
+    for (k, v) in &fields {
+        body.extend_from_slice(
+            format!(
+                "--{}\r\nContent-Disposition: form-data; name=\"{}\"\r\n\r\n{}\r\n",
+                boundary,
+                escape_form_name(k),
+                v
+            )
+            .as_bytes(),
+        );
+    }
+
+    body.extend_from_slice(
+        format!(
+            "--{}\r\nContent-Disposition: form-data; name=\"file\"; filename=\"{}\"\r\nContent-Type: {}\r\n\r\n",
+            boundary,
+            escape_form_name(file_name),
+            content_type
+        )
+        .as_bytes(),
+    );
+    body.extend_from_slice(content);
+    body.extend_from_slice(format!("\r\n--{}--\r\n", boundary).as_bytes());
+
+    Ok((format!("multipart/form-data; boundary={}", boundary), body))
+}
+
+/// Escape quotes in the quoted name or file name of `Content-Disposition`, the same as browsers do
+fn escape_form_name(s: &str) -> String {
+    s.replace('"', "%22")
+}
+
+#[cfg(test)]
+mod test_post_object_common {
+    use base64::{prelude::BASE64_STANDARD, Engine};
+
+    use super::*;
+    use crate::ClientBuilder;
+
+    fn signed_policy() -> SignedPostPolicy {
+        let policy = PostPolicyBuilder::expire_at("2025-01-01T12:00:00.000Z")
+            .key_starts_with("user/")
+            .content_length_range(1, 1024)
+            .build();
+
+        sign_post_policy(&policy, "example-bucket", "ak", "cn-hangzhou", Some("token"), "20250101T100000Z", |s, d| {
+            format!("{}:{}", d, s.len())
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn test_build_policy_json() {
+        let signed = signed_policy();
+        let json = String::from_utf8(BASE64_STANDARD.decode(&signed.policy).unwrap()).unwrap();
+
+        assert_eq!(
+            r#"{"conditions":[["starts-with","$key","user/"],["content-length-range",1,1024],{"bucket":"example-bucket"},{"x-oss-signature-version":"OSS4-HMAC-SHA256"},{"x-oss-credential":"ak/20250101/cn-hangzhou/oss/aliyun_v4_request"},{"x-oss-date":"20250101T100000Z"},{"x-oss-security-token":"token"}],"expiration":"2025-01-01T12:00:00.000Z"}"#,
+            json
+        );
+
+        assert_eq!("ak/20250101/cn-hangzhou/oss/aliyun_v4_request", signed.credential);
+        assert_eq!(format!("20250101:{}", signed.policy.len()), signed.signature);
+        assert_eq!(Some("token".to_string()), signed.security_token);
+    }
+
+    #[test]
+    fn test_policy_expire_seconds() {
+        let policy = PostPolicyBuilder::new(3600).build();
+
+        // the date time string is the local time shifted by the clock offset, so the expiration is in server time
+        let signed = sign_post_policy(&policy, "example-bucket", "ak", "cn-hangzhou", None, "20250101T235930Z", |_, _| String::new()).unwrap();
+        let json = String::from_utf8(BASE64_STANDARD.decode(&signed.policy).unwrap()).unwrap();
+
+        assert!(json.ends_with(r#""expiration":"2025-01-02T00:59:30.000Z"}"#));
+    }
+
+    #[test]
+    fn test_policy_empty_expiration() {
+        let policy = PostPolicy {
+            expiration: PostPolicyExpiration::At(String::new()),
+            conditions: vec![],
+            callback: None,
+        };
+
+        let ret = sign_post_policy(&policy, "example-bucket", "ak", "cn-hangzhou", None, "20250101T100000Z", |_, _| String::new());
+        assert!(matches!(ret, Err(Error::InvalidArgument(_))));
+    }
+
+    #[test]
+    fn test_sign_post_policy_v4() {
+        let client = ClientBuilder::new("ak", "sk", "oss-cn-hangzhou.aliyuncs.com")
+            .region("cn-hangzhou")
+            .build()
+            .unwrap();
+
+        let policy = PostPolicyBuilder::expire_at("2025-01-01T12:00:00.000Z").key_starts_with("user/").build();
+
+        let signed = sign_post_policy(&policy, "example-bucket", "ak", "cn-hangzhou", None, "20250101T100000Z", |s, d| {
+            client.calculate_signature(s, d)
+        })
+        .unwrap();
+
+        // calculated independently with HMAC-SHA256 over the base64 encoded policy
+        assert_eq!(
+            "eyJjb25kaXRpb25zIjpbWyJzdGFydHMtd2l0aCIsIiRrZXkiLCJ1c2VyLyJdLHsiYnVja2V0IjoiZXhhbXBsZS1idWNrZXQifSx7Ingtb3NzLXNpZ25hdHVyZS12ZXJzaW9uIjoiT1NTNC1ITUFDLVNIQTI1NiJ9LHsieC1vc3MtY3JlZGVudGlhbCI6ImFrLzIwMjUwMTAxL2NuLWhhbmd6aG91L29zcy9hbGl5dW5fdjRfcmVxdWVzdCJ9LHsieC1vc3MtZGF0ZSI6IjIwMjUwMTAxVDEwMDAwMFoifV0sImV4cGlyYXRpb24iOiIyMDI1LTAxLTAxVDEyOjAwOjAwLjAwMFoifQ==",
+            signed.policy
+        );
+        assert_eq!("9688fe6f0f6baedf3faf3e7ddf2a1467d11fef7b4d518148b0b395e701d12654", signed.signature);
+    }
+
+    #[test]
+    fn test_build_post_object_form() {
+        let signed = signed_policy();
+        let (content_type, body) = build_post_object_form("user/a.txt", &signed, b"hello", &None).unwrap();
+
+        let boundary = content_type.strip_prefix("multipart/form-data; boundary=").unwrap();
+        let body = String::from_utf8(body).unwrap();
+
+        assert!(body.starts_with(&format!("--{}\r\nContent-Disposition: form-data; name=\"key\"\r\n\r\nuser/a.txt\r\n", boundary)));
+        assert!(body.contains("name=\"x-oss-security-token\"\r\n\r\ntoken\r\n"));
+        assert!(body.contains("name=\"Content-Type\"\r\n\r\ntext/plain\r\n"));
+        assert!(body.ends_with(&format!(
+            "name=\"file\"; filename=\"a.txt\"\r\nContent-Type: text/plain\r\n\r\nhello\r\n--{}--\r\n",
+            boundary
+        )));
+    }
+
+    #[test]
+    fn test_build_post_object_form_escape() {
+        let signed = signed_policy();
+
+        let (_, body) = build_post_object_form("user/a\"b.txt", &signed, b"hello", &None).unwrap();
+        let body = String::from_utf8(body).unwrap();
+        assert!(body.contains("name=\"file\"; filename=\"a%22b.txt\"\r\n"));
+
+        let options = Some(PostObjectOptions {
+            content_type: Some("text/plain\r\nX-Injected: 1".to_string()),
+            ..Default::default()
+        });
+        assert!(matches!(
+            build_post_object_form("user/a.txt", &signed, b"hello", &options),
+            Err(Error::InvalidArgument(_))
+        ));
+
+        let options = Some(PostObjectOptions {
+            metadata: HashMap::from([("owner".to_string(), "a\nb".to_string())]),
+            ..Default::default()
+        });
+        assert!(matches!(
+            build_post_object_form("user/a.txt", &signed, b"hello", &options),
+            Err(Error::InvalidArgument(_))
+        ));
+
+        assert!(matches!(
+            build_post_object_form("user/a\r\nb.txt", &signed, b"hello", &None),
+            Err(Error::InvalidArgument(_))
+        ));
+    }
+}
@@ -2,11 +2,17 @@
 
 use std::collections::HashMap;
 
+use chrono::{Duration, NaiveDateTime, Utc};
+
 use crate::{
-    presign_common::{build_presign_get_request, PresignGetOptions},
-    request::OssRequest,
+    common::SignatureVersion,
+    error::{Error, PresignedUrlError},
+    presign_common::{
+        build_presign_get_request, build_presign_request, parse_presigned_url, verify_presigned_request, PresignGetOptions, PresignOptions, PresignedUrlInfo,
+    },
+    request::{OssRequest, RequestMethod},
     util::{self, get_iso8601_date_time_string},
-    Client,
+    Client, Result,
 };
 
 /// All data for sending request to aliyun oss api after signature calculated
@@ -28,7 +34,107 @@ impl Client {
         S1: AsRef<str>,
         S2: AsRef<str>,
     {
-        let mut request = build_presign_get_request(bucket_name.as_ref(), object_key.as_ref(), &options);
+        let request = build_presign_get_request(bucket_name.as_ref(), object_key.as_ref(), &options);
+        let request = self.sign_presign_request(request);
+        self.request_url(&request)
+    }
+
+    /// Presign URL for request of any method with the signature in the query string.
+    /// The returned headers are the signed headers given in options,
+    /// which must be sent with exactly the same values along with the url.
+    pub fn presign_request<S1, S2>(&self, method: RequestMethod, bucket_name: S1, object_key: S2, options: PresignOptions) -> SignedOssRequest
+    where
+        S1: AsRef<str>,
+        S2: AsRef<str>,
+    {
+        let request = build_presign_request(method, bucket_name.as_ref(), object_key.as_ref(), &options);
+        let request = self.sign_presign_request(request);
+        let url = self.request_url(&request);
+
+        SignedOssRequest { url, headers: request.headers }
+    }
+
+    /// Presign URL for uploading an object with `PUT` request.
+    /// Set content type, content md5 and metadata in options if they should be signed.
+    pub fn presign_put_object<S1, S2>(&self, bucket_name: S1, object_key: S2, options: PresignOptions) -> SignedOssRequest
+    where
+        S1: AsRef<str>,
+        S2: AsRef<str>,
+    {
+        self.presign_request(RequestMethod::Put, bucket_name, object_key, options)
+    }
+
+    /// Presign URL for uploading a part of a multipart upload with `PUT` request.
+    pub fn presign_upload_part<S1, S2, S3>(&self, bucket_name: S1, object_key: S2, upload_id: S3, part_number: u32, options: PresignOptions) -> SignedOssRequest
+    where
+        S1: AsRef<str>,
+        S2: AsRef<str>,
+        S3: AsRef<str>,
+    {
+        let mut options = options;
+        options.query_parameters.insert("partNumber".to_string(), part_number.to_string());
+        options.query_parameters.insert("uploadId".to_string(), upload_id.as_ref().to_string());
+
+        self.presign_request(RequestMethod::Put, bucket_name, object_key, options)
+    }
+
+    /// Presign URL for getting object metadata with `HEAD` request.
+    pub fn presign_head_object<S1, S2>(&self, bucket_name: S1, object_key: S2, options: PresignOptions) -> SignedOssRequest
+    where
+        S1: AsRef<str>,
+        S2: AsRef<str>,
+    {
+        self.presign_request(RequestMethod::Head, bucket_name, object_key, options)
+    }
+
+    /// Presign URL for deleting an object with `DELETE` request.
+    pub fn presign_delete_object<S1, S2>(&self, bucket_name: S1, object_key: S2, options: PresignOptions) -> SignedOssRequest
+    where
+        S1: AsRef<str>,
+        S2: AsRef<str>,
+    {
+        self.presign_request(RequestMethod::Delete, bucket_name, object_key, options)
+    }
+
+    /// Verify a presigned url generated with the access key id of this client,
+    /// including the credential scope, the signature over the canonical request and the expiration.
+    /// `headers` are the request headers sent along with the url, only the signed ones are used in verification.
+    ///
+    /// The bucket name can not be resolved from the url in cname mode, so the verification is unsupported in that case.
+    /// Only signature version 4 is supported.
+    pub fn verify_presigned_url<S: AsRef<str>>(&self, url: S, method: RequestMethod, headers: &HashMap<String, String>) -> Result<PresignedUrlInfo> {
+        if self.signature_version == SignatureVersion::V1 {
+            return Err(Error::PresignedUrl(PresignedUrlError::Unsupported(
+                "verification of signature version 1 is not supported".to_string(),
+            )));
+        }
+
+        if self.cname {
+            return Err(Error::PresignedUrl(PresignedUrlError::Unsupported(
+                "can not resolve bucket name from url in cname mode".to_string(),
+            )));
+        }
+
+        let mut endpoints = vec![self.endpoint.as_str()];
+        if let Some(e) = &self.accelerate_endpoint {
+            endpoints.push(e.as_str());
+        }
+
+        let request = parse_presigned_url(url.as_ref(), method, headers, self.path_style, &endpoints)?;
+
+        // the expiration is checked against the server time
+        let now = Utc::now() + Duration::seconds(self.clock_offset());
+
+        verify_presigned_request(request, &self.access_key_id, &self.region, now, |s, d| self.calculate_signature(s, d))
+    }
+
+    /// Add credential, security token and the calculated signature to query of the request
+    fn sign_presign_request(&self, mut request: OssRequest) -> OssRequest {
+        request.shift_date(self.clock_offset());
+
+        if self.signature_version == SignatureVersion::V1 {
+            return self.sign_presign_request_v1(request);
+        }
 
         let date_time_string = request.query.get("x-oss-date").unwrap().clone();
         let date_string = &date_time_string[..8];
@@ -55,22 +161,39 @@ impl Client {
 
         let sig = self.calculate_signature(&string_to_sign, date_string);
 
-        request = request.add_query("x-oss-signature", &sig);
-
-        let uri = request.build_request_uri();
-        let query_string = request.build_canonical_query_string();
+        request.add_query("x-oss-signature", &sig)
+    }
 
-        let domain_name = if request.bucket_name.is_empty() {
-            format!("{}://{}{}", self.scheme, self.endpoint, uri)
-        } else {
-            format!("{}://{}.{}{}", self.scheme, request.bucket_name, self.endpoint, uri)
+    /// Replace the query parameters of signature version 4 with `OSSAccessKeyId`, `Expires` and `Signature` of signature version 1.
+    /// Only `content-type`, `content-md5` and `x-oss-*` headers are signed, additional headers are not supported by signature version 1.
+    fn sign_presign_request_v1(&self, mut request: OssRequest) -> OssRequest {
+        let query = request.query_mut();
+        let date_time_string = query.remove("x-oss-date").unwrap_or_default();
+        let expire_seconds = query.remove("x-oss-expires").and_then(|s| s.parse::<i64>().ok()).unwrap_or_default();
+        query.remove("x-oss-signature-version");
+        query.remove("x-oss-additional-headers");
+
+        let signed_at = match NaiveDateTime::parse_from_str(&date_time_string, "%Y%m%dT%H%M%SZ") {
+            Ok(d) => d.and_utc().timestamp(),
+            Err(_) => Utc::now().timestamp(),
         };
 
-        if query_string.is_empty() {
-            domain_name
-        } else {
-            format!("{}?{}", domain_name, query_string)
+        let expires = (signed_at + expire_seconds).to_string();
+
+        if let Some(s) = &self.sts_token {
+            request = request.add_query("security-token", s);
         }
+
+        let string_to_sign = request.build_string_to_sign_v1(&expires);
+
+        log::debug!("string to sign: \n--------\n{}\n--------", string_to_sign);
+
+        let sig = self.calculate_signature_v1(&string_to_sign);
+
+        request
+            .add_query("OSSAccessKeyId", &self.access_key_id)
+            .add_query("Expires", expires)
+            .add_query("Signature", sig)
     }
 
     /// Presign a raw request, get the url and headers which contain calculated signature.
@@ -132,48 +255,24 @@ impl Client {
             oss_request.headers_mut().entry(date_header.clone()).or_insert(get_iso8601_date_time_string());
         }
 
-        let date_time_string = oss_request.headers.get(&date_header).unwrap().to_string();
-        let date_string = &date_time_string[..8];
+        oss_request.shift_date(self.clock_offset());
 
         if let Some(s) = &self.sts_token {
             if !oss_request.headers.contains_key("x-oss-security-token") {
                 oss_request = oss_request.add_header("x-oss-security-token", s);
             }
         }
-        let additional_headers = oss_request.build_additional_headers();
-        let string_to_sign = oss_request.build_string_to_sign(&self.region);
 
-        log::debug!("string to sign: \n--------\n{}\n--------", string_to_sign);
+        let http_date = util::get_http_date_with_offset(self.clock_offset());
+        let auth_string = self.build_authorization(&mut oss_request, &http_date);
 
-        let sig = self.calculate_signature(&string_to_sign, date_string);
-
-        log::debug!("signature: {}", sig);
-
-        let auth_string = format!(
-            "OSS4-HMAC-SHA256 Credential={}/{}/{}/oss/aliyun_v4_request,{}Signature={}",
-            self.access_key_id,
-            date_string,
-            self.region,
-            if additional_headers.is_empty() {
-                "".to_string()
-            } else {
-                format!("{},", additional_headers)
-            },
-            sig
-        );
+        if self.signature_version == SignatureVersion::V1 {
+            oss_request = oss_request.add_header("date", &http_date);
+        }
 
         oss_request = oss_request.add_header("authorization", &auth_string);
 
-        let uri = oss_request.build_request_uri();
-        let query_string = oss_request.build_canonical_query_string();
-
-        let url = if oss_request.bucket_name.is_empty() {
-            format!("{}://{}{}", self.scheme, self.endpoint, uri)
-        } else {
-            format!("{}://{}.{}{}", self.scheme, oss_request.bucket_name, self.endpoint, uri)
-        };
-
-        let url = if query_string.is_empty() { url } else { format!("{}?{}", url, query_string) };
+        let url = self.request_url(&oss_request);
 
         SignedOssRequest {
             url,
@@ -278,3 +377,185 @@ mod test_presign {
         log::debug!("{} {:#?}", url, headers);
     }
 }
+
+#[cfg(test)]
+mod test_presign_request {
+    use std::collections::HashMap;
+
+    use chrono::{Duration, Utc};
+
+    use crate::{
+        common::SignatureVersion,
+        error::{Error, PresignedUrlError},
+        presign_common::{build_presign_get_request, parse_presigned_url, verify_presigned_request, PresignGetOptionsBuilder, PresignOptionsBuilder},
+        request::RequestMethod,
+        ClientBuilder,
+    };
+
+    #[test]
+    fn test_presign_upload_part() {
+        let client = ClientBuilder::new("ak", "sk", "oss-cn-hangzhou.aliyuncs.com")
+            .region("cn-hangzhou")
+            .build()
+            .unwrap();
+
+        let options = PresignOptionsBuilder::new(3600).content_md5("1B2M2Y8AsgTpgAmY7PhCfg==").build();
+        let signed = client.presign_upload_part("example-bucket", "videos/a.mp4", "UPLOAD-ID", 3, options);
+
+        let url = url::Url::parse(&signed.url).unwrap();
+        assert_eq!(Some("example-bucket.oss-cn-hangzhou.aliyuncs.com"), url.host_str());
+        assert_eq!("/videos/a.mp4", url.path());
+
+        let query = url.query_pairs().into_owned().collect::<std::collections::HashMap<_, _>>();
+        assert_eq!(Some(&"3".to_string()), query.get("partNumber"));
+        assert_eq!(Some(&"UPLOAD-ID".to_string()), query.get("uploadId"));
+        assert_eq!(Some(&"3600".to_string()), query.get("x-oss-expires"));
+        assert!(query.get("x-oss-credential").unwrap().starts_with("ak/"));
+        assert_eq!(64, query.get("x-oss-signature").unwrap().len());
+
+        assert_eq!(1, signed.headers.len());
+        assert_eq!(Some(&"1B2M2Y8AsgTpgAmY7PhCfg==".to_string()), signed.headers.get("content-md5"));
+    }
+
+    #[test]
+    fn test_verify_presigned_url() {
+        let client = ClientBuilder::new("ak", "sk", "oss-cn-hangzhou.aliyuncs.com")
+            .region("cn-hangzhou")
+            .build()
+            .unwrap();
+
+        let options = PresignOptionsBuilder::new(600)
+            .content_type("video/mp4")
+            .metadata("owner", "yuanyq")
+            .header("Cache-Control", "no-cache")
+            .build();
+        let signed = client.presign_put_object("example-bucket", "videos/测试 a.mp4", options);
+
+        let info = client.verify_presigned_url(&signed.url, RequestMethod::Put, &signed.headers).unwrap();
+        assert_eq!("example-bucket", info.bucket_name);
+        assert_eq!("videos/测试 a.mp4", info.object_key);
+        assert_eq!(600, info.expire_seconds);
+        assert_eq!(vec!["cache-control".to_string()], info.additional_headers);
+
+        // unsigned headers are ignored
+        let mut headers = signed.headers.clone();
+        headers.insert("user-agent".to_string(), "test".to_string());
+        assert!(client.verify_presigned_url(&signed.url, RequestMethod::Put, &headers).is_ok());
+
+        let mut headers = signed.headers.clone();
+        headers.insert("content-type".to_string(), "image/png".to_string());
+        let err = client.verify_presigned_url(&signed.url, RequestMethod::Put, &headers).unwrap_err();
+        assert!(matches!(err, Error::PresignedUrl(PresignedUrlError::SignatureMismatch)));
+
+        let err = client.verify_presigned_url(&signed.url, RequestMethod::Get, &signed.headers).unwrap_err();
+        assert!(matches!(err, Error::PresignedUrl(PresignedUrlError::SignatureMismatch)));
+
+        let other = ClientBuilder::new("ak", "other-sk", "oss-cn-hangzhou.aliyuncs.com")
+            .region("cn-hangzhou")
+            .build()
+            .unwrap();
+        let err = other.verify_presigned_url(&signed.url, RequestMethod::Put, &signed.headers).unwrap_err();
+        assert!(matches!(err, Error::PresignedUrl(PresignedUrlError::SignatureMismatch)));
+
+        let other = ClientBuilder::new("other-ak", "sk", "oss-cn-hangzhou.aliyuncs.com")
+            .region("cn-hangzhou")
+            .build()
+            .unwrap();
+        let err = other.verify_presigned_url(&signed.url, RequestMethod::Put, &signed.headers).unwrap_err();
+        assert!(matches!(err, Error::PresignedUrl(PresignedUrlError::CredentialMismatch(_))));
+
+        let err = client
+            .verify_presigned_url(
+                signed.url.replace("x-oss-expires=600", "x-oss-expires=6000"),
+                RequestMethod::Put,
+                &signed.headers,
+            )
+            .unwrap_err();
+        assert!(matches!(err, Error::PresignedUrl(PresignedUrlError::SignatureMismatch)));
+
+        let err = client
+            .verify_presigned_url("https://example-bucket.oss-cn-hangzhou.aliyuncs.com/a.txt", RequestMethod::Get, &HashMap::new())
+            .unwrap_err();
+        assert!(matches!(err, Error::PresignedUrl(PresignedUrlError::Malformed(_))));
+
+        // expired
+        let request = parse_presigned_url(&signed.url, RequestMethod::Put, &signed.headers, false, &["oss-cn-hangzhou.aliyuncs.com"]).unwrap();
+        let err = verify_presigned_request(request, "ak", "cn-hangzhou", Utc::now() + Duration::seconds(601), |s, d| {
+            client.calculate_signature(s, d)
+        })
+        .unwrap_err();
+        assert!(matches!(err, Error::PresignedUrl(PresignedUrlError::Expired)));
+    }
+
+    #[test]
+    fn test_verify_presigned_url_path_style() {
+        let client = ClientBuilder::new("ak", "sk", "http://127.0.0.1:9000")
+            .region("cn-hangzhou")
+            .path_style(true)
+            .build()
+            .unwrap();
+
+        let signed = client.presign_head_object("example-bucket", "a/b.txt", PresignOptionsBuilder::new(60).build());
+        assert!(signed.url.starts_with("http://127.0.0.1:9000/example-bucket/a/b.txt?"));
+
+        let info = client.verify_presigned_url(&signed.url, RequestMethod::Head, &HashMap::new()).unwrap();
+        assert_eq!("example-bucket", info.bucket_name);
+        assert_eq!("a/b.txt", info.object_key);
+    }
+
+    #[test]
+    fn test_verify_presigned_url_unsupported() {
+        let signed = ClientBuilder::new("ak", "sk", "oss-cn-hangzhou.aliyuncs.com")
+            .region("cn-hangzhou")
+            .build()
+            .unwrap()
+            .presign_head_object("example-bucket", "a/b.txt", PresignOptionsBuilder::new(60).build());
+
+        let client = ClientBuilder::new("ak", "sk", "oss-cn-hangzhou.aliyuncs.com")
+            .signature_version(SignatureVersion::V1)
+            .build()
+            .unwrap();
+        let err = client.verify_presigned_url(&signed.url, RequestMethod::Head, &HashMap::new()).unwrap_err();
+        assert!(matches!(err, Error::PresignedUrl(PresignedUrlError::Unsupported(_))));
+
+        let client = ClientBuilder::new("ak", "sk", "static.example.com")
+            .region("cn-hangzhou")
+            .cname(true)
+            .build()
+            .unwrap();
+        let err = client.verify_presigned_url(&signed.url, RequestMethod::Head, &HashMap::new()).unwrap_err();
+        assert!(matches!(err, Error::PresignedUrl(PresignedUrlError::Unsupported(_))));
+    }
+
+    #[test]
+    fn test_presign_v1() {
+        let client = ClientBuilder::new(
+            "44CF9590006BF252F707",
+            "OtxrzxIsfpFjA7SwPzILwy8Bw21TLhquhboDYROV",
+            "oss-cn-hangzhou.aliyuncs.com",
+        )
+        .signature_version(SignatureVersion::V1)
+        .build()
+        .unwrap();
+
+        let mut request = build_presign_get_request("oss-example", "oss-api.pdf", &PresignGetOptionsBuilder::new(60).build());
+        request.query_mut().insert("x-oss-date".to_string(), "20060309T072420Z".to_string());
+
+        let request = client.sign_presign_request(request);
+        assert_eq!(3, request.query.len());
+        assert_eq!(Some(&"44CF9590006BF252F707".to_string()), request.query.get("OSSAccessKeyId"));
+        assert_eq!(Some(&"1141889120".to_string()), request.query.get("Expires"));
+        assert_eq!(Some(&"EwaNTn1erJGkimiJ9WmXgwnANLc=".to_string()), request.query.get("Signature"));
+
+        let url = client.request_url(&request);
+        assert_eq!(
+            "https://oss-example.oss-cn-hangzhou.aliyuncs.com/oss-api.pdf?Expires=1141889120&OSSAccessKeyId=44CF9590006BF252F707&Signature=EwaNTn1erJGkimiJ9WmXgwnANLc%3D",
+            url
+        );
+
+        let signed = client.presign_put_object("oss-example", "a.txt", PresignOptionsBuilder::new(60).content_type("text/plain").build());
+        assert!(signed.url.contains("OSSAccessKeyId=44CF9590006BF252F707"));
+        assert!(!signed.url.contains("x-oss-"));
+        assert!(client.verify_presigned_url(&signed.url, RequestMethod::Put, &signed.headers).is_err());
+    }
+}
@@ -2,10 +2,13 @@
 
 use std::collections::HashMap;
 
+use chrono::{DateTime, Duration, NaiveDateTime, Utc};
+
 use crate::{
-    common,
+    common::{self, RequestPayer},
+    error::{Error, PresignedUrlError},
     request::{OssRequest, RequestMethod},
-    util,
+    util, Result,
 };
 
 /// Presign options for GET
@@ -33,6 +36,9 @@ pub struct PresignGetOptions {
 
     /// Additional query parameters added to the presigned url
     pub query_parameters: HashMap<String, String>,
+
+    /// Set to `RequestPayer::Requester` if the object is in a requester-pays bucket which is owned by others.
+    pub request_payer: Option<RequestPayer>,
 }
 
 /// Builder for `PresignGetOptions`
@@ -46,6 +52,7 @@ pub struct PresignGetOptionsBuilder {
     version_id: Option<String>,
     process: Option<String>,
     query_parameters: HashMap<String, String>,
+    request_payer: Option<RequestPayer>,
 }
 
 impl PresignGetOptionsBuilder {
@@ -96,6 +103,11 @@ impl PresignGetOptionsBuilder {
         self
     }
 
+    pub fn request_payer(mut self, request_payer: RequestPayer) -> Self {
+        self.request_payer = Some(request_payer);
+        self
+    }
+
     pub fn build(self) -> PresignGetOptions {
         PresignGetOptions {
             expire_seconds: self.expire_seconds,
@@ -106,6 +118,7 @@ impl PresignGetOptionsBuilder {
             version_id: self.version_id,
             process: self.process,
             query_parameters: self.query_parameters,
+            request_payer: self.request_payer,
         }
     }
 }
@@ -150,6 +163,10 @@ pub(crate) fn build_presign_get_request(bucket_name: &str, object_key: &str, opt
         request = request.add_query("versionId", s);
     }
 
+    if let Some(RequestPayer::Requester) = &options.request_payer {
+        request = request.add_query("x-oss-request-payer", "requester");
+    }
+
     if !options.query_parameters.is_empty() {
         for (k, v) in &options.query_parameters {
             request = request.add_query(k, v);
@@ -158,3 +175,338 @@ pub(crate) fn build_presign_get_request(bucket_name: &str, object_key: &str, opt
 
     request
 }
+
+/// Presign options for requests of any method, with the signature in the query string.
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde-support", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde-camelcase", serde(rename_all = "camelCase"))]
+pub struct PresignOptions {
+    /// Time to live for this URL in seconds.
+    ///
+    /// The minimum value is `1`, the maximum value is `604800` seconds (7 days) for regular access key id and secret.
+    /// If you use STSToken to generate a signed url, the maximum value is `43200` seconds (12 hours).
+    pub expire_seconds: u32,
+
+    /// Headers included in the signature. The request sent with the presigned url must carry these headers with the same values.
+    /// `content-type`, `content-md5` and `x-oss-*` headers are signed by default,
+    /// other headers are signed as additional headers.
+    pub headers: HashMap<String, String>,
+
+    /// Additional query parameters added to the presigned url
+    pub query_parameters: HashMap<String, String>,
+
+    /// Set to `RequestPayer::Requester` if the object is in a requester-pays bucket which is owned by others.
+    pub request_payer: Option<RequestPayer>,
+}
+
+/// Builder for `PresignOptions`
+#[derive(Debug, Default)]
+pub struct PresignOptionsBuilder {
+    expire_seconds: u32,
+    headers: HashMap<String, String>,
+    query_parameters: HashMap<String, String>,
+    request_payer: Option<RequestPayer>,
+}
+
+impl PresignOptionsBuilder {
+    pub fn new(expire_seconds: u32) -> Self {
+        Self {
+            expire_seconds,
+            ..Default::default()
+        }
+    }
+
+    pub fn expire_seconds(mut self, expire_seconds: u32) -> Self {
+        self.expire_seconds = expire_seconds;
+        self
+    }
+
+    pub fn content_type(self, content_type: impl Into<String>) -> Self {
+        self.header("content-type", content_type)
+    }
+
+    pub fn content_md5(self, content_md5: impl Into<String>) -> Self {
+        self.header("content-md5", content_md5)
+    }
+
+    /// Add user metadata. The key is prefixed with `x-oss-meta-` if it is not.
+    pub fn metadata(self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        let key = key.into();
+        let key = if key.to_lowercase().starts_with("x-oss-meta-") {
+            key
+        } else {
+            format!("x-oss-meta-{}", key)
+        };
+
+        self.header(key, value)
+    }
+
+    pub fn header(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.insert(key.into().to_lowercase(), value.into());
+        self
+    }
+
+    pub fn query_parameter(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.query_parameters.insert(key.into(), value.into());
+        self
+    }
+
+    pub fn request_payer(mut self, request_payer: RequestPayer) -> Self {
+        self.request_payer = Some(request_payer);
+        self
+    }
+
+    pub fn build(self) -> PresignOptions {
+        PresignOptions {
+            expire_seconds: self.expire_seconds,
+            headers: self.headers,
+            query_parameters: self.query_parameters,
+            request_payer: self.request_payer,
+        }
+    }
+}
+
+pub(crate) fn build_presign_request(method: RequestMethod, bucket_name: &str, object_key: &str, options: &PresignOptions) -> OssRequest {
+    let mut request = OssRequest::new()
+        .method(method)
+        .bucket(bucket_name)
+        .object(object_key)
+        .add_query("x-oss-expires", options.expire_seconds.to_string())
+        .add_query("x-oss-signature-version", common::SIGNATURE_VERSION);
+
+    let oss_date = request.headers_mut().remove("x-oss-date").unwrap_or(util::get_iso8601_date_time_string());
+    request = request.add_query("x-oss-date", oss_date);
+
+    // only the headers given in options are sent with the presigned url
+    request.headers_mut().clear();
+
+    for (k, v) in &options.headers {
+        let k = k.to_lowercase();
+        let additional = k != "content-type" && k != "content-md5" && !k.starts_with("x-oss-");
+        request = request.add_header_ext(k, v, additional);
+    }
+
+    if !request.additional_headers.is_empty() {
+        let additional_headers = request.build_additional_headers();
+        request = request.add_query("x-oss-additional-headers", additional_headers);
+    }
+
+    if let Some(RequestPayer::Requester) = &options.request_payer {
+        request = request.add_query("x-oss-request-payer", "requester");
+    }
+
+    for (k, v) in &options.query_parameters {
+        request = request.add_query(k, v);
+    }
+
+    request
+}
+
+/// Parsed information of a verified presigned url
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde-support", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde-camelcase", serde(rename_all = "camelCase"))]
+pub struct PresignedUrlInfo {
+    pub bucket_name: String,
+    pub object_key: String,
+    pub access_key_id: String,
+    pub region: String,
+
+    /// Signing time in ISO8601 format, e.g. `20231203T121212Z`
+    pub date_time: String,
+
+    pub expire_seconds: u32,
+
+    /// Names of the additional headers which are included in the signature
+    pub additional_headers: Vec<String>,
+
+    pub security_token: Option<String>,
+
+    /// All query parameters except the signature
+    pub query: HashMap<String, String>,
+}
+
+impl PresignedUrlInfo {
+    /// The time after which the presigned url is expired
+    pub fn expires_at(&self) -> DateTime<Utc> {
+        let signed_at = NaiveDateTime::parse_from_str(&self.date_time, "%Y%m%dT%H%M%SZ").unwrap_or_default().and_utc();
+        signed_at + Duration::seconds(self.expire_seconds as i64)
+    }
+}
+
+fn malformed(message: impl Into<String>) -> Error {
+    Error::PresignedUrl(PresignedUrlError::Malformed(message.into()))
+}
+
+/// Resolve the bucket name, object key and query of a presigned url into a request.
+/// Only the headers which take part in the signature calculation are kept.
+///
+/// `endpoints` are the hosts which the bucket name is prefixed to in virtual-hosted style.
+pub(crate) fn parse_presigned_url(
+    url: &str,
+    method: RequestMethod,
+    headers: &HashMap<String, String>,
+    path_style: bool,
+    endpoints: &[&str],
+) -> Result<OssRequest> {
+    let url = url::Url::parse(url)?;
+
+    let path = urlencoding::decode(url.path()).map_err(|e| malformed(e.to_string()))?.to_string();
+    let path = path.strip_prefix('/').unwrap_or(&path);
+
+    let (bucket_name, object_key) = if path_style {
+        match path.split_once('/') {
+            Some((b, o)) => (b.to_string(), o.to_string()),
+            None => (path.to_string(), String::new()),
+        }
+    } else {
+        let host = match (url.host_str(), url.port()) {
+            (Some(h), Some(p)) => format!("{}:{}", h, p),
+            (Some(h), None) => h.to_string(),
+            _ => return Err(malformed("host is missing")),
+        };
+
+        let bucket_name = endpoints
+            .iter()
+            .find_map(|e| host.strip_suffix(e).and_then(|s| s.strip_suffix('.')))
+            .ok_or_else(|| malformed(format!("can not resolve bucket name from host: {}", host)))?;
+
+        (bucket_name.to_string(), path.to_string())
+    };
+
+    let mut request = OssRequest::new().method(method).bucket(bucket_name).object(object_key);
+    request.headers_mut().clear();
+
+    for (k, v) in url.query_pairs() {
+        request = request.add_query(k, v);
+    }
+
+    let additional_headers = request.query.get("x-oss-additional-headers").cloned().unwrap_or_default();
+    for name in additional_headers.split(';').filter(|s| !s.is_empty()) {
+        request = request.add_additional_header_name(name);
+    }
+
+    for (k, v) in headers {
+        let k = k.to_lowercase();
+        if k == "content-type" || k == "content-md5" || k.starts_with("x-oss-") || request.additional_headers.contains(&k) {
+            request = request.add_header(k, v);
+        }
+    }
+
+    Ok(request)
+}
+
+/// Verify the credential scope, signature and expiration of the presigned request which is built by `parse_presigned_url`.
+/// `signer` calculates the signature with string to sign and date string (e.g. `20231203`).
+pub(crate) fn verify_presigned_request<F>(mut request: OssRequest, access_key_id: &str, region: &str, now: DateTime<Utc>, signer: F) -> Result<PresignedUrlInfo>
+where
+    F: Fn(&str, &str) -> String,
+{
+    let signature = request
+        .query_mut()
+        .remove("x-oss-signature")
+        .ok_or_else(|| malformed("x-oss-signature is missing"))?;
+
+    let query_value = |k: &str| request.query.get(k).cloned().ok_or_else(|| malformed(format!("{} is missing", k)));
+
+    let signature_version = query_value("x-oss-signature-version")?;
+    if signature_version != common::SIGNATURE_VERSION {
+        return Err(malformed(format!("unsupported signature version: {}", signature_version)));
+    }
+
+    let date_time = query_value("x-oss-date")?;
+    if NaiveDateTime::parse_from_str(&date_time, "%Y%m%dT%H%M%SZ").is_err() {
+        return Err(malformed(format!("invalid x-oss-date: {}", date_time)));
+    }
+    let date_string = &date_time[..8];
+
+    let expire_seconds = query_value("x-oss-expires")?
+        .parse::<u32>()
+        .map_err(|_| malformed("x-oss-expires is not a valid number"))?;
+    if !(1..=604800).contains(&expire_seconds) {
+        return Err(malformed(format!("x-oss-expires is out of range: {}", expire_seconds)));
+    }
+
+    let credential = query_value("x-oss-credential")?;
+    let expected_credential = format!("{}/{}/{}/oss/aliyun_v4_request", access_key_id, date_string, region);
+    if credential != expected_credential {
+        return Err(Error::PresignedUrl(PresignedUrlError::CredentialMismatch(format!(
+            "expected {}, got {}",
+            expected_credential, credential
+        ))));
+    }
+
+    let canonical_request = request.build_canonical_request();
+    let string_to_sign = format!(
+        "{}\n{}\n{}/{}/oss/aliyun_v4_request\n{}",
+        common::SIGNATURE_VERSION,
+        date_time,
+        date_string,
+        region,
+        hex::encode(util::sha256(canonical_request.as_bytes()))
+    );
+
+    let expected_signature = signer(&string_to_sign, date_string);
+
+    // compare in constant time
+    let matched = expected_signature.len() == signature.len() && expected_signature.bytes().zip(signature.bytes()).fold(0u8, |acc, (a, b)| acc | (a ^ b)) == 0;
+
+    if !matched {
+        return Err(Error::PresignedUrl(PresignedUrlError::SignatureMismatch));
+    }
+
+    let mut additional_headers = request.additional_headers.iter().cloned().collect::<Vec<_>>();
+    additional_headers.sort();
+
+    let info = PresignedUrlInfo {
+        bucket_name: request.bucket_name.clone(),
+        object_key: request.object_key.clone(),
+        access_key_id: access_key_id.to_string(),
+        region: region.to_string(),
+        date_time,
+        expire_seconds,
+        additional_headers,
+        security_token: request.query.get("x-oss-security-token").cloned(),
+        query: request.query,
+    };
+
+    if now > info.expires_at() {
+        return Err(Error::PresignedUrl(PresignedUrlError::Expired));
+    }
+
+    Ok(info)
+}
+
+#[cfg(test)]
+mod test_presign_common {
+    use crate::request::RequestMethod;
+
+    use super::{build_presign_request, PresignOptionsBuilder};
+
+    #[test]
+    fn test_build_presign_request() {
+        let options = PresignOptionsBuilder::new(3600)
+            .content_type("video/mp4")
+            .metadata("owner", "yuanyq")
+            .header("Cache-Control", "no-cache")
+            .query_parameter("partNumber", "1")
+            .build();
+
+        let request = build_presign_request(RequestMethod::Put, "example-bucket", "videos/a.mp4", &options);
+
+        assert_eq!(RequestMethod::Put, request.method);
+        assert_eq!(3, request.headers.len());
+        assert_eq!(Some(&"video/mp4".to_string()), request.headers.get("content-type"));
+        assert_eq!(Some(&"yuanyq".to_string()), request.headers.get("x-oss-meta-owner"));
+        assert_eq!(Some(&"no-cache".to_string()), request.headers.get("cache-control"));
+
+        assert_eq!(Some(&"3600".to_string()), request.query.get("x-oss-expires"));
+        assert_eq!(Some(&"cache-control".to_string()), request.query.get("x-oss-additional-headers"));
+        assert_eq!(Some(&"1".to_string()), request.query.get("partNumber"));
+        assert!(request.query.contains_key("x-oss-date"));
+
+        let canonical_request = request.build_canonical_request();
+        assert!(canonical_request.starts_with("PUT\n/example-bucket/videos/a.mp4\n"));
+        assert!(canonical_request.contains("\ncache-control:no-cache\ncontent-type:video/mp4\nx-oss-meta-owner:yuanyq\n\ncache-control\nUNSIGNED-PAYLOAD"));
+    }
+}
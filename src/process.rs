@@ -0,0 +1,83 @@
+//! Data processing module
+
+use async_trait::async_trait;
+
+use crate::process_common::{
+    build_async_process_object_request, build_process_object_request, AsyncProcessObjectRequest, AsyncProcessObjectResult, ProcessObjectOptions,
+    ProcessObjectRequest, ProcessObjectResult,
+};
+use crate::{Client, Result};
+
+#[async_trait]
+pub trait ObjectProcessOperations {
+    /// Process an object, e.g. resize an image, and save the result to another object.
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/user-guide/sys-or-saveas>
+    async fn process_object<S1, S2>(
+        &self,
+        bucket_name: S1,
+        object_key: S2,
+        request: ProcessObjectRequest,
+        options: Option<ProcessObjectOptions>,
+    ) -> Result<ProcessObjectResult>
+    where
+        S1: AsRef<str> + Send,
+        S2: AsRef<str> + Send;
+
+    /// Submit an asynchronous processing task, e.g. video transcoding, video snapshots and document conversion.
+    /// The task result is sent to the notification topic if it is set.
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/user-guide/asynchronous-processing>
+    async fn async_process_object<S1, S2>(
+        &self,
+        bucket_name: S1,
+        object_key: S2,
+        request: AsyncProcessObjectRequest,
+        options: Option<ProcessObjectOptions>,
+    ) -> Result<AsyncProcessObjectResult>
+    where
+        S1: AsRef<str> + Send,
+        S2: AsRef<str> + Send;
+}
+
+#[async_trait]
+impl ObjectProcessOperations for Client {
+    /// Process an object, e.g. resize an image, and save the result to another object.
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/user-guide/sys-or-saveas>
+    async fn process_object<S1, S2>(
+        &self,
+        bucket_name: S1,
+        object_key: S2,
+        request: ProcessObjectRequest,
+        options: Option<ProcessObjectOptions>,
+    ) -> Result<ProcessObjectResult>
+    where
+        S1: AsRef<str> + Send,
+        S2: AsRef<str> + Send,
+    {
+        let request = build_process_object_request(bucket_name.as_ref(), object_key.as_ref(), &request, &options)?;
+        let (_, content) = self.do_request::<String>(request).await?;
+        Ok(serde_json::from_str(&content)?)
+    }
+
+    /// Submit an asynchronous processing task, e.g. video transcoding, video snapshots and document conversion.
+    /// The task result is sent to the notification topic if it is set.
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/user-guide/asynchronous-processing>
+    async fn async_process_object<S1, S2>(
+        &self,
+        bucket_name: S1,
+        object_key: S2,
+        request: AsyncProcessObjectRequest,
+        options: Option<ProcessObjectOptions>,
+    ) -> Result<AsyncProcessObjectResult>
+    where
+        S1: AsRef<str> + Send,
+        S2: AsRef<str> + Send,
+    {
+        let request = build_async_process_object_request(bucket_name.as_ref(), object_key.as_ref(), &request, &options)?;
+        let (_, content) = self.do_request::<String>(request).await?;
+        Ok(serde_json::from_str(&content)?)
+    }
+}
@@ -0,0 +1,1482 @@
+//! Common types for data processing, e.g. image processing (`x-oss-process`)
+//! and asynchronous processing of videos and documents (`x-oss-async-process`).
+//!
+//! Official document: <https://help.aliyun.com/zh/oss/user-guide/img-parameters>
+
+use std::fmt::Display;
+
+use base64::{prelude::BASE64_URL_SAFE_NO_PAD, Engine};
+
+use crate::{
+    common::{apply_request_payer, RequestPayer},
+    error::Error,
+    request::{OssRequest, RequestMethod},
+    util::{validate_bucket_name, validate_object_key},
+    Result,
+};
+
+/// Encode the value with URL safe base64 without padding, which is required by parameters of `x-oss-process`,
+/// e.g. watermark text, `sys/saveas` object key.
+pub(crate) fn encode_process_value(value: &str) -> String {
+    BASE64_URL_SAFE_NO_PAD.encode(value)
+}
+
+///
+/// Resize mode of image.
+///
+/// - `Lfit`: Scale proportionally to the largest image which fits in the width and height. This is the default value.
+/// - `Mfit`: Scale proportionally to the smallest image which covers the width and height.
+/// - `Fill`: Scale proportionally to cover the width and height, then crop from the center.
+/// - `Pad`: Scale proportionally to fit in the width and height, then pad with `color`.
+/// - `Fixed`: Scale to the width and height without keeping the aspect ratio.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde-support", derive(serde::Serialize, serde::Deserialize))]
+pub enum ResizeMode {
+    #[default]
+    #[cfg_attr(feature = "serde-support", serde(rename = "lfit"))]
+    Lfit,
+
+    #[cfg_attr(feature = "serde-support", serde(rename = "mfit"))]
+    Mfit,
+
+    #[cfg_attr(feature = "serde-support", serde(rename = "fill"))]
+    Fill,
+
+    #[cfg_attr(feature = "serde-support", serde(rename = "pad"))]
+    Pad,
+
+    #[cfg_attr(feature = "serde-support", serde(rename = "fixed"))]
+    Fixed,
+}
+
+impl ResizeMode {
+    pub fn as_str(&self) -> &str {
+        match self {
+            ResizeMode::Lfit => "lfit",
+            ResizeMode::Mfit => "mfit",
+            ResizeMode::Fill => "fill",
+            ResizeMode::Pad => "pad",
+            ResizeMode::Fixed => "fixed",
+        }
+    }
+}
+
+impl AsRef<str> for ResizeMode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl Display for ResizeMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl TryFrom<&str> for ResizeMode {
+    type Error = crate::error::Error;
+
+    fn try_from(value: &str) -> std::result::Result<Self, Self::Error> {
+        match value {
+            "lfit" => Ok(ResizeMode::Lfit),
+            "mfit" => Ok(ResizeMode::Mfit),
+            "fill" => Ok(ResizeMode::Fill),
+            "pad" => Ok(ResizeMode::Pad),
+            "fixed" => Ok(ResizeMode::Fixed),
+            _ => Err(Error::InvalidEnumValue {
+                name: "ResizeMode",
+                value: value.to_string(),
+            }),
+        }
+    }
+}
+
+impl TryFrom<String> for ResizeMode {
+    type Error = crate::error::Error;
+
+    fn try_from(value: String) -> std::result::Result<Self, Self::Error> {
+        Self::try_from(value.as_str())
+    }
+}
+
+impl TryFrom<&String> for ResizeMode {
+    type Error = crate::error::Error;
+
+    fn try_from(value: &String) -> std::result::Result<Self, Self::Error> {
+        Self::try_from(value.as_str())
+    }
+}
+
+/// Position of cropping area or watermark in the image
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde-support", derive(serde::Serialize, serde::Deserialize))]
+pub enum Gravity {
+    #[cfg_attr(feature = "serde-support", serde(rename = "nw"))]
+    NorthWest,
+
+    #[cfg_attr(feature = "serde-support", serde(rename = "north"))]
+    North,
+
+    #[cfg_attr(feature = "serde-support", serde(rename = "ne"))]
+    NorthEast,
+
+    #[cfg_attr(feature = "serde-support", serde(rename = "west"))]
+    West,
+
+    #[default]
+    #[cfg_attr(feature = "serde-support", serde(rename = "center"))]
+    Center,
+
+    #[cfg_attr(feature = "serde-support", serde(rename = "east"))]
+    East,
+
+    #[cfg_attr(feature = "serde-support", serde(rename = "sw"))]
+    SouthWest,
+
+    #[cfg_attr(feature = "serde-support", serde(rename = "south"))]
+    South,
+
+    #[cfg_attr(feature = "serde-support", serde(rename = "se"))]
+    SouthEast,
+}
+
+impl Gravity {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Gravity::NorthWest => "nw",
+            Gravity::North => "north",
+            Gravity::NorthEast => "ne",
+            Gravity::West => "west",
+            Gravity::Center => "center",
+            Gravity::East => "east",
+            Gravity::SouthWest => "sw",
+            Gravity::South => "south",
+            Gravity::SouthEast => "se",
+        }
+    }
+}
+
+impl AsRef<str> for Gravity {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl Display for Gravity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl TryFrom<&str> for Gravity {
+    type Error = crate::error::Error;
+
+    fn try_from(value: &str) -> std::result::Result<Self, Self::Error> {
+        match value {
+            "nw" => Ok(Gravity::NorthWest),
+            "north" => Ok(Gravity::North),
+            "ne" => Ok(Gravity::NorthEast),
+            "west" => Ok(Gravity::West),
+            "center" => Ok(Gravity::Center),
+            "east" => Ok(Gravity::East),
+            "sw" => Ok(Gravity::SouthWest),
+            "south" => Ok(Gravity::South),
+            "se" => Ok(Gravity::SouthEast),
+            _ => Err(Error::InvalidEnumValue {
+                name: "Gravity",
+                value: value.to_string(),
+            }),
+        }
+    }
+}
+
+impl TryFrom<String> for Gravity {
+    type Error = crate::error::Error;
+
+    fn try_from(value: String) -> std::result::Result<Self, Self::Error> {
+        Self::try_from(value.as_str())
+    }
+}
+
+impl TryFrom<&String> for Gravity {
+    type Error = crate::error::Error;
+
+    fn try_from(value: &String) -> std::result::Result<Self, Self::Error> {
+        Self::try_from(value.as_str())
+    }
+}
+
+/// Target format of image format conversion
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde-support", derive(serde::Serialize, serde::Deserialize))]
+pub enum ImageFormat {
+    #[cfg_attr(feature = "serde-support", serde(rename = "jpg"))]
+    Jpg,
+
+    #[cfg_attr(feature = "serde-support", serde(rename = "png"))]
+    Png,
+
+    #[cfg_attr(feature = "serde-support", serde(rename = "webp"))]
+    Webp,
+
+    #[cfg_attr(feature = "serde-support", serde(rename = "bmp"))]
+    Bmp,
+
+    #[cfg_attr(feature = "serde-support", serde(rename = "gif"))]
+    Gif,
+
+    #[cfg_attr(feature = "serde-support", serde(rename = "tiff"))]
+    Tiff,
+
+    #[cfg_attr(feature = "serde-support", serde(rename = "heic"))]
+    Heic,
+
+    #[cfg_attr(feature = "serde-support", serde(rename = "avif"))]
+    Avif,
+}
+
+impl ImageFormat {
+    pub fn as_str(&self) -> &str {
+        match self {
+            ImageFormat::Jpg => "jpg",
+            ImageFormat::Png => "png",
+            ImageFormat::Webp => "webp",
+            ImageFormat::Bmp => "bmp",
+            ImageFormat::Gif => "gif",
+            ImageFormat::Tiff => "tiff",
+            ImageFormat::Heic => "heic",
+            ImageFormat::Avif => "avif",
+        }
+    }
+}
+
+impl AsRef<str> for ImageFormat {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl Display for ImageFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl TryFrom<&str> for ImageFormat {
+    type Error = crate::error::Error;
+
+    fn try_from(value: &str) -> std::result::Result<Self, Self::Error> {
+        match value {
+            "jpg" => Ok(ImageFormat::Jpg),
+            "png" => Ok(ImageFormat::Png),
+            "webp" => Ok(ImageFormat::Webp),
+            "bmp" => Ok(ImageFormat::Bmp),
+            "gif" => Ok(ImageFormat::Gif),
+            "tiff" => Ok(ImageFormat::Tiff),
+            "heic" => Ok(ImageFormat::Heic),
+            "avif" => Ok(ImageFormat::Avif),
+            _ => Err(Error::InvalidEnumValue {
+                name: "ImageFormat",
+                value: value.to_string(),
+            }),
+        }
+    }
+}
+
+impl TryFrom<String> for ImageFormat {
+    type Error = crate::error::Error;
+
+    fn try_from(value: String) -> std::result::Result<Self, Self::Error> {
+        Self::try_from(value.as_str())
+    }
+}
+
+impl TryFrom<&String> for ImageFormat {
+    type Error = crate::error::Error;
+
+    fn try_from(value: &String) -> std::result::Result<Self, Self::Error> {
+        Self::try_from(value.as_str())
+    }
+}
+
+///
+/// Image quality.
+///
+/// - `Relative(q)`: Compress to `q`% of the original quality, serialized as `q_{q}`.
+/// - `Absolute(q)`: Compress to quality `q`, serialized as `Q_{q}`.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde-support", derive(serde::Serialize, serde::Deserialize))]
+pub enum ImageQuality {
+    Relative(u32),
+    Absolute(u32),
+}
+
+/// Parameters of image resizing.
+///
+/// Official document: <https://help.aliyun.com/zh/oss/user-guide/resize-images-4>
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde-support", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde-camelcase", serde(rename_all = "camelCase"))]
+pub struct ImageResize {
+    pub mode: Option<ResizeMode>,
+    pub width: Option<u32>,
+    pub height: Option<u32>,
+
+    /// Length of the longer side
+    pub long: Option<u32>,
+
+    /// Length of the shorter side
+    pub short: Option<u32>,
+
+    /// Scale by percentage, 1 to 1000
+    pub percentage: Option<u32>,
+
+    /// Whether to limit the result to be not larger than the original image. Default is `true`
+    pub limit: Option<bool>,
+
+    /// Padding color for `ResizeMode::Pad`, hex RGB, e.g. `FFFFFF`
+    pub color: Option<String>,
+}
+
+impl ImageResize {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn mode(mut self, mode: ResizeMode) -> Self {
+        self.mode = Some(mode);
+        self
+    }
+
+    pub fn width(mut self, width: u32) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    pub fn height(mut self, height: u32) -> Self {
+        self.height = Some(height);
+        self
+    }
+
+    pub fn long(mut self, long: u32) -> Self {
+        self.long = Some(long);
+        self
+    }
+
+    pub fn short(mut self, short: u32) -> Self {
+        self.short = Some(short);
+        self
+    }
+
+    pub fn percentage(mut self, percentage: u32) -> Self {
+        self.percentage = Some(percentage);
+        self
+    }
+
+    pub fn limit(mut self, limit: bool) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn color(mut self, color: impl Into<String>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+}
+
+impl Display for ImageResize {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "resize")?;
+
+        if let Some(p) = self.percentage {
+            return write!(f, ",p_{}", p);
+        }
+
+        if let Some(m) = &self.mode {
+            write!(f, ",m_{}", m)?;
+        }
+
+        if let Some(n) = self.width {
+            write!(f, ",w_{}", n)?;
+        }
+
+        if let Some(n) = self.height {
+            write!(f, ",h_{}", n)?;
+        }
+
+        if let Some(n) = self.long {
+            write!(f, ",l_{}", n)?;
+        }
+
+        if let Some(n) = self.short {
+            write!(f, ",s_{}", n)?;
+        }
+
+        if let Some(b) = self.limit {
+            write!(f, ",limit_{}", if b { 1 } else { 0 })?;
+        }
+
+        if let Some(s) = &self.color {
+            write!(f, ",color_{}", s)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Parameters of image cropping.
+///
+/// Official document: <https://help.aliyun.com/zh/oss/user-guide/custom-crop>
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde-support", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde-camelcase", serde(rename_all = "camelCase"))]
+pub struct ImageCrop {
+    pub width: Option<u32>,
+    pub height: Option<u32>,
+
+    /// Offset relative to `gravity`
+    pub x: Option<u32>,
+    pub y: Option<u32>,
+
+    /// The start point of cropping. Default is `Gravity::NorthWest`
+    pub gravity: Option<Gravity>,
+}
+
+impl ImageCrop {
+    pub fn new(width: u32, height: u32) -> Self {
+        Self {
+            width: Some(width),
+            height: Some(height),
+            ..Default::default()
+        }
+    }
+
+    pub fn offset(mut self, x: u32, y: u32) -> Self {
+        self.x = Some(x);
+        self.y = Some(y);
+        self
+    }
+
+    pub fn gravity(mut self, gravity: Gravity) -> Self {
+        self.gravity = Some(gravity);
+        self
+    }
+}
+
+impl Display for ImageCrop {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "crop")?;
+
+        if let Some(n) = self.width {
+            write!(f, ",w_{}", n)?;
+        }
+
+        if let Some(n) = self.height {
+            write!(f, ",h_{}", n)?;
+        }
+
+        if let Some(n) = self.x {
+            write!(f, ",x_{}", n)?;
+        }
+
+        if let Some(n) = self.y {
+            write!(f, ",y_{}", n)?;
+        }
+
+        if let Some(g) = &self.gravity {
+            write!(f, ",g_{}", g)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Parameters of image watermark. Either text or image (or both) should be set.
+///
+/// Official document: <https://help.aliyun.com/zh/oss/user-guide/add-watermarks>
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde-support", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde-camelcase", serde(rename_all = "camelCase"))]
+pub struct ImageWatermark {
+    /// Watermark text. It is encoded by this library.
+    pub text: Option<String>,
+
+    /// Key of the watermark image object in the same bucket,
+    /// which can have process parameters, e.g. `panda.png?x-oss-process=image/resize,P_30`. It is encoded by this library.
+    pub image: Option<String>,
+
+    /// Font of the text, e.g. `wqy-zenhei`. It is encoded by this library.
+    pub font: Option<String>,
+
+    /// Color of the text, hex RGB, e.g. `000000`
+    pub color: Option<String>,
+
+    /// Font size of the text
+    pub size: Option<u32>,
+
+    /// Transparency, 0 to 100
+    pub transparency: Option<u32>,
+    pub gravity: Option<Gravity>,
+
+    /// Horizontal and vertical margin to the edges
+    pub x: Option<u32>,
+    pub y: Option<u32>,
+}
+
+impl ImageWatermark {
+    pub fn text(text: impl Into<String>) -> Self {
+        Self {
+            text: Some(text.into()),
+            ..Default::default()
+        }
+    }
+
+    pub fn image(image_object_key: impl Into<String>) -> Self {
+        Self {
+            image: Some(image_object_key.into()),
+            ..Default::default()
+        }
+    }
+
+    pub fn font(mut self, font: impl Into<String>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    pub fn color(mut self, color: impl Into<String>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+
+    pub fn size(mut self, size: u32) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    pub fn transparency(mut self, transparency: u32) -> Self {
+        self.transparency = Some(transparency);
+        self
+    }
+
+    pub fn gravity(mut self, gravity: Gravity) -> Self {
+        self.gravity = Some(gravity);
+        self
+    }
+
+    pub fn margin(mut self, x: u32, y: u32) -> Self {
+        self.x = Some(x);
+        self.y = Some(y);
+        self
+    }
+}
+
+impl Display for ImageWatermark {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "watermark")?;
+
+        if let Some(s) = &self.image {
+            write!(f, ",image_{}", encode_process_value(s))?;
+        }
+
+        if let Some(s) = &self.text {
+            write!(f, ",text_{}", encode_process_value(s))?;
+        }
+
+        if let Some(s) = &self.font {
+            write!(f, ",type_{}", encode_process_value(s))?;
+        }
+
+        if let Some(s) = &self.color {
+            write!(f, ",color_{}", s)?;
+        }
+
+        if let Some(n) = self.size {
+            write!(f, ",size_{}", n)?;
+        }
+
+        if let Some(n) = self.transparency {
+            write!(f, ",t_{}", n)?;
+        }
+
+        if let Some(g) = &self.gravity {
+            write!(f, ",g_{}", g)?;
+        }
+
+        if let Some(n) = self.x {
+            write!(f, ",x_{}", n)?;
+        }
+
+        if let Some(n) = self.y {
+            write!(f, ",y_{}", n)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Build an image process string such as `image/resize,w_100/quality,q_90/format,webp`.
+/// Actions are applied in the order they are added.
+///
+/// Official document: <https://help.aliyun.com/zh/oss/user-guide/img-parameters>
+///
+/// # Examples
+///
+/// ```
+/// use ali_oss_rs::process_common::{ImageFormat, ImageProcess, ImageResize, ResizeMode};
+///
+/// let process = ImageProcess::new()
+///     .resize(ImageResize::new().mode(ResizeMode::Fill).width(200).height(200))
+///     .format(ImageFormat::Webp);
+///
+/// assert_eq!("image/resize,m_fill,w_200,h_200/format,webp", process.to_string());
+/// ```
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde-support", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde-camelcase", serde(rename_all = "camelCase"))]
+pub struct ImageProcess {
+    actions: Vec<String>,
+}
+
+impl ImageProcess {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn resize(mut self, resize: ImageResize) -> Self {
+        self.actions.push(resize.to_string());
+        self
+    }
+
+    pub fn crop(mut self, crop: ImageCrop) -> Self {
+        self.actions.push(crop.to_string());
+        self
+    }
+
+    /// Rotate clockwise by degrees, 0 to 360
+    pub fn rotate(mut self, degree: u32) -> Self {
+        self.actions.push(format!("rotate,{}", degree));
+        self
+    }
+
+    pub fn watermark(mut self, watermark: ImageWatermark) -> Self {
+        self.actions.push(watermark.to_string());
+        self
+    }
+
+    pub fn format(mut self, format: ImageFormat) -> Self {
+        self.actions.push(format!("format,{}", format));
+        self
+    }
+
+    pub fn quality(mut self, quality: ImageQuality) -> Self {
+        let action = match quality {
+            ImageQuality::Relative(q) => format!("quality,q_{}", q),
+            ImageQuality::Absolute(q) => format!("quality,Q_{}", q),
+        };
+        self.actions.push(action);
+        self
+    }
+
+    /// Rotate the image according to its EXIF orientation
+    pub fn auto_orient(mut self, auto_orient: bool) -> Self {
+        self.actions.push(format!("auto-orient,{}", if auto_orient { 1 } else { 0 }));
+        self
+    }
+
+    /// Return the information of the image as JSON, e.g. size, format, width, height and EXIF
+    pub fn info(mut self) -> Self {
+        self.actions.push("info".to_string());
+        self
+    }
+
+    /// Add an action which is not covered by this builder, e.g. `blur,r_3,s_2`
+    pub fn action(mut self, action: impl Into<String>) -> Self {
+        self.actions.push(action.into());
+        self
+    }
+}
+
+impl Display for ImageProcess {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "image")?;
+
+        for a in &self.actions {
+            write!(f, "/{}", a)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl From<ImageProcess> for String {
+    fn from(value: ImageProcess) -> Self {
+        value.to_string()
+    }
+}
+
+/// Request of processing an object and saving the result to another object
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde-support", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde-camelcase", serde(rename_all = "camelCase"))]
+pub struct ProcessObjectRequest {
+    /// The process string, e.g. `image/resize,w_100`, or an [`ImageProcess`]
+    pub process: String,
+
+    /// Object key to save the processed result
+    pub target_object_key: String,
+
+    /// Bucket to save the processed result. It is the same bucket as the source object if not set.
+    pub target_bucket_name: Option<String>,
+}
+
+impl ProcessObjectRequest {
+    pub fn new(process: impl Into<String>, target_object_key: impl Into<String>) -> Self {
+        Self {
+            process: process.into(),
+            target_object_key: target_object_key.into(),
+            target_bucket_name: None,
+        }
+    }
+
+    pub fn target_bucket_name(mut self, bucket_name: impl Into<String>) -> Self {
+        self.target_bucket_name = Some(bucket_name.into());
+        self
+    }
+}
+
+/// Options for processing object
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde-support", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde-camelcase", serde(rename_all = "camelCase"))]
+pub struct ProcessObjectOptions {
+    pub request_payer: Option<RequestPayer>,
+}
+
+/// Result of processing object
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProcessObjectResult {
+    pub bucket: String,
+    pub file_size: u64,
+    pub object: String,
+    pub status: String,
+}
+
+/// Parameters of video transcoding, serialized as `video/convert,f_mp4,vcodec_h264,...`.
+/// Time is in milliseconds and bitrates are in bit/s.
+///
+/// Official document: <https://help.aliyun.com/zh/oss/user-guide/video-transcoding>
+///
+/// # Examples
+///
+/// ```
+/// use ali_oss_rs::process_common::{AsyncProcessObjectRequestBuilder, VideoConvert};
+///
+/// let convert = VideoConvert::new("mp4").video_codec("h265").resolution(1920, 1080).video_bitrate(2000000).audio_codec("aac");
+/// assert_eq!("video/convert,f_mp4,vcodec_h265,s_1920x1080,vb_2000000,acodec_aac", convert.to_string());
+///
+/// let request = AsyncProcessObjectRequestBuilder::new(convert).target_object_key("videos/a.mp4").build();
+/// ```
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde-support", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde-camelcase", serde(rename_all = "camelCase"))]
+pub struct VideoConvert {
+    /// Container format, e.g. `mp4`, `mkv`, `ts`, `flv`, `webm`, or `mp3`, `aac` for audio only
+    pub format: String,
+
+    /// Start time of transcoding
+    pub start: Option<u64>,
+
+    /// Duration of transcoding from `start`
+    pub duration: Option<u64>,
+
+    /// Video codec, e.g. `h264`, `h265`, `vp9`
+    pub video_codec: Option<String>,
+
+    /// Resolution in `(width, height)`
+    pub resolution: Option<(u32, u32)>,
+
+    /// Frame rate
+    pub fps: Option<u32>,
+    pub video_bitrate: Option<u64>,
+
+    /// Remove the video stream
+    pub disable_video: bool,
+
+    /// Audio codec, e.g. `aac`, `mp3`, `opus`
+    pub audio_codec: Option<String>,
+    pub audio_bitrate: Option<u64>,
+
+    /// Audio sample rate in Hz, e.g. `44100`
+    pub sample_rate: Option<u32>,
+
+    /// Number of audio channels
+    pub channels: Option<u32>,
+
+    /// Remove the audio stream
+    pub disable_audio: bool,
+
+    /// Parameters which are not covered by the fields, e.g. `("crf", "23")` for `crf_23`
+    pub params: Vec<(String, String)>,
+}
+
+impl VideoConvert {
+    pub fn new(format: impl Into<String>) -> Self {
+        Self {
+            format: format.into(),
+            ..Default::default()
+        }
+    }
+
+    pub fn start(mut self, start: u64) -> Self {
+        self.start = Some(start);
+        self
+    }
+
+    pub fn duration(mut self, duration: u64) -> Self {
+        self.duration = Some(duration);
+        self
+    }
+
+    pub fn video_codec(mut self, codec: impl Into<String>) -> Self {
+        self.video_codec = Some(codec.into());
+        self
+    }
+
+    pub fn resolution(mut self, width: u32, height: u32) -> Self {
+        self.resolution = Some((width, height));
+        self
+    }
+
+    pub fn fps(mut self, fps: u32) -> Self {
+        self.fps = Some(fps);
+        self
+    }
+
+    pub fn video_bitrate(mut self, bitrate: u64) -> Self {
+        self.video_bitrate = Some(bitrate);
+        self
+    }
+
+    pub fn disable_video(mut self) -> Self {
+        self.disable_video = true;
+        self
+    }
+
+    pub fn audio_codec(mut self, codec: impl Into<String>) -> Self {
+        self.audio_codec = Some(codec.into());
+        self
+    }
+
+    pub fn audio_bitrate(mut self, bitrate: u64) -> Self {
+        self.audio_bitrate = Some(bitrate);
+        self
+    }
+
+    pub fn sample_rate(mut self, sample_rate: u32) -> Self {
+        self.sample_rate = Some(sample_rate);
+        self
+    }
+
+    pub fn channels(mut self, channels: u32) -> Self {
+        self.channels = Some(channels);
+        self
+    }
+
+    pub fn disable_audio(mut self) -> Self {
+        self.disable_audio = true;
+        self
+    }
+
+    pub fn param(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.params.push((name.into(), value.into()));
+        self
+    }
+}
+
+impl Display for VideoConvert {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "video/convert,f_{}", self.format)?;
+
+        if let Some(n) = self.start {
+            write!(f, ",ss_{}", n)?;
+        }
+
+        if let Some(n) = self.duration {
+            write!(f, ",t_{}", n)?;
+        }
+
+        if self.disable_video {
+            write!(f, ",vn_1")?;
+        }
+
+        if let Some(s) = &self.video_codec {
+            write!(f, ",vcodec_{}", s)?;
+        }
+
+        if let Some((w, h)) = self.resolution {
+            write!(f, ",s_{}x{}", w, h)?;
+        }
+
+        if let Some(n) = self.fps {
+            write!(f, ",fps_{}", n)?;
+        }
+
+        if let Some(n) = self.video_bitrate {
+            write!(f, ",vb_{}", n)?;
+        }
+
+        if self.disable_audio {
+            write!(f, ",an_1")?;
+        }
+
+        if let Some(s) = &self.audio_codec {
+            write!(f, ",acodec_{}", s)?;
+        }
+
+        if let Some(n) = self.audio_bitrate {
+            write!(f, ",ab_{}", n)?;
+        }
+
+        if let Some(n) = self.sample_rate {
+            write!(f, ",ar_{}", n)?;
+        }
+
+        if let Some(n) = self.channels {
+            write!(f, ",ac_{}", n)?;
+        }
+
+        for (k, v) in &self.params {
+            write!(f, ",{}_{}", k, v)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl From<VideoConvert> for String {
+    fn from(value: VideoConvert) -> Self {
+        value.to_string()
+    }
+}
+
+/// Parameters of video snapshots, serialized as `video/snapshots,ss_1000,f_jpg,...`.
+/// Time is in milliseconds.
+///
+/// Official document: <https://help.aliyun.com/zh/oss/user-guide/video-snapshots>
+///
+/// # Examples
+///
+/// ```
+/// use ali_oss_rs::process_common::VideoSnapshot;
+///
+/// let snapshot = VideoSnapshot::new("jpg").start(1000).size(320, 180).interval(10000).count(5);
+/// assert_eq!("video/snapshots,ss_1000,f_jpg,w_320,h_180,inter_10000,num_5", snapshot.to_string());
+/// ```
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde-support", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde-camelcase", serde(rename_all = "camelCase"))]
+pub struct VideoSnapshot {
+    /// Image format, `jpg` or `png`
+    pub format: String,
+
+    /// Time of the first snapshot
+    pub start: Option<u64>,
+
+    /// Snapshot mode, `inter` for the exact time or `key` for the nearest key frame
+    pub mode: Option<String>,
+    pub width: Option<u32>,
+    pub height: Option<u32>,
+
+    /// How the frame is scaled to `width` and `height`, e.g. `crop`, `stretch`, `fill`, `fit`
+    pub scale_type: Option<String>,
+
+    /// Interval between snapshots
+    pub interval: Option<u64>,
+
+    /// Maximum number of snapshots
+    pub count: Option<u32>,
+
+    /// Parameters which are not covered by the fields
+    pub params: Vec<(String, String)>,
+}
+
+impl VideoSnapshot {
+    pub fn new(format: impl Into<String>) -> Self {
+        Self {
+            format: format.into(),
+            ..Default::default()
+        }
+    }
+
+    pub fn start(mut self, start: u64) -> Self {
+        self.start = Some(start);
+        self
+    }
+
+    pub fn mode(mut self, mode: impl Into<String>) -> Self {
+        self.mode = Some(mode.into());
+        self
+    }
+
+    pub fn size(mut self, width: u32, height: u32) -> Self {
+        self.width = Some(width);
+        self.height = Some(height);
+        self
+    }
+
+    pub fn scale_type(mut self, scale_type: impl Into<String>) -> Self {
+        self.scale_type = Some(scale_type.into());
+        self
+    }
+
+    pub fn interval(mut self, interval: u64) -> Self {
+        self.interval = Some(interval);
+        self
+    }
+
+    pub fn count(mut self, count: u32) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    pub fn param(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.params.push((name.into(), value.into()));
+        self
+    }
+}
+
+impl Display for VideoSnapshot {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "video/snapshots")?;
+
+        if let Some(n) = self.start {
+            write!(f, ",ss_{}", n)?;
+        }
+
+        write!(f, ",f_{}", self.format)?;
+
+        if let Some(s) = &self.mode {
+            write!(f, ",m_{}", s)?;
+        }
+
+        if let Some(n) = self.width {
+            write!(f, ",w_{}", n)?;
+        }
+
+        if let Some(n) = self.height {
+            write!(f, ",h_{}", n)?;
+        }
+
+        if let Some(s) = &self.scale_type {
+            write!(f, ",scaletype_{}", s)?;
+        }
+
+        if let Some(n) = self.interval {
+            write!(f, ",inter_{}", n)?;
+        }
+
+        if let Some(n) = self.count {
+            write!(f, ",num_{}", n)?;
+        }
+
+        for (k, v) in &self.params {
+            write!(f, ",{}_{}", k, v)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl From<VideoSnapshot> for String {
+    fn from(value: VideoSnapshot) -> Self {
+        value.to_string()
+    }
+}
+
+/// Parameters of document conversion, serialized as `doc/convert,target_pdf,source_docx,...`.
+///
+/// Official document: <https://help.aliyun.com/zh/oss/user-guide/document-conversion>
+///
+/// # Examples
+///
+/// ```
+/// use ali_oss_rs::process_common::DocConvert;
+///
+/// let convert = DocConvert::new("png").source("docx").pages("1,2,4-10");
+/// assert_eq!("doc/convert,target_png,source_docx,pages_1,2,4-10", convert.to_string());
+/// ```
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde-support", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde-camelcase", serde(rename_all = "camelCase"))]
+pub struct DocConvert {
+    /// Target format, e.g. `pdf`, `png`, `jpg`, `txt`
+    pub target: String,
+
+    /// Source format, e.g. `docx`, `pptx`, `xlsx`. It is detected from the object key if not set.
+    pub source: Option<String>,
+
+    /// Pages to convert, e.g. `1,2,4-10`
+    pub pages: Option<String>,
+
+    /// Parameters which are not covered by the fields
+    pub params: Vec<(String, String)>,
+}
+
+impl DocConvert {
+    pub fn new(target: impl Into<String>) -> Self {
+        Self {
+            target: target.into(),
+            ..Default::default()
+        }
+    }
+
+    pub fn source(mut self, source: impl Into<String>) -> Self {
+        self.source = Some(source.into());
+        self
+    }
+
+    pub fn pages(mut self, pages: impl Into<String>) -> Self {
+        self.pages = Some(pages.into());
+        self
+    }
+
+    pub fn param(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.params.push((name.into(), value.into()));
+        self
+    }
+}
+
+impl Display for DocConvert {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "doc/convert,target_{}", self.target)?;
+
+        if let Some(s) = &self.source {
+            write!(f, ",source_{}", s)?;
+        }
+
+        if let Some(s) = &self.pages {
+            write!(f, ",pages_{}", s)?;
+        }
+
+        for (k, v) in &self.params {
+            write!(f, ",{}_{}", k, v)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl From<DocConvert> for String {
+    fn from(value: DocConvert) -> Self {
+        value.to_string()
+    }
+}
+
+/// Request of asynchronous processing, e.g. video transcoding, video snapshots and document conversion.
+/// The result is saved to the target object and the task status is sent to the notification topic (MNS).
+///
+/// Official document: <https://help.aliyun.com/zh/oss/user-guide/asynchronous-processing>
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde-support", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde-camelcase", serde(rename_all = "camelCase"))]
+pub struct AsyncProcessObjectRequest {
+    /// The process string, e.g. `video/convert,f_mp4,vcodec_h264` or `doc/convert,target_pdf,source_docx`,
+    /// or a [`VideoConvert`], [`VideoSnapshot`] or [`DocConvert`]
+    pub process: String,
+
+    /// Object key to save the processed result.
+    /// Variables such as `{index}` and `{autoext}` can be used for results with multiple objects.
+    pub target_object_key: Option<String>,
+
+    /// Bucket to save the processed result. It is the same bucket as the source object if not set.
+    pub target_bucket_name: Option<String>,
+
+    /// MNS topic to receive the task result
+    pub notify_topic: Option<String>,
+}
+
+#[derive(Debug, Default)]
+pub struct AsyncProcessObjectRequestBuilder {
+    request: AsyncProcessObjectRequest,
+}
+
+impl AsyncProcessObjectRequestBuilder {
+    pub fn new(process: impl Into<String>) -> Self {
+        Self {
+            request: AsyncProcessObjectRequest {
+                process: process.into(),
+                ..Default::default()
+            },
+        }
+    }
+
+    pub fn target_object_key(mut self, object_key: impl Into<String>) -> Self {
+        self.request.target_object_key = Some(object_key.into());
+        self
+    }
+
+    pub fn target_bucket_name(mut self, bucket_name: impl Into<String>) -> Self {
+        self.request.target_bucket_name = Some(bucket_name.into());
+        self
+    }
+
+    pub fn notify_topic(mut self, topic: impl Into<String>) -> Self {
+        self.request.notify_topic = Some(topic.into());
+        self
+    }
+
+    pub fn build(self) -> AsyncProcessObjectRequest {
+        self.request
+    }
+}
+
+impl AsyncProcessObjectRequest {
+    /// Build the full process string with `sys/saveas` and `notify`,
+    /// e.g. `video/convert,f_mp4|sys/saveas,b_xxx,o_xxx/notify,topic_xxx`
+    pub(crate) fn to_process_string(&self) -> String {
+        let mut ret = self.process.clone();
+
+        if self.target_object_key.is_some() || self.target_bucket_name.is_some() {
+            ret.push_str("|sys/saveas");
+
+            if let Some(s) = &self.target_bucket_name {
+                ret.push_str(&format!(",b_{}", encode_process_value(s)));
+            }
+
+            if let Some(s) = &self.target_object_key {
+                ret.push_str(&format!(",o_{}", encode_process_value(s)));
+            }
+        }
+
+        if let Some(s) = &self.notify_topic {
+            ret.push_str(&format!("/notify,topic_{}", encode_process_value(s)));
+        }
+
+        ret
+    }
+}
+
+/// Result of asynchronous processing
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct AsyncProcessObjectResult {
+    pub event_id: String,
+    pub request_id: String,
+    pub task_id: String,
+}
+
+pub(crate) fn build_process_object_request(
+    bucket_name: &str,
+    object_key: &str,
+    request: &ProcessObjectRequest,
+    options: &Option<ProcessObjectOptions>,
+) -> Result<OssRequest> {
+    if !validate_bucket_name(bucket_name) {
+        return Err(Error::InvalidBucketName(bucket_name.to_string()));
+    }
+
+    if !validate_object_key(object_key) {
+        return Err(Error::InvalidObjectKey(object_key.to_string()));
+    }
+
+    if request.process.is_empty() {
+        return Err(Error::InvalidArgument("process must not be empty".to_string()));
+    }
+
+    if !validate_object_key(&request.target_object_key) {
+        return Err(Error::InvalidObjectKey(request.target_object_key.to_string()));
+    }
+
+    let mut save_as = format!("sys/saveas,o_{}", encode_process_value(&request.target_object_key));
+
+    if let Some(s) = &request.target_bucket_name {
+        if !validate_bucket_name(s) {
+            return Err(Error::InvalidBucketName(s.to_string()));
+        }
+        save_as = format!("{},b_{}", save_as, encode_process_value(s));
+    }
+
+    let body = format!("x-oss-process={}|{}", request.process, save_as);
+
+    let mut request = OssRequest::new()
+        .method(RequestMethod::Post)
+        .bucket(bucket_name)
+        .object(object_key)
+        .add_query("x-oss-process", "")
+        .content_length(body.len() as u64)
+        .text_body(body);
+
+    if let Some(options) = options {
+        request = apply_request_payer(request, &options.request_payer);
+    }
+
+    Ok(request)
+}
+
+pub(crate) fn build_async_process_object_request(
+    bucket_name: &str,
+    object_key: &str,
+    request: &AsyncProcessObjectRequest,
+    options: &Option<ProcessObjectOptions>,
+) -> Result<OssRequest> {
+    if !validate_bucket_name(bucket_name) {
+        return Err(Error::InvalidBucketName(bucket_name.to_string()));
+    }
+
+    if !validate_object_key(object_key) {
+        return Err(Error::InvalidObjectKey(object_key.to_string()));
+    }
+
+    if request.process.is_empty() {
+        return Err(Error::InvalidArgument("process must not be empty".to_string()));
+    }
+
+    if let Some(s) = &request.target_bucket_name {
+        if !validate_bucket_name(s) {
+            return Err(Error::InvalidBucketName(s.to_string()));
+        }
+    }
+
+    let body = format!("x-oss-async-process={}", request.to_process_string());
+
+    let mut request = OssRequest::new()
+        .method(RequestMethod::Post)
+        .bucket(bucket_name)
+        .object(object_key)
+        .add_query("x-oss-async-process", "")
+        .content_length(body.len() as u64)
+        .text_body(body);
+
+    if let Some(options) = options {
+        request = apply_request_payer(request, &options.request_payer);
+    }
+
+    Ok(request)
+}
+
+#[cfg(test)]
+mod test_process_common {
+    use crate::request::{RequestBody, RequestMethod};
+
+    use super::*;
+
+    #[test]
+    fn test_image_process() {
+        let process = ImageProcess::new()
+            .resize(ImageResize::new().mode(ResizeMode::Lfit).width(100).height(80).limit(false))
+            .crop(ImageCrop::new(50, 40).offset(10, 10).gravity(Gravity::SouthEast))
+            .rotate(90)
+            .quality(ImageQuality::Relative(85))
+            .format(ImageFormat::Jpg);
+
+        assert_eq!(
+            "image/resize,m_lfit,w_100,h_80,limit_0/crop,w_50,h_40,x_10,y_10,g_se/rotate,90/quality,q_85/format,jpg",
+            process.to_string()
+        );
+
+        assert_eq!("image/resize,p_50", ImageProcess::new().resize(ImageResize::new().percentage(50)).to_string());
+        assert_eq!("image/info", ImageProcess::new().info().to_string());
+    }
+
+    #[test]
+    fn test_image_watermark() {
+        let watermark = ImageWatermark::text("Hello 图片服务!")
+            .font("wqy-zenhei")
+            .color("FFFFFF")
+            .size(40)
+            .gravity(Gravity::SouthEast)
+            .margin(10, 10);
+
+        assert_eq!(
+            "watermark,text_SGVsbG8g5Zu-54mH5pyN5YqhIQ,type_d3F5LXplbmhlaQ,color_FFFFFF,size_40,g_se,x_10,y_10",
+            watermark.to_string()
+        );
+
+        let watermark = ImageWatermark::image("panda.png").transparency(50);
+        assert_eq!("watermark,image_cGFuZGEucG5n,t_50", watermark.to_string());
+    }
+
+    #[test]
+    fn test_build_process_object_request() {
+        let process = ImageProcess::new().resize(ImageResize::new().width(100));
+        let request = ProcessObjectRequest::new(process, "thumbnails/a.jpg").target_bucket_name("target-bucket");
+
+        let oss_request = build_process_object_request("example-bucket", "images/a.jpg", &request, &None).unwrap();
+        assert_eq!(RequestMethod::Post, oss_request.method);
+        assert_eq!(Some(&"".to_string()), oss_request.query.get("x-oss-process"));
+
+        match &oss_request.body {
+            RequestBody::Text(s) => assert_eq!("x-oss-process=image/resize,w_100|sys/saveas,o_dGh1bWJuYWlscy9hLmpwZw,b_dGFyZ2V0LWJ1Y2tldA", s),
+            _ => panic!("text body expected"),
+        }
+
+        assert!(build_process_object_request("example-bucket", "images/a.jpg", &ProcessObjectRequest::new("", "b.jpg"), &None).is_err());
+    }
+
+    #[test]
+    fn test_build_async_process_object_request() {
+        let request = AsyncProcessObjectRequestBuilder::new("doc/convert,target_png,source_docx")
+            .target_bucket_name("test-bucket")
+            .target_object_key("doc_images/{index}.png")
+            .notify_topic("AudioConvert")
+            .build();
+
+        let oss_request = build_async_process_object_request("example-bucket", "example.docx", &request, &None).unwrap();
+        assert_eq!(Some(&"".to_string()), oss_request.query.get("x-oss-async-process"));
+
+        match &oss_request.body {
+            RequestBody::Text(s) => assert_eq!(
+                "x-oss-async-process=doc/convert,target_png,source_docx|sys/saveas,b_dGVzdC1idWNrZXQ,o_ZG9jX2ltYWdlcy97aW5kZXh9LnBuZw/notify,topic_QXVkaW9Db252ZXJ0",
+                s
+            ),
+            _ => panic!("text body expected"),
+        }
+
+        let request = AsyncProcessObjectRequestBuilder::new("video/snapshots,ss_1000,f_jpg").build();
+        assert_eq!("video/snapshots,ss_1000,f_jpg", request.to_process_string());
+    }
+
+    #[test]
+    fn test_async_process() {
+        let convert = VideoConvert::new("mp4")
+            .start(1000)
+            .duration(60000)
+            .video_codec("h264")
+            .resolution(1280, 720)
+            .fps(30)
+            .video_bitrate(1000000)
+            .audio_codec("aac")
+            .audio_bitrate(128000)
+            .sample_rate(44100)
+            .channels(2)
+            .param("crf", "23");
+        assert_eq!(
+            "video/convert,f_mp4,ss_1000,t_60000,vcodec_h264,s_1280x720,fps_30,vb_1000000,acodec_aac,ab_128000,ar_44100,ac_2,crf_23",
+            convert.to_string()
+        );
+        assert_eq!(
+            "video/convert,f_mp3,vn_1,acodec_mp3",
+            VideoConvert::new("mp3").disable_video().audio_codec("mp3").to_string()
+        );
+
+        let snapshot = VideoSnapshot::new("png").mode("key").size(640, 360).scale_type("crop");
+        assert_eq!("video/snapshots,f_png,m_key,w_640,h_360,scaletype_crop", snapshot.to_string());
+
+        let request = AsyncProcessObjectRequestBuilder::new(DocConvert::new("pdf").source("docx"))
+            .target_object_key("a.pdf")
+            .build();
+        assert_eq!("doc/convert,target_pdf,source_docx|sys/saveas,o_YS5wZGY", request.to_process_string());
+    }
+
+    #[test]
+    fn test_async_process_object_result() {
+        let json = r#"{"EventId":"181-1kZUlN60OH4fWOcOjZEnGnG****","RequestId":"1D99637F-F59E-5B41-9200-C4892F52****","TaskId":"MediaConvert-e4a737df-69e9-4fca-8d9b-17c40ea4****"}"#;
+        let ret: AsyncProcessObjectResult = serde_json::from_str(json).unwrap();
+        assert_eq!("181-1kZUlN60OH4fWOcOjZEnGnG****", ret.event_id);
+        assert_eq!("1D99637F-F59E-5B41-9200-C4892F52****", ret.request_id);
+        assert_eq!("MediaConvert-e4a737df-69e9-4fca-8d9b-17c40ea4****", ret.task_id);
+    }
+}
@@ -0,0 +1,135 @@
+//! Public access block module. Block public access at the account level or the bucket level.
+
+use async_trait::async_trait;
+
+use crate::{
+    error::Error,
+    public_access_block_common::{
+        build_delete_public_access_block_request, build_get_public_access_block_request, build_put_public_access_block_request,
+        parse_public_access_block_from_xml,
+    },
+    Client, Result,
+};
+
+#[async_trait]
+pub trait PublicAccessBlockOperations {
+    /// Enable or disable public access block for the account.
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/putpublicaccessblock>
+    async fn put_public_access_block(&self, block_public_access: bool) -> Result<()>;
+
+    /// Get public access block setting of the account. Returns `true` if public access is blocked.
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/getpublicaccessblock>
+    async fn get_public_access_block(&self) -> Result<bool>;
+
+    /// Delete public access block setting of the account.
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/deletepublicaccessblock>
+    async fn delete_public_access_block(&self) -> Result<()>;
+
+    /// Enable or disable public access block for a bucket.
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/putbucketpublicaccessblock>
+    async fn put_bucket_public_access_block<S>(&self, bucket_name: S, block_public_access: bool) -> Result<()>
+    where
+        S: AsRef<str> + Send;
+
+    /// Get public access block setting of a bucket. Returns `true` if public access is blocked.
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/getbucketpublicaccessblock>
+    async fn get_bucket_public_access_block<S>(&self, bucket_name: S) -> Result<bool>
+    where
+        S: AsRef<str> + Send;
+
+    /// Delete public access block setting of a bucket.
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/deletebucketpublicaccessblock>
+    async fn delete_bucket_public_access_block<S>(&self, bucket_name: S) -> Result<()>
+    where
+        S: AsRef<str> + Send;
+}
+
+#[async_trait]
+impl PublicAccessBlockOperations for Client {
+    /// Enable or disable public access block for the account.
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/putpublicaccessblock>
+    async fn put_public_access_block(&self, block_public_access: bool) -> Result<()> {
+        let request = build_put_public_access_block_request("", block_public_access)?;
+        self.do_request::<()>(request).await?;
+        Ok(())
+    }
+
+    /// Get public access block setting of the account.
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/getpublicaccessblock>
+    async fn get_public_access_block(&self) -> Result<bool> {
+        let request = build_get_public_access_block_request("")?;
+        let (_, content) = self.do_request::<String>(request).await?;
+        parse_public_access_block_from_xml(&content)
+    }
+
+    /// Delete public access block setting of the account.
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/deletepublicaccessblock>
+    async fn delete_public_access_block(&self) -> Result<()> {
+        let request = build_delete_public_access_block_request("")?;
+        self.do_request::<()>(request).await?;
+        Ok(())
+    }
+
+    /// Enable or disable public access block for a bucket.
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/putbucketpublicaccessblock>
+    async fn put_bucket_public_access_block<S>(&self, bucket_name: S, block_public_access: bool) -> Result<()>
+    where
+        S: AsRef<str> + Send,
+    {
+        let bucket_name = bucket_name.as_ref();
+
+        if bucket_name.is_empty() {
+            return Err(Error::InvalidBucketName(String::new()));
+        }
+
+        let request = build_put_public_access_block_request(bucket_name, block_public_access)?;
+        self.do_request::<()>(request).await?;
+        Ok(())
+    }
+
+    /// Get public access block setting of a bucket.
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/getbucketpublicaccessblock>
+    async fn get_bucket_public_access_block<S>(&self, bucket_name: S) -> Result<bool>
+    where
+        S: AsRef<str> + Send,
+    {
+        let bucket_name = bucket_name.as_ref();
+
+        if bucket_name.is_empty() {
+            return Err(Error::InvalidBucketName(String::new()));
+        }
+
+        let request = build_get_public_access_block_request(bucket_name)?;
+        let (_, content) = self.do_request::<String>(request).await?;
+        parse_public_access_block_from_xml(&content)
+    }
+
+    /// Delete public access block setting of a bucket.
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/deletebucketpublicaccessblock>
+    async fn delete_bucket_public_access_block<S>(&self, bucket_name: S) -> Result<()>
+    where
+        S: AsRef<str> + Send,
+    {
+        let bucket_name = bucket_name.as_ref();
+
+        if bucket_name.is_empty() {
+            return Err(Error::InvalidBucketName(String::new()));
+        }
+
+        let request = build_delete_public_access_block_request(bucket_name)?;
+        self.do_request::<()>(request).await?;
+        Ok(())
+    }
+}
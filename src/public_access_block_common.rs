@@ -0,0 +1,99 @@
+use quick_xml::events::{BytesDecl, BytesEnd, BytesStart, BytesText, Event};
+
+use crate::{
+    common,
+    error::Error,
+    request::{OssRequest, RequestMethod},
+    util::validate_bucket_name,
+    Result,
+};
+
+/// Parse `BlockPublicAccess` value from `PublicAccessBlockConfiguration` XML.
+pub(crate) fn parse_public_access_block_from_xml(xml: &str) -> Result<bool> {
+    let mut reader = quick_xml::Reader::from_str(xml);
+    let mut tag = String::new();
+    let mut block_public_access = false;
+
+    loop {
+        match reader.read_event()? {
+            Event::Eof => break,
+            Event::Start(t) => tag = String::from_utf8_lossy(t.local_name().as_ref()).to_string(),
+            Event::Text(text) if tag.as_str() == "BlockPublicAccess" => {
+                block_public_access = text.unescape()?.trim() == "true";
+            }
+            Event::End(_) => tag.clear(),
+            _ => {}
+        }
+    }
+
+    Ok(block_public_access)
+}
+
+/// Build the base request for public access block.
+/// If `bucket_name` is empty, the request is sent at the account level.
+fn build_public_access_block_request(bucket_name: &str, method: RequestMethod) -> Result<OssRequest> {
+    let mut request = OssRequest::new().method(method).add_query("publicAccessBlock", "");
+
+    if !bucket_name.is_empty() {
+        if !validate_bucket_name(bucket_name) {
+            return Err(Error::InvalidBucketName(bucket_name.to_string()));
+        }
+
+        request = request.bucket(bucket_name);
+    }
+
+    Ok(request)
+}
+
+pub(crate) fn build_put_public_access_block_request(bucket_name: &str, block_public_access: bool) -> Result<OssRequest> {
+    let mut writer = quick_xml::Writer::new(Vec::new());
+    writer.write_event(Event::Decl(BytesDecl::new("1.0", Some("UTF-8"), None)))?;
+    writer.write_event(Event::Start(BytesStart::new("PublicAccessBlockConfiguration")))?;
+    writer.write_event(Event::Start(BytesStart::new("BlockPublicAccess")))?;
+    writer.write_event(Event::Text(BytesText::new(if block_public_access { "true" } else { "false" })))?;
+    writer.write_event(Event::End(BytesEnd::new("BlockPublicAccess")))?;
+    writer.write_event(Event::End(BytesEnd::new("PublicAccessBlockConfiguration")))?;
+
+    let xml = String::from_utf8(writer.into_inner())?;
+
+    let request = build_public_access_block_request(bucket_name, RequestMethod::Put)?
+        .content_type(common::MIME_TYPE_XML)
+        .text_body(xml);
+
+    Ok(request)
+}
+
+pub(crate) fn build_get_public_access_block_request(bucket_name: &str) -> Result<OssRequest> {
+    build_public_access_block_request(bucket_name, RequestMethod::Get)
+}
+
+pub(crate) fn build_delete_public_access_block_request(bucket_name: &str) -> Result<OssRequest> {
+    build_public_access_block_request(bucket_name, RequestMethod::Delete)
+}
+
+#[cfg(test)]
+mod test_public_access_block_common {
+    use crate::request::RequestBody;
+
+    use super::{build_delete_public_access_block_request, build_put_public_access_block_request, parse_public_access_block_from_xml};
+
+    #[test]
+    fn test_build_public_access_block_request() {
+        let request = build_put_public_access_block_request("", true).unwrap();
+        assert!(request.bucket_name.is_empty());
+        assert_eq!("/", request.build_canonical_uri());
+
+        let xml = match &request.body {
+            RequestBody::Text(s) => s.clone(),
+            _ => panic!("unexpected request body"),
+        };
+        assert!(xml.contains("<PublicAccessBlockConfiguration><BlockPublicAccess>true</BlockPublicAccess></PublicAccessBlockConfiguration>"));
+        assert!(parse_public_access_block_from_xml(&xml).unwrap());
+
+        let request = build_delete_public_access_block_request("test-bucket").unwrap();
+        assert_eq!("/test-bucket/", request.build_canonical_uri());
+        assert_eq!("publicAccessBlock", request.build_canonical_query_string());
+
+        assert!(build_delete_public_access_block_request("Invalid_Bucket").is_err());
+    }
+}
@@ -19,6 +19,17 @@ pub enum RequestBody {
     File(PathBuf, Option<Range<u64>>),
 }
 
+/// Streaming request body. It can be sent only once and can not be cloned, so it is kept apart from [`RequestBody`].
+#[derive(Debug)]
+pub enum StreamBody {
+    /// Streaming body used by async client
+    Stream(reqwest::Body),
+
+    /// Streaming body used by blocking client
+    #[cfg(feature = "blocking")]
+    Reader(reqwest::blocking::Body),
+}
+
 /// Request method
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum RequestMethod {
@@ -67,6 +78,9 @@ pub struct OssRequest {
     pub query: HashMap<String, String>,
 
     pub body: RequestBody,
+
+    /// If it is set, it is sent instead of `body`.
+    pub stream_body: Option<StreamBody>,
 }
 
 impl Default for OssRequest {
@@ -95,6 +109,7 @@ impl OssRequest {
             additional_headers: HashSet::new(),
             query: HashMap::new(),
             body: RequestBody::Empty,
+            stream_body: None,
         }
     }
 
@@ -171,6 +186,19 @@ impl OssRequest {
         self
     }
 
+    /// Set streaming body which is sent instead of `body`.
+    /// If the content length is `None`, the body is sent with chunked transfer encoding.
+    pub fn stream_body(mut self, body: StreamBody, content_length: Option<u64>) -> Self {
+        self.stream_body = Some(body);
+        match content_length {
+            Some(len) => self.content_length(len),
+            None => {
+                self.headers.remove("content-length");
+                self
+            }
+        }
+    }
+
     /// helper method for [`Self::body`]. only the body is set and left `content-length`, `content-type` untouched.
     pub fn text_body(self, text: impl Into<String>) -> Self {
         self.body(RequestBody::Text(text.into()))
@@ -215,6 +243,38 @@ impl OssRequest {
         &mut self.query
     }
 
+    /// Shift the `x-oss-date` in headers and query by `offset_seconds`,
+    /// which is the offset of the server clock to the local clock.
+    pub(crate) fn shift_date(&mut self, offset_seconds: i64) {
+        if offset_seconds == 0 {
+            return;
+        }
+
+        for map in [&mut self.headers, &mut self.query] {
+            if let Some(s) = map.get_mut("x-oss-date") {
+                *s = util::shift_iso8601_date_time_string(s, offset_seconds);
+            }
+        }
+    }
+
+    /// Clone the request if the body could be sent again. Streaming bodies can not be cloned.
+    pub(crate) fn try_clone(&self) -> Option<Self> {
+        if self.stream_body.is_some() {
+            return None;
+        }
+
+        Some(Self {
+            bucket_name: self.bucket_name.clone(),
+            object_key: self.object_key.clone(),
+            method: self.method,
+            headers: self.headers.clone(),
+            additional_headers: self.additional_headers.clone(),
+            query: self.query.clone(),
+            body: self.body.clone(),
+            stream_body: None,
+        })
+    }
+
     ///
     /// 官方文档：<https://help.aliyun.com/zh/oss/developer-reference/recommend-to-use-signature-version-4>
     ///
@@ -363,4 +423,146 @@ impl OssRequest {
             hex::encode(&canonical_request_hash)
         )
     }
+    /// Build the canonicalized resource for signature version 1.
+    ///
+    /// - `/` if there is no bucket
+    /// - `/bucket/` for bucket level requests
+    /// - `/bucket/object` for object level requests
+    ///
+    /// Followed by the sub-resources sorted by name, e.g. `/bucket/object?acl&uploadId=xxx`.
+    /// Neither the object key nor the sub-resource values are url encoded.
+    pub(crate) fn build_canonical_resource_v1(&self) -> String {
+        let resource = match (self.bucket_name.is_empty(), self.object_key.is_empty()) {
+            (true, _) => "/".to_string(),
+            (false, true) => format!("/{}/", self.bucket_name),
+            (false, false) => format!("/{}/{}", self.bucket_name, self.object_key),
+        };
+
+        let mut pairs = self.query.iter().filter(|(k, _)| V1_SUB_RESOURCES.contains(&k.as_str())).collect::<Vec<_>>();
+
+        if pairs.is_empty() {
+            return resource;
+        }
+
+        pairs.sort_by(|a, b| a.0.cmp(b.0));
+
+        let sub_resources = pairs
+            .iter()
+            .map(|(k, v)| if v.is_empty() { k.to_string() } else { format!("{}={}", k, v) })
+            .collect::<Vec<_>>()
+            .join("&");
+
+        format!("{}?{}", resource, sub_resources)
+    }
+
+    /// Build the canonicalized `x-oss-*` headers for signature version 1.
+    /// Each header is followed by a line break, so it is an empty string if there is no such header.
+    pub(crate) fn build_canonical_oss_headers_v1(&self) -> String {
+        let mut pairs = self
+            .headers
+            .iter()
+            .map(|(k, v)| (k.to_lowercase(), v))
+            .filter(|(k, _)| k.starts_with("x-oss-"))
+            .collect::<Vec<_>>();
+
+        pairs.sort_by(|a, b| a.0.cmp(&b.0));
+
+        pairs.iter().map(|(k, v)| format!("{}:{}\n", k, v.trim())).collect::<Vec<_>>().join("")
+    }
+
+    /// Build the string to sign for signature version 1.
+    /// `date` is the `Date` header for header signing, or the `Expires` timestamp for presigned urls.
+    pub(crate) fn build_string_to_sign_v1(&self, date: &str) -> String {
+        let header = |name: &str| {
+            self.headers
+                .iter()
+                .find(|(k, _)| k.eq_ignore_ascii_case(name))
+                .map(|(_, v)| v.trim().to_string())
+                .unwrap_or_default()
+        };
+
+        format!(
+            "{}\n{}\n{}\n{}\n{}{}",
+            self.method,
+            header("content-md5"),
+            header("content-type"),
+            date,
+            self.build_canonical_oss_headers_v1(),
+            self.build_canonical_resource_v1()
+        )
+    }
 }
+
+/// Query parameters which are included in the canonicalized resource of signature version 1
+const V1_SUB_RESOURCES: &[&str] = &[
+    "accessPoint",
+    "accessPointPolicy",
+    "accessmonitor",
+    "acl",
+    "append",
+    "asyncFetch",
+    "bucketInfo",
+    "callback",
+    "callback-var",
+    "cleanRestoredObject",
+    "cname",
+    "comp",
+    "continuation-token",
+    "cors",
+    "delete",
+    "encryption",
+    "endTime",
+    "img",
+    "inventory",
+    "inventoryId",
+    "lifecycle",
+    "live",
+    "location",
+    "logging",
+    "metaQuery",
+    "objectMeta",
+    "partNumber",
+    "policy",
+    "policyStatus",
+    "position",
+    "publicAccessBlock",
+    "qos",
+    "qosInfo",
+    "referer",
+    "regionList",
+    "replication",
+    "replicationLocation",
+    "replicationProgress",
+    "requestPayment",
+    "resourceGroup",
+    "response-cache-control",
+    "response-content-disposition",
+    "response-content-encoding",
+    "response-content-language",
+    "response-content-type",
+    "response-expires",
+    "restore",
+    "security-token",
+    "sequential",
+    "startTime",
+    "stat",
+    "status",
+    "style",
+    "styleName",
+    "symlink",
+    "tagging",
+    "transferAcceleration",
+    "uploadId",
+    "uploads",
+    "versionId",
+    "versioning",
+    "versions",
+    "vod",
+    "website",
+    "worm",
+    "wormExtend",
+    "wormId",
+    "x-oss-async-process",
+    "x-oss-process",
+    "x-oss-request-payer",
+];
@@ -0,0 +1,254 @@
+//! Select object module
+
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use futures::{Stream, StreamExt};
+
+use crate::{
+    error::Error,
+    select_object_common::{
+        build_create_select_object_meta_request, build_select_object_request, check_select_status, is_output_raw, parse_select_object_meta,
+        CreateSelectObjectMetaRequest, SelectFrame, SelectFrameDecoder, SelectObjectMeta, SelectObjectOptions, SelectObjectRequest, SelectObjectSummary,
+    },
+    ByteStream, Client, Result,
+};
+
+/// Selected data of select object.
+///
+/// Frames in the response body are decoded and the data of data frames is yielded.
+/// If the select operation fails in the middle, [`Error::SelectObjectFailed`] is yielded.
+pub struct SelectObjectStream {
+    inner: ByteStream,
+    decoder: SelectFrameDecoder,
+
+    /// The response body is the selected data without frames
+    raw: bool,
+    summary: Option<SelectObjectSummary>,
+    done: bool,
+}
+
+impl SelectObjectStream {
+    fn new(inner: ByteStream, raw: bool, verify_crc: bool) -> Self {
+        Self {
+            inner,
+            decoder: SelectFrameDecoder::new(verify_crc),
+            raw,
+            summary: None,
+            done: false,
+        }
+    }
+
+    /// The summary in the end frame. It is available after the stream is exhausted,
+    /// and is always `None` if the raw data is output.
+    pub fn summary(&self) -> Option<&SelectObjectSummary> {
+        self.summary.as_ref()
+    }
+}
+
+impl Stream for SelectObjectStream {
+    type Item = Result<Bytes>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+
+        if this.done {
+            return Poll::Ready(None);
+        }
+
+        if this.raw {
+            return this.inner.poll_next_unpin(cx).map(|o| o.map(|r| r.map_err(Error::from)));
+        }
+
+        loop {
+            match this.decoder.next_frame() {
+                Err(e) => {
+                    this.done = true;
+                    return Poll::Ready(Some(Err(e)));
+                }
+                Ok(Some(SelectFrame::Data(_, data))) => {
+                    if !data.is_empty() {
+                        return Poll::Ready(Some(Ok(data)));
+                    }
+                    continue;
+                }
+                Ok(Some(SelectFrame::End(summary))) => {
+                    this.done = true;
+                    let ret = check_select_status(summary.status, &summary.error_message);
+                    this.summary = Some(summary);
+                    return Poll::Ready(ret.err().map(Err));
+                }
+                Ok(Some(_)) => continue,
+                Ok(None) => {}
+            }
+
+            match this.inner.poll_next_unpin(cx) {
+                Poll::Pending => return Poll::Pending,
+                Poll::Ready(Some(Ok(bytes))) => this.decoder.feed(&bytes),
+                Poll::Ready(Some(Err(e))) => {
+                    this.done = true;
+                    return Poll::Ready(Some(Err(e.into())));
+                }
+                Poll::Ready(None) => {
+                    this.done = true;
+                    return Poll::Ready(Some(Err(Error::InvalidResponse("select object response ends without end frame".to_string()))));
+                }
+            }
+        }
+    }
+}
+
+#[async_trait]
+pub trait SelectObjectOperations {
+    /// Select data from a CSV or JSON object with SQL statement.
+    /// The returned stream yields the selected data.
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/selectobject>
+    async fn select_object<S1, S2>(
+        &self,
+        bucket_name: S1,
+        object_key: S2,
+        request: SelectObjectRequest,
+        options: Option<SelectObjectOptions>,
+    ) -> Result<SelectObjectStream>
+    where
+        S1: AsRef<str> + Send,
+        S2: AsRef<str> + Send;
+
+    /// Create select meta of a CSV or JSON object, which is required for selecting by ranges.
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/createselectobjectmeta>
+    async fn create_select_object_meta<S1, S2>(&self, bucket_name: S1, object_key: S2, request: CreateSelectObjectMetaRequest) -> Result<SelectObjectMeta>
+    where
+        S1: AsRef<str> + Send,
+        S2: AsRef<str> + Send;
+}
+
+#[async_trait]
+impl SelectObjectOperations for Client {
+    /// Select data from a CSV or JSON object with SQL statement.
+    /// The returned stream yields the selected data.
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/selectobject>
+    async fn select_object<S1, S2>(
+        &self,
+        bucket_name: S1,
+        object_key: S2,
+        request: SelectObjectRequest,
+        options: Option<SelectObjectOptions>,
+    ) -> Result<SelectObjectStream>
+    where
+        S1: AsRef<str> + Send,
+        S2: AsRef<str> + Send,
+    {
+        let options = options.unwrap_or_default();
+        let request = build_select_object_request(bucket_name.as_ref(), object_key.as_ref(), &request, &options)?;
+        let (headers, stream) = self.do_request::<ByteStream>(request).await?;
+        Ok(SelectObjectStream::new(stream, is_output_raw(&headers), options.enable_payload_crc))
+    }
+
+    /// Create select meta of a CSV or JSON object, which is required for selecting by ranges.
+    ///
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/createselectobjectmeta>
+    async fn create_select_object_meta<S1, S2>(&self, bucket_name: S1, object_key: S2, request: CreateSelectObjectMetaRequest) -> Result<SelectObjectMeta>
+    where
+        S1: AsRef<str> + Send,
+        S2: AsRef<str> + Send,
+    {
+        let request = build_create_select_object_meta_request(bucket_name.as_ref(), object_key.as_ref(), &request)?;
+        let (_, mut stream) = self.do_request::<ByteStream>(request).await?;
+
+        let mut body = vec![];
+        while let Some(bytes) = stream.next().await {
+            body.extend_from_slice(&bytes?);
+        }
+
+        parse_select_object_meta(&body)
+    }
+}
+
+#[cfg(test)]
+mod test_select_object_async {
+    use futures::StreamExt;
+
+    use crate::{
+        error::Error,
+        mock_server::{self, MockResponse},
+        select_object_common::{
+            test_select_object_common::{data_frame, end_frame},
+            InputSerialization, SelectObjectRequest,
+        },
+        ClientBuilder,
+    };
+
+    use super::{SelectObjectOperations, SelectObjectStream};
+
+    async fn select_with_mock_server(response: MockResponse) -> SelectObjectStream {
+        let (port, _) = mock_server::start(1, move |_| response.clone());
+
+        let client = ClientBuilder::new("ak", "sk", format!("http://127.0.0.1:{}", port))
+            .region("cn-hangzhou")
+            .path_style(true)
+            .build()
+            .unwrap();
+
+        let request = SelectObjectRequest::new("select * from ossobject", InputSerialization::default());
+        client.select_object("example-bucket", "a.csv", request, None).await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_select_object_frames() {
+        let mut body = data_frame(4, b"a,b\n");
+        body.extend(end_frame(4, 4, 200, ""));
+
+        let mut stream = select_with_mock_server(MockResponse::new(206).body(body)).await;
+        assert_eq!(b"a,b\n".as_slice(), stream.next().await.unwrap().unwrap().as_ref());
+        assert!(stream.next().await.is_none());
+        assert_eq!(200, stream.summary().unwrap().status);
+    }
+
+    #[tokio::test]
+    async fn test_select_object_failed() {
+        let mut body = data_frame(4, b"a,b\n");
+        body.extend(end_frame(4, 4, 400, "InvalidCsvLine"));
+
+        let mut stream = select_with_mock_server(MockResponse::new(206).body(body)).await;
+        assert!(stream.next().await.unwrap().is_ok());
+
+        match stream.next().await.unwrap() {
+            Err(Error::SelectObjectFailed { status, message }) => {
+                assert_eq!(400, status);
+                assert_eq!("InvalidCsvLine", message);
+            }
+            r => panic!("select object failed error expected, got: {:?}", r),
+        }
+
+        assert!(stream.next().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_select_object_without_end_frame() {
+        let mut stream = select_with_mock_server(MockResponse::new(206).body(data_frame(4, b"a,b\n"))).await;
+        assert!(stream.next().await.unwrap().is_ok());
+        assert!(matches!(stream.next().await.unwrap(), Err(Error::InvalidResponse(_))));
+        assert!(stream.next().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_select_object_output_raw() {
+        let response = MockResponse::new(206).header("x-oss-select-output-raw", "true").body(b"a,b\nc,d\n".to_vec());
+        let mut stream = select_with_mock_server(response).await;
+
+        let mut data = vec![];
+        while let Some(bytes) = stream.next().await {
+            data.extend_from_slice(&bytes.unwrap());
+        }
+
+        assert_eq!(b"a,b\nc,d\n".to_vec(), data);
+        assert!(stream.summary().is_none());
+    }
+}
@@ -0,0 +1,1201 @@
+//! Common types for select object and create select object meta.
+//!
+//! The response body of select object is a series of binary frames. Each frame is:
+//!
+//! ```text
+//! | version (1) | frame type (3) | payload length (4) | header checksum (4) | payload (n) | payload checksum (4) |
+//! ```
+//!
+//! All numbers are big endian. The payload checksum is the CRC32 of the whole payload, or `0` if the checksum is not calculated.
+
+use std::fmt::Display;
+
+use base64::{prelude::BASE64_STANDARD, Engine};
+use bytes::{Buf, Bytes, BytesMut};
+use quick_xml::events::{BytesDecl, BytesEnd, BytesStart, BytesText, Event};
+
+use crate::{
+    common::{self, apply_request_payer, RequestPayer},
+    error::Error,
+    request::{OssRequest, RequestMethod},
+    util::{validate_bucket_name, validate_object_key},
+    Result,
+};
+
+/// Compression type of the object to select from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde-support", derive(serde::Serialize, serde::Deserialize))]
+pub enum CompressionType {
+    #[default]
+    #[cfg_attr(feature = "serde-support", serde(rename = "None"))]
+    None,
+
+    #[cfg_attr(feature = "serde-support", serde(rename = "GZIP"))]
+    Gzip,
+}
+
+impl CompressionType {
+    pub fn as_str(&self) -> &str {
+        match self {
+            CompressionType::None => "None",
+            CompressionType::Gzip => "GZIP",
+        }
+    }
+}
+
+impl AsRef<str> for CompressionType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl Display for CompressionType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl TryFrom<&str> for CompressionType {
+    type Error = crate::error::Error;
+
+    fn try_from(value: &str) -> std::result::Result<Self, Self::Error> {
+        match value {
+            "None" => Ok(CompressionType::None),
+            "GZIP" => Ok(CompressionType::Gzip),
+            _ => Err(Error::InvalidEnumValue {
+                name: "CompressionType",
+                value: value.to_string(),
+            }),
+        }
+    }
+}
+
+impl TryFrom<String> for CompressionType {
+    type Error = crate::error::Error;
+
+    fn try_from(value: String) -> std::result::Result<Self, Self::Error> {
+        Self::try_from(value.as_str())
+    }
+}
+
+impl TryFrom<&String> for CompressionType {
+    type Error = crate::error::Error;
+
+    fn try_from(value: &String) -> std::result::Result<Self, Self::Error> {
+        Self::try_from(value.as_str())
+    }
+}
+
+///
+/// How the first line of a CSV object is treated.
+///
+/// - `None`: There is no header line. This is the default value.
+/// - `Ignore`: The first line is a header line but it is ignored. Columns can be referenced by `_1`, `_2` etc.
+/// - `Use`: The first line is a header line. Columns can be referenced by their names.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde-support", derive(serde::Serialize, serde::Deserialize))]
+pub enum FileHeaderInfo {
+    #[default]
+    #[cfg_attr(feature = "serde-support", serde(rename = "NONE"))]
+    None,
+
+    #[cfg_attr(feature = "serde-support", serde(rename = "IGNORE"))]
+    Ignore,
+
+    #[cfg_attr(feature = "serde-support", serde(rename = "USE"))]
+    Use,
+}
+
+impl FileHeaderInfo {
+    pub fn as_str(&self) -> &str {
+        match self {
+            FileHeaderInfo::None => "NONE",
+            FileHeaderInfo::Ignore => "IGNORE",
+            FileHeaderInfo::Use => "USE",
+        }
+    }
+}
+
+impl AsRef<str> for FileHeaderInfo {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl Display for FileHeaderInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl TryFrom<&str> for FileHeaderInfo {
+    type Error = crate::error::Error;
+
+    fn try_from(value: &str) -> std::result::Result<Self, Self::Error> {
+        match value {
+            "NONE" => Ok(FileHeaderInfo::None),
+            "IGNORE" => Ok(FileHeaderInfo::Ignore),
+            "USE" => Ok(FileHeaderInfo::Use),
+            _ => Err(Error::InvalidEnumValue {
+                name: "FileHeaderInfo",
+                value: value.to_string(),
+            }),
+        }
+    }
+}
+
+impl TryFrom<String> for FileHeaderInfo {
+    type Error = crate::error::Error;
+
+    fn try_from(value: String) -> std::result::Result<Self, Self::Error> {
+        Self::try_from(value.as_str())
+    }
+}
+
+impl TryFrom<&String> for FileHeaderInfo {
+    type Error = crate::error::Error;
+
+    fn try_from(value: &String) -> std::result::Result<Self, Self::Error> {
+        Self::try_from(value.as_str())
+    }
+}
+
+///
+/// Type of a JSON object.
+///
+/// - `Document`: The whole object is a single JSON document. This is the default value.
+/// - `Lines`: Each line of the object is a JSON document.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde-support", derive(serde::Serialize, serde::Deserialize))]
+pub enum JsonType {
+    #[default]
+    #[cfg_attr(feature = "serde-support", serde(rename = "DOCUMENT"))]
+    Document,
+
+    #[cfg_attr(feature = "serde-support", serde(rename = "LINES"))]
+    Lines,
+}
+
+impl JsonType {
+    pub fn as_str(&self) -> &str {
+        match self {
+            JsonType::Document => "DOCUMENT",
+            JsonType::Lines => "LINES",
+        }
+    }
+}
+
+impl AsRef<str> for JsonType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl Display for JsonType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl TryFrom<&str> for JsonType {
+    type Error = crate::error::Error;
+
+    fn try_from(value: &str) -> std::result::Result<Self, Self::Error> {
+        match value {
+            "DOCUMENT" => Ok(JsonType::Document),
+            "LINES" => Ok(JsonType::Lines),
+            _ => Err(Error::InvalidEnumValue {
+                name: "JsonType",
+                value: value.to_string(),
+            }),
+        }
+    }
+}
+
+impl TryFrom<String> for JsonType {
+    type Error = crate::error::Error;
+
+    fn try_from(value: String) -> std::result::Result<Self, Self::Error> {
+        Self::try_from(value.as_str())
+    }
+}
+
+impl TryFrom<&String> for JsonType {
+    type Error = crate::error::Error;
+
+    fn try_from(value: &String) -> std::result::Result<Self, Self::Error> {
+        Self::try_from(value.as_str())
+    }
+}
+
+///
+/// Select part of an object. Both start and end are inclusive.
+/// The object must have select meta created by `create_select_object_meta` to use these ranges.
+///
+/// - `Line(start, end)`: Select lines `start` to `end`, serialized as `line-range=start-end`.
+/// - `Split(start, end)`: Select splits `start` to `end`, serialized as `split-range=start-end`.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde-support", derive(serde::Serialize, serde::Deserialize))]
+pub enum SelectRange {
+    Line(u64, u64),
+    Split(u64, u64),
+}
+
+impl Display for SelectRange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SelectRange::Line(start, end) => write!(f, "line-range={}-{}", start, end),
+            SelectRange::Split(start, end) => write!(f, "split-range={}-{}", start, end),
+        }
+    }
+}
+
+/// How to parse a CSV object
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde-support", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde-camelcase", serde(rename_all = "camelCase"))]
+pub struct CsvInputSerialization {
+    pub file_header_info: Option<FileHeaderInfo>,
+
+    /// Default is `\n`
+    pub record_delimiter: Option<String>,
+
+    /// Default is `,`
+    pub field_delimiter: Option<String>,
+
+    /// Default is `"`
+    pub quote_character: Option<String>,
+
+    /// Lines starting with this character are skipped. Default is `#`
+    pub comment_character: Option<String>,
+
+    pub range: Option<SelectRange>,
+
+    /// Whether record delimiters are allowed in quoted fields. Default is `true`.
+    /// Set to `false` to make splitting by ranges faster.
+    pub allow_quoted_record_delimiter: Option<bool>,
+}
+
+#[derive(Debug, Default)]
+pub struct CsvInputSerializationBuilder {
+    input: CsvInputSerialization,
+}
+
+impl CsvInputSerializationBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn file_header_info(mut self, file_header_info: FileHeaderInfo) -> Self {
+        self.input.file_header_info = Some(file_header_info);
+        self
+    }
+
+    pub fn record_delimiter(mut self, record_delimiter: impl Into<String>) -> Self {
+        self.input.record_delimiter = Some(record_delimiter.into());
+        self
+    }
+
+    pub fn field_delimiter(mut self, field_delimiter: impl Into<String>) -> Self {
+        self.input.field_delimiter = Some(field_delimiter.into());
+        self
+    }
+
+    pub fn quote_character(mut self, quote_character: impl Into<String>) -> Self {
+        self.input.quote_character = Some(quote_character.into());
+        self
+    }
+
+    pub fn comment_character(mut self, comment_character: impl Into<String>) -> Self {
+        self.input.comment_character = Some(comment_character.into());
+        self
+    }
+
+    pub fn range(mut self, range: SelectRange) -> Self {
+        self.input.range = Some(range);
+        self
+    }
+
+    pub fn allow_quoted_record_delimiter(mut self, allow: bool) -> Self {
+        self.input.allow_quoted_record_delimiter = Some(allow);
+        self
+    }
+
+    pub fn build(self) -> CsvInputSerialization {
+        self.input
+    }
+}
+
+/// How to parse a JSON object
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde-support", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde-camelcase", serde(rename_all = "camelCase"))]
+pub struct JsonInputSerialization {
+    pub json_type: JsonType,
+
+    /// Only `JsonType::Lines` objects support ranges
+    pub range: Option<SelectRange>,
+
+    /// Parse numbers in the JSON object as strings to avoid losing precision
+    pub parse_json_number_as_string: Option<bool>,
+}
+
+/// Format of the object to select from
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde-support", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde-camelcase", serde(rename_all = "camelCase"))]
+pub enum InputSerialization {
+    Csv(CsvInputSerialization),
+    Json(JsonInputSerialization),
+}
+
+impl Default for InputSerialization {
+    fn default() -> Self {
+        Self::Csv(CsvInputSerialization::default())
+    }
+}
+
+impl InputSerialization {
+    /// The format name used in `x-oss-process` query, `csv` or `json`
+    fn format_name(&self) -> &str {
+        match self {
+            InputSerialization::Csv(_) => "csv",
+            InputSerialization::Json(_) => "json",
+        }
+    }
+}
+
+/// Request of select object
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde-support", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde-camelcase", serde(rename_all = "camelCase"))]
+pub struct SelectObjectRequest {
+    /// SQL statement, e.g. `select * from ossobject where _1 > 100`
+    pub expression: String,
+    pub input: InputSerialization,
+    pub compression_type: CompressionType,
+}
+
+impl SelectObjectRequest {
+    pub fn new(expression: impl Into<String>, input: InputSerialization) -> Self {
+        Self {
+            expression: expression.into(),
+            input,
+            compression_type: CompressionType::None,
+        }
+    }
+
+    pub fn compression_type(mut self, compression_type: CompressionType) -> Self {
+        self.compression_type = compression_type;
+        self
+    }
+}
+
+/// Options for select object
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde-support", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde-camelcase", serde(rename_all = "camelCase"))]
+pub struct SelectObjectOptions {
+    /// Record delimiter of the output. Default is `\n`
+    pub output_record_delimiter: Option<String>,
+
+    /// Field delimiter of CSV output. Default is `,`
+    pub output_field_delimiter: Option<String>,
+
+    /// Keep all columns of CSV output. Columns not selected are output as empty values.
+    pub keep_all_columns: Option<bool>,
+
+    /// Output the header line of CSV object. Only valid when `FileHeaderInfo::Use` is set.
+    pub output_header: Option<bool>,
+
+    /// Output the selected data as is, without frames.
+    /// The response can not be verified by CRC and a failure in the middle can not be detected.
+    pub output_raw_data: Option<bool>,
+
+    /// Calculate CRC32 of each frame and verify it when decoding. Default is `true`.
+    pub enable_payload_crc: bool,
+
+    /// Skip records with missing columns instead of failing
+    pub skip_partial_data_record: Option<bool>,
+
+    /// The max number of records which can be skipped because of invalid data.
+    pub max_skipped_records_allowed: Option<u64>,
+
+    pub request_payer: Option<RequestPayer>,
+}
+
+impl Default for SelectObjectOptions {
+    fn default() -> Self {
+        Self {
+            output_record_delimiter: None,
+            output_field_delimiter: None,
+            keep_all_columns: None,
+            output_header: None,
+            output_raw_data: None,
+            enable_payload_crc: true,
+            skip_partial_data_record: None,
+            max_skipped_records_allowed: None,
+            request_payer: None,
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct SelectObjectOptionsBuilder {
+    options: SelectObjectOptions,
+}
+
+impl SelectObjectOptionsBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn output_record_delimiter(mut self, record_delimiter: impl Into<String>) -> Self {
+        self.options.output_record_delimiter = Some(record_delimiter.into());
+        self
+    }
+
+    pub fn output_field_delimiter(mut self, field_delimiter: impl Into<String>) -> Self {
+        self.options.output_field_delimiter = Some(field_delimiter.into());
+        self
+    }
+
+    pub fn keep_all_columns(mut self, keep_all_columns: bool) -> Self {
+        self.options.keep_all_columns = Some(keep_all_columns);
+        self
+    }
+
+    pub fn output_header(mut self, output_header: bool) -> Self {
+        self.options.output_header = Some(output_header);
+        self
+    }
+
+    pub fn output_raw_data(mut self, output_raw_data: bool) -> Self {
+        self.options.output_raw_data = Some(output_raw_data);
+        self
+    }
+
+    pub fn enable_payload_crc(mut self, enable_payload_crc: bool) -> Self {
+        self.options.enable_payload_crc = enable_payload_crc;
+        self
+    }
+
+    pub fn skip_partial_data_record(mut self, skip: bool) -> Self {
+        self.options.skip_partial_data_record = Some(skip);
+        self
+    }
+
+    pub fn max_skipped_records_allowed(mut self, max: u64) -> Self {
+        self.options.max_skipped_records_allowed = Some(max);
+        self
+    }
+
+    pub fn request_payer(mut self, request_payer: RequestPayer) -> Self {
+        self.options.request_payer = Some(request_payer);
+        self
+    }
+
+    pub fn build(self) -> SelectObjectOptions {
+        self.options
+    }
+}
+
+/// Request of create select object meta.
+/// Only `record_delimiter`, `field_delimiter` and `quote_character` of CSV input and `json_type` of JSON input are used.
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde-support", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde-camelcase", serde(rename_all = "camelCase"))]
+pub struct CreateSelectObjectMetaRequest {
+    pub input: InputSerialization,
+    pub compression_type: CompressionType,
+
+    /// Re-create the select meta even if it exists
+    pub overwrite_if_exists: Option<bool>,
+}
+
+impl CreateSelectObjectMetaRequest {
+    pub fn new(input: InputSerialization) -> Self {
+        Self {
+            input,
+            compression_type: CompressionType::None,
+            overwrite_if_exists: None,
+        }
+    }
+
+    pub fn overwrite_if_exists(mut self, overwrite: bool) -> Self {
+        self.overwrite_if_exists = Some(overwrite);
+        self
+    }
+}
+
+/// Select meta of an object
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde-support", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde-camelcase", serde(rename_all = "camelCase"))]
+pub struct SelectObjectMeta {
+    /// Number of bytes scanned
+    pub total_scanned_bytes: u64,
+    pub splits_count: u32,
+    pub rows_count: u64,
+
+    /// Only CSV objects have this value
+    pub columns_count: Option<u32>,
+}
+
+/// Summary of a select object request, sent in the end frame
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde-support", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde-camelcase", serde(rename_all = "camelCase"))]
+pub struct SelectObjectSummary {
+    /// Number of bytes scanned
+    pub total_scanned_bytes: u64,
+
+    /// HTTP status code of the select operation. e.g. `206` means some records are skipped.
+    pub status: u32,
+    pub error_message: String,
+}
+
+pub(crate) const FRAME_TYPE_DATA: u32 = 0x800001;
+pub(crate) const FRAME_TYPE_CONTINUOUS: u32 = 0x800004;
+pub(crate) const FRAME_TYPE_END: u32 = 0x800005;
+pub(crate) const FRAME_TYPE_CSV_META_END: u32 = 0x800006;
+pub(crate) const FRAME_TYPE_JSON_META_END: u32 = 0x800007;
+
+/// 1 byte version, 3 bytes frame type, 4 bytes payload length and 4 bytes header checksum
+const FRAME_HEADER_LEN: usize = 12;
+const FRAME_CHECKSUM_LEN: usize = 4;
+
+/// Decoded frame of select object response
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum SelectFrame {
+    /// Offset of the object scanned and the selected data
+    Data(u64, Bytes),
+
+    /// Sent when no data is selected for a while to keep the connection alive
+    Continuous(u64),
+
+    End(SelectObjectSummary),
+
+    /// Meta end frame and the status and error message in it
+    MetaEnd(SelectObjectMeta, u32, String),
+}
+
+/// Decode frames from select object response body.
+/// Bytes read from the body are fed to the decoder and frames are decoded when they are complete.
+pub(crate) struct SelectFrameDecoder {
+    buffer: BytesMut,
+    verify_crc: bool,
+}
+
+impl SelectFrameDecoder {
+    pub(crate) fn new(verify_crc: bool) -> Self {
+        Self {
+            buffer: BytesMut::new(),
+            verify_crc,
+        }
+    }
+
+    pub(crate) fn feed(&mut self, data: &[u8]) {
+        self.buffer.extend_from_slice(data);
+    }
+
+    /// Decode next frame. Returns `None` if more bytes are needed.
+    pub(crate) fn next_frame(&mut self) -> Result<Option<SelectFrame>> {
+        if self.buffer.len() < FRAME_HEADER_LEN {
+            return Ok(None);
+        }
+
+        let frame_type = u32::from_be_bytes([0, self.buffer[1], self.buffer[2], self.buffer[3]]);
+        let payload_len = u32::from_be_bytes([self.buffer[4], self.buffer[5], self.buffer[6], self.buffer[7]]) as usize;
+
+        if self.buffer.len() < FRAME_HEADER_LEN + payload_len + FRAME_CHECKSUM_LEN {
+            return Ok(None);
+        }
+
+        self.buffer.advance(FRAME_HEADER_LEN);
+        let mut payload = self.buffer.split_to(payload_len).freeze();
+        let checksum = self.buffer.get_u32();
+
+        if self.verify_crc && checksum != 0 {
+            let crc = crc32fast::hash(&payload);
+            if crc != checksum {
+                return Err(Error::InvalidResponse(format!(
+                    "select object frame checksum mismatch. expected: {}, calculated: {}",
+                    checksum, crc
+                )));
+            }
+        }
+
+        let min_payload_len = match frame_type {
+            FRAME_TYPE_DATA | FRAME_TYPE_CONTINUOUS => 8,
+            FRAME_TYPE_END => 20,
+            FRAME_TYPE_CSV_META_END => 36,
+            FRAME_TYPE_JSON_META_END => 32,
+            _ => return Err(Error::InvalidResponse(format!("unexpected select object frame type: {:#x}", frame_type))),
+        };
+
+        if payload.len() < min_payload_len {
+            return Err(Error::InvalidResponse(format!(
+                "invalid select object frame payload length {} of frame type {:#x}",
+                payload.len(),
+                frame_type
+            )));
+        }
+
+        let offset = payload.get_u64();
+
+        let frame = match frame_type {
+            FRAME_TYPE_DATA => SelectFrame::Data(offset, payload),
+            FRAME_TYPE_CONTINUOUS => SelectFrame::Continuous(offset),
+            FRAME_TYPE_END => SelectFrame::End(SelectObjectSummary {
+                total_scanned_bytes: payload.get_u64(),
+                status: payload.get_u32(),
+                error_message: String::from_utf8_lossy(&payload).to_string(),
+            }),
+            _ => {
+                let total_scanned_bytes = payload.get_u64();
+                let status = payload.get_u32();
+                let splits_count = payload.get_u32();
+                let rows_count = payload.get_u64();
+                let columns_count = if frame_type == FRAME_TYPE_CSV_META_END {
+                    Some(payload.get_u32())
+                } else {
+                    None
+                };
+
+                let meta = SelectObjectMeta {
+                    total_scanned_bytes,
+                    splits_count,
+                    rows_count,
+                    columns_count,
+                };
+
+                SelectFrame::MetaEnd(meta, status, String::from_utf8_lossy(&payload).to_string())
+            }
+        };
+
+        Ok(Some(frame))
+    }
+}
+
+/// Status in the end frame greater than or equal to 400 means the select operation failed.
+pub(crate) fn check_select_status(status: u32, error_message: &str) -> Result<()> {
+    if status >= 400 {
+        return Err(Error::SelectObjectFailed {
+            status,
+            message: error_message.to_string(),
+        });
+    }
+
+    Ok(())
+}
+
+fn write_text_element(writer: &mut quick_xml::Writer<Vec<u8>>, name: &str, value: &str) -> Result<()> {
+    writer.write_event(Event::Start(BytesStart::new(name)))?;
+    writer.write_event(Event::Text(BytesText::new(value)))?;
+    writer.write_event(Event::End(BytesEnd::new(name)))?;
+    Ok(())
+}
+
+fn write_base64_element(writer: &mut quick_xml::Writer<Vec<u8>>, name: &str, value: &str) -> Result<()> {
+    write_text_element(writer, name, &BASE64_STANDARD.encode(value))
+}
+
+fn write_bool_element(writer: &mut quick_xml::Writer<Vec<u8>>, name: &str, value: bool) -> Result<()> {
+    write_text_element(writer, name, if value { "true" } else { "false" })
+}
+
+fn build_select_request_xml(request: &SelectObjectRequest, options: &SelectObjectOptions) -> Result<String> {
+    let mut writer = quick_xml::Writer::new(Vec::new());
+    writer.write_event(Event::Decl(BytesDecl::new("1.0", Some("UTF-8"), None)))?;
+    writer.write_event(Event::Start(BytesStart::new("SelectRequest")))?;
+
+    write_base64_element(&mut writer, "Expression", &request.expression)?;
+
+    writer.write_event(Event::Start(BytesStart::new("InputSerialization")))?;
+    write_text_element(&mut writer, "CompressionType", request.compression_type.as_str())?;
+
+    match &request.input {
+        InputSerialization::Csv(csv) => {
+            writer.write_event(Event::Start(BytesStart::new("CSV")))?;
+
+            if let Some(v) = &csv.file_header_info {
+                write_text_element(&mut writer, "FileHeaderInfo", v.as_str())?;
+            }
+
+            if let Some(s) = &csv.record_delimiter {
+                write_base64_element(&mut writer, "RecordDelimiter", s)?;
+            }
+
+            if let Some(s) = &csv.field_delimiter {
+                write_base64_element(&mut writer, "FieldDelimiter", s)?;
+            }
+
+            if let Some(s) = &csv.quote_character {
+                write_base64_element(&mut writer, "QuoteCharacter", s)?;
+            }
+
+            if let Some(s) = &csv.comment_character {
+                write_base64_element(&mut writer, "CommentCharacter", s)?;
+            }
+
+            if let Some(r) = &csv.range {
+                write_text_element(&mut writer, "Range", &r.to_string())?;
+            }
+
+            if let Some(b) = csv.allow_quoted_record_delimiter {
+                write_bool_element(&mut writer, "AllowQuotedRecordDelimiter", b)?;
+            }
+
+            writer.write_event(Event::End(BytesEnd::new("CSV")))?;
+        }
+        InputSerialization::Json(json) => {
+            writer.write_event(Event::Start(BytesStart::new("JSON")))?;
+
+            write_text_element(&mut writer, "Type", json.json_type.as_str())?;
+
+            if let Some(r) = &json.range {
+                write_text_element(&mut writer, "Range", &r.to_string())?;
+            }
+
+            if let Some(b) = json.parse_json_number_as_string {
+                write_bool_element(&mut writer, "ParseJsonNumberAsString", b)?;
+            }
+
+            writer.write_event(Event::End(BytesEnd::new("JSON")))?;
+        }
+    }
+
+    writer.write_event(Event::End(BytesEnd::new("InputSerialization")))?;
+
+    writer.write_event(Event::Start(BytesStart::new("OutputSerialization")))?;
+
+    match &request.input {
+        InputSerialization::Csv(_) => {
+            writer.write_event(Event::Start(BytesStart::new("CSV")))?;
+
+            if let Some(s) = &options.output_record_delimiter {
+                write_base64_element(&mut writer, "RecordDelimiter", s)?;
+            }
+
+            if let Some(s) = &options.output_field_delimiter {
+                write_base64_element(&mut writer, "FieldDelimiter", s)?;
+            }
+
+            writer.write_event(Event::End(BytesEnd::new("CSV")))?;
+
+            if let Some(b) = options.keep_all_columns {
+                write_bool_element(&mut writer, "KeepAllColumns", b)?;
+            }
+
+            if let Some(b) = options.output_header {
+                write_bool_element(&mut writer, "OutputHeader", b)?;
+            }
+        }
+        InputSerialization::Json(_) => {
+            writer.write_event(Event::Start(BytesStart::new("JSON")))?;
+
+            if let Some(s) = &options.output_record_delimiter {
+                write_base64_element(&mut writer, "RecordDelimiter", s)?;
+            }
+
+            writer.write_event(Event::End(BytesEnd::new("JSON")))?;
+        }
+    }
+
+    if let Some(b) = options.output_raw_data {
+        write_bool_element(&mut writer, "OutputRawData", b)?;
+    }
+
+    write_bool_element(&mut writer, "EnablePayloadCrc", options.enable_payload_crc)?;
+
+    writer.write_event(Event::End(BytesEnd::new("OutputSerialization")))?;
+
+    if options.skip_partial_data_record.is_some() || options.max_skipped_records_allowed.is_some() {
+        writer.write_event(Event::Start(BytesStart::new("Options")))?;
+
+        if let Some(b) = options.skip_partial_data_record {
+            write_bool_element(&mut writer, "SkipPartialDataRecord", b)?;
+        }
+
+        if let Some(n) = options.max_skipped_records_allowed {
+            write_text_element(&mut writer, "MaxSkippedRecordsAllowed", &n.to_string())?;
+        }
+
+        writer.write_event(Event::End(BytesEnd::new("Options")))?;
+    }
+
+    writer.write_event(Event::End(BytesEnd::new("SelectRequest")))?;
+
+    Ok(String::from_utf8(writer.into_inner())?)
+}
+
+fn build_meta_request_xml(request: &CreateSelectObjectMetaRequest) -> Result<String> {
+    let root = match &request.input {
+        InputSerialization::Csv(_) => "CsvMetaRequest",
+        InputSerialization::Json(_) => "JsonMetaRequest",
+    };
+
+    let mut writer = quick_xml::Writer::new(Vec::new());
+    writer.write_event(Event::Decl(BytesDecl::new("1.0", Some("UTF-8"), None)))?;
+    writer.write_event(Event::Start(BytesStart::new(root)))?;
+
+    writer.write_event(Event::Start(BytesStart::new("InputSerialization")))?;
+    write_text_element(&mut writer, "CompressionType", request.compression_type.as_str())?;
+
+    match &request.input {
+        InputSerialization::Csv(csv) => {
+            writer.write_event(Event::Start(BytesStart::new("CSV")))?;
+
+            if let Some(s) = &csv.record_delimiter {
+                write_base64_element(&mut writer, "RecordDelimiter", s)?;
+            }
+
+            if let Some(s) = &csv.field_delimiter {
+                write_base64_element(&mut writer, "FieldDelimiter", s)?;
+            }
+
+            if let Some(s) = &csv.quote_character {
+                write_base64_element(&mut writer, "QuoteCharacter", s)?;
+            }
+
+            writer.write_event(Event::End(BytesEnd::new("CSV")))?;
+        }
+        InputSerialization::Json(json) => {
+            writer.write_event(Event::Start(BytesStart::new("JSON")))?;
+            write_text_element(&mut writer, "Type", json.json_type.as_str())?;
+            writer.write_event(Event::End(BytesEnd::new("JSON")))?;
+        }
+    }
+
+    writer.write_event(Event::End(BytesEnd::new("InputSerialization")))?;
+
+    if let Some(b) = request.overwrite_if_exists {
+        write_bool_element(&mut writer, "OverwriteIfExists", b)?;
+    }
+
+    writer.write_event(Event::End(BytesEnd::new(root)))?;
+
+    Ok(String::from_utf8(writer.into_inner())?)
+}
+
+pub(crate) fn build_select_object_request(
+    bucket_name: &str,
+    object_key: &str,
+    request: &SelectObjectRequest,
+    options: &SelectObjectOptions,
+) -> Result<OssRequest> {
+    if !validate_bucket_name(bucket_name) {
+        return Err(Error::InvalidBucketName(bucket_name.to_string()));
+    }
+
+    if !validate_object_key(object_key) {
+        return Err(Error::InvalidObjectKey(object_key.to_string()));
+    }
+
+    if request.expression.is_empty() {
+        return Err(Error::InvalidArgument("select expression must not be empty".to_string()));
+    }
+
+    let xml = build_select_request_xml(request, options)?;
+
+    let request = OssRequest::new()
+        .method(RequestMethod::Post)
+        .bucket(bucket_name)
+        .object(object_key)
+        .add_query("x-oss-process", format!("{}/select", request.input.format_name()))
+        .content_type(common::MIME_TYPE_XML)
+        .content_length(xml.len() as u64)
+        .text_body(xml);
+
+    Ok(apply_request_payer(request, &options.request_payer))
+}
+
+pub(crate) fn build_create_select_object_meta_request(bucket_name: &str, object_key: &str, request: &CreateSelectObjectMetaRequest) -> Result<OssRequest> {
+    if !validate_bucket_name(bucket_name) {
+        return Err(Error::InvalidBucketName(bucket_name.to_string()));
+    }
+
+    if !validate_object_key(object_key) {
+        return Err(Error::InvalidObjectKey(object_key.to_string()));
+    }
+
+    let xml = build_meta_request_xml(request)?;
+
+    Ok(OssRequest::new()
+        .method(RequestMethod::Post)
+        .bucket(bucket_name)
+        .object(object_key)
+        .add_query("x-oss-process", format!("{}/meta", request.input.format_name()))
+        .content_type(common::MIME_TYPE_XML)
+        .content_length(xml.len() as u64)
+        .text_body(xml))
+}
+
+/// Decode the select object meta from the whole response body of create select object meta.
+pub(crate) fn parse_select_object_meta(body: &[u8]) -> Result<SelectObjectMeta> {
+    let mut decoder = SelectFrameDecoder::new(true);
+    decoder.feed(body);
+
+    while let Some(frame) = decoder.next_frame()? {
+        if let SelectFrame::MetaEnd(meta, status, message) = frame {
+            check_select_status(status, &message)?;
+            return Ok(meta);
+        }
+    }
+
+    Err(Error::InvalidResponse(
+        "no meta end frame in the response of create select object meta".to_string(),
+    ))
+}
+
+/// Returns `true` if the response headers indicate the select object response body is raw data without frames
+pub(crate) fn is_output_raw(headers: &std::collections::HashMap<String, String>) -> bool {
+    headers.get("x-oss-select-output-raw").map(|s| s == "true").unwrap_or(false)
+}
+
+#[cfg(test)]
+pub(crate) mod test_select_object_common {
+    use bytes::{BufMut, Bytes, BytesMut};
+
+    use crate::request::RequestMethod;
+
+    use super::*;
+
+    pub(crate) fn build_frame(frame_type: u32, payload: &[u8], with_crc: bool) -> Vec<u8> {
+        let mut buf = BytesMut::new();
+        buf.put_u32(frame_type | 0x01000000);
+        buf.put_u32(payload.len() as u32);
+        buf.put_u32(0);
+        buf.put_slice(payload);
+        buf.put_u32(if with_crc { crc32fast::hash(payload) } else { 0 });
+        buf.to_vec()
+    }
+
+    pub(crate) fn data_frame(offset: u64, data: &[u8]) -> Vec<u8> {
+        let mut payload = offset.to_be_bytes().to_vec();
+        payload.extend_from_slice(data);
+        build_frame(FRAME_TYPE_DATA, &payload, true)
+    }
+
+    pub(crate) fn end_frame(offset: u64, total: u64, status: u32, message: &str) -> Vec<u8> {
+        let mut payload = BytesMut::new();
+        payload.put_u64(offset);
+        payload.put_u64(total);
+        payload.put_u32(status);
+        payload.put_slice(message.as_bytes());
+        build_frame(FRAME_TYPE_END, &payload, true)
+    }
+
+    #[test]
+    fn test_decode_frames() {
+        let mut body = data_frame(10, b"a,b\n");
+        body.extend(build_frame(FRAME_TYPE_CONTINUOUS, &20u64.to_be_bytes(), true));
+        body.extend(data_frame(30, b"c,d\n"));
+        body.extend(end_frame(40, 40, 200, ""));
+
+        let mut decoder = SelectFrameDecoder::new(true);
+        decoder.feed(&body);
+
+        assert_eq!(Some(SelectFrame::Data(10, Bytes::from_static(b"a,b\n"))), decoder.next_frame().unwrap());
+        assert_eq!(Some(SelectFrame::Continuous(20)), decoder.next_frame().unwrap());
+        assert_eq!(Some(SelectFrame::Data(30, Bytes::from_static(b"c,d\n"))), decoder.next_frame().unwrap());
+
+        match decoder.next_frame().unwrap() {
+            Some(SelectFrame::End(summary)) => {
+                assert_eq!(40, summary.total_scanned_bytes);
+                assert_eq!(200, summary.status);
+                assert!(summary.error_message.is_empty());
+            }
+            _ => panic!("end frame expected"),
+        }
+
+        assert!(decoder.next_frame().unwrap().is_none());
+        assert!(decoder.buffer.is_empty());
+    }
+
+    /// Frames encoded by hand as literal bytes, independent of `build_frame`, following the layout in the official document:
+    /// version and frame type, payload length, header checksum, payload and payload checksum (CRC32, calculated with `zlib.crc32`).
+    /// Official document: <https://help.aliyun.com/zh/oss/developer-reference/selectobject>
+    #[test]
+    fn test_decode_literal_frames() {
+        #[rustfmt::skip]
+        let body: &[u8] = &[
+            // data frame: offset 39, data `1,Alice\n2,Bob\n`
+            0x01, 0x80, 0x00, 0x01, 0x00, 0x00, 0x00, 0x16, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x27,
+            0x31, 0x2c, 0x41, 0x6c, 0x69, 0x63, 0x65, 0x0a, 0x32, 0x2c, 0x42, 0x6f, 0x62, 0x0a,
+            0xa9, 0x94, 0xd3, 0xb1,
+            // end frame: offset 39, total scanned bytes 39, status 200
+            0x01, 0x80, 0x00, 0x05, 0x00, 0x00, 0x00, 0x14, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x27,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x27,
+            0x00, 0x00, 0x00, 0xc8,
+            0x27, 0x86, 0x6f, 0xb6,
+            // csv meta end frame: offset 39, total scanned bytes 39, status 200, 1 split, 3 rows, 2 columns
+            0x01, 0x80, 0x00, 0x06, 0x00, 0x00, 0x00, 0x24, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x27,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x27,
+            0x00, 0x00, 0x00, 0xc8,
+            0x00, 0x00, 0x00, 0x01,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03,
+            0x00, 0x00, 0x00, 0x02,
+            0xc5, 0xfd, 0x27, 0x58,
+        ];
+
+        let mut decoder = SelectFrameDecoder::new(true);
+        decoder.feed(body);
+
+        assert_eq!(
+            Some(SelectFrame::Data(39, Bytes::from_static(b"1,Alice\n2,Bob\n"))),
+            decoder.next_frame().unwrap()
+        );
+
+        assert_eq!(
+            Some(SelectFrame::End(SelectObjectSummary {
+                total_scanned_bytes: 39,
+                status: 200,
+                error_message: String::new(),
+            })),
+            decoder.next_frame().unwrap()
+        );
+
+        let meta = SelectObjectMeta {
+            total_scanned_bytes: 39,
+            splits_count: 1,
+            rows_count: 3,
+            columns_count: Some(2),
+        };
+        assert_eq!(Some(SelectFrame::MetaEnd(meta, 200, String::new())), decoder.next_frame().unwrap());
+
+        assert!(decoder.next_frame().unwrap().is_none());
+        assert!(decoder.buffer.is_empty());
+    }
+
+    #[test]
+    fn test_decode_partial_feeding() {
+        let body = data_frame(0, b"hello world");
+        let mut decoder = SelectFrameDecoder::new(true);
+
+        for b in &body[..body.len() - 1] {
+            decoder.feed(&[*b]);
+            assert!(decoder.next_frame().unwrap().is_none());
+        }
+
+        decoder.feed(&body[body.len() - 1..]);
+        assert_eq!(Some(SelectFrame::Data(0, Bytes::from_static(b"hello world"))), decoder.next_frame().unwrap());
+    }
+
+    #[test]
+    fn test_decode_crc() {
+        let mut body = data_frame(0, b"hello");
+        let n = body.len();
+        body[n - 5] = b'x';
+
+        let mut decoder = SelectFrameDecoder::new(true);
+        decoder.feed(&body);
+        assert!(decoder.next_frame().is_err());
+
+        // checksum is not verified if it is disabled
+        let mut decoder = SelectFrameDecoder::new(false);
+        decoder.feed(&body);
+        assert_eq!(Some(SelectFrame::Data(0, Bytes::from_static(b"hellx"))), decoder.next_frame().unwrap());
+
+        // zero checksum means the checksum is not calculated by the server
+        let mut payload = 0u64.to_be_bytes().to_vec();
+        payload.extend_from_slice(b"hello");
+        let mut decoder = SelectFrameDecoder::new(true);
+        decoder.feed(&build_frame(FRAME_TYPE_DATA, &payload, false));
+        assert_eq!(Some(SelectFrame::Data(0, Bytes::from_static(b"hello"))), decoder.next_frame().unwrap());
+    }
+
+    #[test]
+    fn test_decode_unexpected_frame_type() {
+        let mut decoder = SelectFrameDecoder::new(true);
+        decoder.feed(&build_frame(0x800009, &0u64.to_be_bytes(), true));
+        assert!(decoder.next_frame().is_err());
+    }
+
+    #[test]
+    fn test_parse_meta() {
+        let mut payload = BytesMut::new();
+        payload.put_u64(1024);
+        payload.put_u64(1024);
+        payload.put_u32(200);
+        payload.put_u32(3);
+        payload.put_u64(100);
+        payload.put_u32(5);
+
+        let meta = parse_select_object_meta(&build_frame(FRAME_TYPE_CSV_META_END, &payload, true)).unwrap();
+        assert_eq!(
+            SelectObjectMeta {
+                total_scanned_bytes: 1024,
+                splits_count: 3,
+                rows_count: 100,
+                columns_count: Some(5)
+            },
+            meta
+        );
+
+        let mut payload = BytesMut::new();
+        payload.put_u64(0);
+        payload.put_u64(0);
+        payload.put_u32(400);
+        payload.put_u32(0);
+        payload.put_u64(0);
+        payload.put_slice(b"InvalidJson");
+
+        let err = parse_select_object_meta(&build_frame(FRAME_TYPE_JSON_META_END, &payload, true)).unwrap_err();
+        assert!(matches!(err, Error::SelectObjectFailed { status: 400, .. }));
+    }
+
+    #[test]
+    fn test_build_select_request() {
+        let input = CsvInputSerializationBuilder::new()
+            .file_header_info(FileHeaderInfo::Use)
+            .field_delimiter(",")
+            .range(SelectRange::Line(0, 99))
+            .build();
+
+        let request = SelectObjectRequest::new("select * from ossobject", InputSerialization::Csv(input));
+        let options = SelectObjectOptionsBuilder::new().keep_all_columns(true).skip_partial_data_record(true).build();
+
+        let oss_request = build_select_object_request("example-bucket", "data.csv", &request, &options).unwrap();
+        assert_eq!(RequestMethod::Post, oss_request.method);
+        assert_eq!(Some(&"csv/select".to_string()), oss_request.query.get("x-oss-process"));
+
+        let xml = build_select_request_xml(&request, &options).unwrap();
+        assert_eq!(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?><SelectRequest>\
+            <Expression>c2VsZWN0ICogZnJvbSBvc3NvYmplY3Q=</Expression>\
+            <InputSerialization><CompressionType>None</CompressionType>\
+            <CSV><FileHeaderInfo>USE</FileHeaderInfo><FieldDelimiter>LA==</FieldDelimiter><Range>line-range=0-99</Range></CSV>\
+            </InputSerialization>\
+            <OutputSerialization><CSV></CSV><KeepAllColumns>true</KeepAllColumns><EnablePayloadCrc>true</EnablePayloadCrc></OutputSerialization>\
+            <Options><SkipPartialDataRecord>true</SkipPartialDataRecord></Options>\
+            </SelectRequest>",
+            xml
+        );
+
+        let request = CreateSelectObjectMetaRequest::new(InputSerialization::Json(JsonInputSerialization {
+            json_type: JsonType::Lines,
+            ..Default::default()
+        }))
+        .overwrite_if_exists(true);
+
+        let oss_request = build_create_select_object_meta_request("example-bucket", "data.json", &request).unwrap();
+        assert_eq!(Some(&"json/meta".to_string()), oss_request.query.get("x-oss-process"));
+
+        let xml = build_meta_request_xml(&request).unwrap();
+        assert_eq!(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?><JsonMetaRequest>\
+            <InputSerialization><CompressionType>None</CompressionType><JSON><Type>LINES</Type></JSON></InputSerialization>\
+            <OverwriteIfExists>true</OverwriteIfExists>\
+            </JsonMetaRequest>",
+            xml
+        );
+    }
+}
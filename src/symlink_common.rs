@@ -85,15 +85,15 @@ pub(crate) fn build_put_symlink_request(
     options: &Option<PutSymlinkOptions>,
 ) -> Result<OssRequest> {
     if !validate_bucket_name(bucket_name) {
-        return Err(Error::Other(format!("invalid bucket name: {}", bucket_name)));
+        return Err(Error::InvalidBucketName(bucket_name.to_string()));
     }
 
     if !validate_object_key(symlink_object_key) {
-        return Err(Error::Other(format!("invalid object key: {}", symlink_object_key)));
+        return Err(Error::InvalidObjectKey(symlink_object_key.to_string()));
     }
 
     if !validate_object_key(target_object_key) {
-        return Err(Error::Other(format!("invalid object key: {}", target_object_key)));
+        return Err(Error::InvalidObjectKey(target_object_key.to_string()));
     }
 
     let mut request = OssRequest::new()
@@ -121,7 +121,7 @@ pub(crate) fn build_put_symlink_request(
         if !opt.metadata.is_empty() {
             for (k, v) in &opt.metadata {
                 if !validate_meta_key(k) {
-                    return Err(Error::Other(format!("invalid meta key: {}", k)));
+                    return Err(Error::InvalidArgument(format!("invalid meta key: {}", k)));
                 }
 
                 request = request.add_header(k, v);
@@ -134,11 +134,11 @@ pub(crate) fn build_put_symlink_request(
 
 pub(crate) fn build_get_symlink_request(bucket_name: &str, symlink_object_key: &str, options: &Option<GetSymlinkOptions>) -> Result<OssRequest> {
     if !validate_bucket_name(bucket_name) {
-        return Err(Error::Other(format!("invalid bucket name: {}", bucket_name)));
+        return Err(Error::InvalidBucketName(bucket_name.to_string()));
     }
 
     if !validate_object_key(symlink_object_key) {
-        return Err(Error::Other(format!("invalid object key: {}", symlink_object_key)));
+        return Err(Error::InvalidObjectKey(symlink_object_key.to_string()));
     }
 
     let mut request = OssRequest::new()
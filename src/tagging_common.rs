@@ -74,11 +74,11 @@ pub(crate) fn parse_tags_from_xml(xml: String) -> Result<HashMap<String, String>
 
 pub(crate) fn build_get_object_tag_request(bucket_name: &str, object_key: &str, options: &Option<GetObjectTagOptions>) -> Result<OssRequest> {
     if !validate_bucket_name(bucket_name) {
-        return Err(Error::Other(format!("invalid bucket name: {}", bucket_name)));
+        return Err(Error::InvalidBucketName(bucket_name.to_string()));
     }
 
     if !validate_object_key(object_key) {
-        return Err(Error::Other(format!("invalid object key: {}", object_key)));
+        return Err(Error::InvalidObjectKey(object_key.to_string()));
     }
 
     let mut request = OssRequest::new()
@@ -103,24 +103,24 @@ pub(crate) fn build_put_object_tag_request(
     options: &Option<PutObjectTagOptions>,
 ) -> Result<OssRequest> {
     if !validate_bucket_name(bucket_name) {
-        return Err(Error::Other(format!("invalid bucket name: {}", bucket_name)));
+        return Err(Error::InvalidBucketName(bucket_name.to_string()));
     }
 
     if !validate_object_key(object_key) {
-        return Err(Error::Other(format!("invalid object key: {}", object_key)));
+        return Err(Error::InvalidObjectKey(object_key.to_string()));
     }
 
     if tags.is_empty() {
-        return Err(Error::Other("tags cannot be empty".to_string()));
+        return Err(Error::InvalidArgument("tags cannot be empty".to_string()));
     }
 
     for (k, v) in tags {
         if !validate_tag_key(k) {
-            return Err(Error::Other(format!("invalid tag key: {}", k)));
+            return Err(Error::InvalidArgument(format!("invalid tag key: {}", k)));
         }
 
         if !validate_tag_value(v) {
-            return Err(Error::Other(format!("invalid tag value: {}", v)));
+            return Err(Error::InvalidArgument(format!("invalid tag value: {}", v)));
         }
     }
 
@@ -144,11 +144,11 @@ pub(crate) fn build_put_object_tag_request(
 
 pub(crate) fn build_delete_object_tag_request(bucket_name: &str, object_key: &str, options: &Option<DeleteObjectTagOptions>) -> Result<OssRequest> {
     if !validate_bucket_name(bucket_name) {
-        return Err(Error::Other(format!("invalid bucket name: {}", bucket_name)));
+        return Err(Error::InvalidBucketName(bucket_name.to_string()));
     }
 
     if !validate_object_key(object_key) {
-        return Err(Error::Other(format!("invalid object key: {}", object_key)));
+        return Err(Error::InvalidObjectKey(object_key.to_string()));
     }
 
     let mut request = OssRequest::new()
@@ -1,7 +1,8 @@
 use std::path::{Component, Path};
 
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDateTime, Utc};
 use hmac::{Hmac, Mac};
+use sha1::Sha1;
 use sha2::{Digest, Sha256};
 
 use crate::common;
@@ -29,15 +30,35 @@ pub(crate) fn get_iso8601_date_string() -> String {
     now.format("%Y%m%d").to_string()
 }
 
-pub(crate) fn get_http_date() -> String {
+/// Get HTTP date string of the current time shifted by `offset_seconds`,
+/// which is the offset of the server clock to the local clock.
+pub(crate) fn get_http_date_with_offset(offset_seconds: i64) -> String {
     // HTTP Date 格式必须使用 UTC 时间
-    let now: DateTime<Utc> = Utc::now();
+    let now: DateTime<Utc> = Utc::now() + chrono::Duration::seconds(offset_seconds);
 
     // 格式化为 HTTP Date 格式
     // 例如: "Sun, 06 Nov 1994 08:49:37 GMT"
     now.format("%a, %d %b %Y %H:%M:%S GMT").to_string()
 }
 
+/// Shift the date time string (e.g. `20231203T121212Z`) by `offset_seconds`.
+/// The original string is returned if it can not be parsed.
+pub(crate) fn shift_iso8601_date_time_string(date_time_string: &str, offset_seconds: i64) -> String {
+    match NaiveDateTime::parse_from_str(date_time_string, "%Y%m%dT%H%M%SZ") {
+        Ok(d) => (d.and_utc() + chrono::Duration::seconds(offset_seconds)).format("%Y%m%dT%H%M%SZ").to_string(),
+        Err(_) => date_time_string.to_string(),
+    }
+}
+
+/// Parse the server time from `ServerTime` of error response (e.g. `2015-01-19T08:09:52.000Z`)
+/// or HTTP `Date` header (e.g. `Mon, 19 Jan 2015 08:09:52 GMT`).
+pub(crate) fn parse_server_time(s: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(s)
+        .or_else(|_| DateTime::parse_from_rfc2822(s))
+        .ok()
+        .map(|d| d.with_timezone(&Utc))
+}
+
 /// Find region from endpoint string
 pub(crate) fn get_region_from_endpoint<S: AsRef<str>>(endpoint: S) -> Result<String, String> {
     match endpoint.as_ref().find(".") {
@@ -56,6 +77,14 @@ pub(crate) fn hmac_sha256(key_data: &[u8], msg_data: &[u8]) -> Vec<u8> {
     ret.into_bytes().to_vec()
 }
 
+pub(crate) fn hmac_sha1(key_data: &[u8], msg_data: &[u8]) -> Vec<u8> {
+    type HmacSha1 = Hmac<Sha1>;
+    let mut mac = HmacSha1::new_from_slice(key_data).unwrap();
+    mac.update(msg_data);
+    let ret = mac.finalize();
+    ret.into_bytes().to_vec()
+}
+
 pub(crate) fn sha256(data: &[u8]) -> Vec<u8> {
     let mut hasher = Sha256::new();
     hasher.update(data);
@@ -222,7 +251,7 @@ pub(crate) fn file_md5(file: impl AsRef<Path>) -> String {
 
 #[cfg(test)]
 mod test_util {
-    use crate::util::{get_http_date, get_iso8601_date_string, get_region_from_endpoint};
+    use crate::util::{get_http_date_with_offset, get_iso8601_date_string, get_region_from_endpoint};
 
     use super::get_iso8601_date_time_string;
 
@@ -234,10 +263,24 @@ mod test_util {
         let s = get_iso8601_date_string();
         println!("ISO8601 date string: {}", s);
 
-        let s = get_http_date();
+        let s = get_http_date_with_offset(0);
         println!("HTTP Date header: {}", s);
     }
 
+    #[test]
+    fn test_clock_offset() {
+        use super::{parse_server_time, shift_iso8601_date_time_string};
+
+        assert_eq!("20231203T131212Z", shift_iso8601_date_time_string("20231203T121212Z", 3600));
+        assert_eq!("20231202T235959Z", shift_iso8601_date_time_string("20231203T000009Z", -10));
+        assert_eq!("invalid", shift_iso8601_date_time_string("invalid", 10));
+
+        let t1 = parse_server_time("2015-01-19T08:09:52.000Z").unwrap();
+        let t2 = parse_server_time("Mon, 19 Jan 2015 08:09:52 GMT").unwrap();
+        assert_eq!(t1, t2);
+        assert!(parse_server_time("").is_none());
+    }
+
     #[test]
     fn test_validate_path() {
         use super::validate_path;